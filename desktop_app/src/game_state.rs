@@ -1,6 +1,7 @@
 use bevy::prelude::*;
-use crate::models::{Equipe, MoteurMatch, Joueur, creer_joueurs_reels};
+use crate::models::{Championnat, Competition, Contrat, Coupe, CritereTetesDeSerie, Equipe, FenetreTransferts, FormatConfrontation, GenerateurAleatoire, GenerateurMonde, MarcheTransferts, MoteurMatch, NatureOperation, Joueur, NiveauDifficulte, OffreTransfert, PRIME_COUPE, RegleMatch, ReponseOffre, StyleEntraineur, TAILLE_LIGUE_MAX, TAILLE_LIGUE_MIN, creer_joueurs_reels};
 use crate::models::player::Position;
+use crate::models::power_up::TypePowerUp;
 
 /// Écrans disponibles dans l'application
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, States, Default)]
@@ -13,6 +14,8 @@ pub enum EcranJeu {
     MatchEnCours,
     ResultatMatch,
     Classement,
+    Coupe,
+    Transferts,
     FichesJoueurs,
     Options,
 }
@@ -26,8 +29,20 @@ pub struct EtatJeu {
     pub joueur_selectionne_id: Option<u32>,
     pub id_compteur: u32,
     pub match_compteur: u32,
-    pub saison: u32,
+    pub championnat: Championnat,
+    pub coupe: Option<Coupe>,
+    pub critere_coupe: CritereTetesDeSerie,
+    pub format_coupe: FormatConfrontation,
     pub joueurs_disponibles: Vec<Joueur>,
+    pub jours_repos_entre_matchs: u32,
+    pub regles_match: RegleMatch,
+    pub difficulte_ia: NiveauDifficulte,  // Niveau de l'entraîneur adverse
+    pub graine_monde: Option<u64>,        // Graine de la ligue générée (None = équipes de démo seules)
+    pub marche: MarcheTransferts,
+    pub club_consulte_idx: usize,         // Club dont l'effectif est affiché sur l'écran des transferts
+    pub message_transfert: Option<String>,
+    pub contre_offre: Option<OffreTransfert>,  // Contre-offre en attente de réponse du joueur
+    pub rng_mercato: GenerateurAleatoire,      // Aléa du mercato IA, reproductible avec la graine du monde
 }
 
 impl Default for EtatJeu {
@@ -41,15 +56,30 @@ impl Default for EtatJeu {
         let mut equipe2 = Equipe::new(2, "Les Chercheurs Bleus");
         equipe2.budget = 500_000;
 
+        let equipes = vec![equipe1, equipe2];
+        let championnat = Championnat::new(1, &equipes);
+
         Self {
-            equipes: vec![equipe1, equipe2],
+            equipes,
             match_actuel: None,
             equipe_selectionnee_idx: None,
             joueur_selectionne_id: None,
             id_compteur: 100,
             match_compteur: 1,
-            saison: 1,
+            championnat,
+            coupe: None,
+            critere_coupe: CritereTetesDeSerie::default(),
+            format_coupe: FormatConfrontation::default(),
             joueurs_disponibles: joueurs,
+            jours_repos_entre_matchs: 3,
+            regles_match: RegleMatch::default(),
+            difficulte_ia: NiveauDifficulte::default(),
+            graine_monde: None,
+            marche: MarcheTransferts::new(),
+            club_consulte_idx: 0,
+            message_transfert: None,
+            contre_offre: None,
+            rng_mercato: GenerateurAleatoire::new(GenerateurAleatoire::graine_aleatoire()),
         }
     }
 }
@@ -71,12 +101,30 @@ impl EtatJeu {
         self.equipe_selectionnee_idx.and_then(|idx| self.equipes.get_mut(idx))
     }
 
+    /// Club dirigé par le joueur (l'équipe sélectionnée, sinon la première)
+    pub fn equipe_joueur_id(&self) -> Option<u32> {
+        self.get_equipe_selectionnee().or(self.equipes.first()).map(|e| e.id)
+    }
+
     pub fn creer_match(&mut self, equipe1_id: u32, equipe2_id: u32) -> Result<(), String> {
+        // Les clubs IA recomposent leur onze : blessés et suspendus restent en tribune
+        let club_joueur = self.equipe_joueur_id();
+        for equipe in self.equipes.iter_mut()
+            .filter(|e| (e.id == equipe1_id || e.id == equipe2_id) && Some(e.id) != club_joueur)
+        {
+            equipe.selectionner_titulaires_auto();
+        }
+
         let equipe1 = self.equipes.iter().find(|e| e.id == equipe1_id)
             .ok_or("Équipe 1 non trouvée")?.clone();
         let equipe2 = self.equipes.iter().find(|e| e.id == equipe2_id)
             .ok_or("Équipe 2 non trouvée")?.clone();
 
+        for equipe in [&equipe1, &equipe2] {
+            if let Some(j) = equipe.get_titulaires().iter().find(|j| !j.est_disponible()) {
+                return Err(format!("{} ({}) n'est pas disponible (blessé/suspendu)", j.prenom, equipe.nom));
+            }
+        }
         if equipe1.get_titulaires().len() < 5 {
             return Err(format!("L'équipe {} n'a pas assez de titulaires (min 5)", equipe1.nom));
         }
@@ -86,7 +134,100 @@ impl EtatJeu {
 
         let id = self.match_compteur;
         self.match_compteur += 1;
-        self.match_actuel = Some(MoteurMatch::nouveau(id, equipe1, equipe2));
+        let mut moteur = MoteurMatch::nouveau(id, equipe1, equipe2, self.regles_match);
+        // Tout club autre que celui du joueur est dirigé par l'IA, qu'il reçoive ou se déplace
+        for (est_domicile, equipe_id) in [(true, equipe1_id), (false, equipe2_id)] {
+            if Some(equipe_id) == club_joueur {
+                // Le joueur déclenche lui-même les capacités de son équipe
+                if est_domicile {
+                    moteur.capacites_auto_domicile = false;
+                } else {
+                    moteur.capacites_auto_exterieur = false;
+                }
+            } else {
+                moteur.confier_a_l_ia(est_domicile, self.difficulte_ia, StyleEntraineur::default());
+            }
+        }
+        self.match_actuel = Some(moteur);
+        Ok(())
+    }
+
+    /// Préparer le prochain match du club du joueur : les rencontres IA contre IA programmées
+    /// avant la sienne sont jouées d'office, avec un arrêt à la trêve. Sans rencontre au
+    /// calendrier, match amical.
+    pub fn creer_prochain_match(&mut self) -> Result<(), String> {
+        let club_id = self.equipe_joueur_id().ok_or("Aucun club à diriger")?;
+        while let Some(rencontre) = self.championnat.prochaine_rencontre() {
+            if rencontre.concerne(club_id) { break; }
+            let (domicile_id, exterieur_id) = (rencontre.domicile_id, rencontre.exterieur_id);
+            let fenetre_avant = self.marche.fenetre_ouverte;
+            match self.creer_match(domicile_id, exterieur_id) {
+                Ok(()) => {
+                    if let Some(m) = self.match_actuel.as_mut() {
+                        m.simuler_jusqu_a_la_fin();
+                    }
+                    self.synchroniser_match_vers_equipes();
+                }
+                // Un club IA qui ne peut pas aligner cinq joueurs perd sur tapis vert (3-0,
+                // 0-0 si aucun des deux ne le peut) : le championnat ne reste pas bloqué
+                Err(erreur) => {
+                    let (buts_d, buts_e) = match (self.est_forfait(domicile_id), self.est_forfait(exterieur_id)) {
+                        (true, false) => (0, 3),
+                        (false, true) => (3, 0),
+                        _ => (0, 0),
+                    };
+                    self.championnat.enregistrer_resultat(domicile_id, exterieur_id, buts_d, buts_e).map_err(|_| erreur)?;
+                    // La journée compte quand même : suspensions purgées, blessés qui se soignent
+                    let jours_repos = self.jours_repos_entre_matchs;
+                    for equipe in self.equipes.iter_mut().filter(|e| e.id == domicile_id || e.id == exterieur_id) {
+                        for j in &mut equipe.joueurs {
+                            j.cloturer_match();
+                        }
+                        equipe.recuperer_entre_matchs(jours_repos);
+                    }
+                    self.suivre_fenetre_transferts();
+                }
+            }
+            // On s'arrête à la trêve pour laisser le joueur recruter ; la fenêtre reste
+            // ouverte et la prochaine demande reprend là où on s'est arrêté
+            if self.marche.fenetre_ouverte != fenetre_avant && self.marche.fenetre_ouverte == Some(FenetreTransferts::Hiver) {
+                return Err("trêve hivernale, le mercato d'hiver vient d'ouvrir".to_string());
+            }
+        }
+
+        let (domicile_id, exterieur_id) = match self.championnat.prochaine_rencontre_de(club_id) {
+            Some(rencontre) => (rencontre.domicile_id, rencontre.exterieur_id),
+            None => {
+                let adversaire = self.equipes.iter().find(|e| e.id != club_id).ok_or("Aucun adversaire disponible")?;
+                (club_id, adversaire.id)
+            }
+        };
+        self.creer_match(domicile_id, exterieur_id)?;
+        // Le club du joueur reprend la compétition : la fenêtre de transferts se referme
+        self.marche.fermer_fenetre();
+        Ok(())
+    }
+
+    /// Tirer le tableau d'une nouvelle coupe selon le critère et le format choisis
+    pub fn lancer_coupe(&mut self) -> Result<(), String> {
+        let tetes_de_serie = self.critere_coupe.ordonner(&self.equipes, &self.championnat);
+        let nom = format!("Coupe des Labos {}", self.championnat.saison);
+        self.coupe = Some(Coupe::tirer(&nom, &tetes_de_serie, self.format_coupe)?);
+        Ok(())
+    }
+
+    /// Préparer le prochain match de la coupe en cours
+    pub fn creer_match_coupe(&mut self) -> Result<(), String> {
+        let affiche = self.coupe.as_ref()
+            .ok_or("Aucune coupe en cours")?
+            .prochaine_affiche()
+            .ok_or("La coupe est terminée")?;
+        self.creer_match(affiche.domicile_id, affiche.exterieur_id)?;
+        if let Some(m) = self.match_actuel.as_mut() {
+            m.competition = Competition::Coupe;
+            m.buts_aller = affiche.buts_aller;
+            m.regles = m.regles.pour_match_de_coupe(affiche.decisive);
+        }
         Ok(())
     }
 
@@ -96,15 +237,20 @@ impl EtatJeu {
             return Err("Joueur non trouvé".to_string());
         }
 
+        // Les ids de joueurs sont uniques dans toute la ligue
+        let id = self.joueurs_disponibles[joueur_idx].id;
+        if let Some(club) = self.equipes.iter().find(|e| e.get_joueur(id).is_some()) {
+            return Err(format!("Un joueur d'id {} évolue déjà à {}", id, club.nom));
+        }
+
         let equipe = self.equipes.iter_mut().find(|e| e.id == equipe_id)
             .ok_or("Équipe non trouvée")?;
 
-        if equipe.joueurs.len() >= 15 {
-            return Err("L'équipe est complète (15 joueurs max)".to_string());
-        }
-
-        let joueur = self.joueurs_disponibles.remove(joueur_idx);
-        equipe.joueurs.push(joueur);
+        // ajouter_joueur fait respecter la taille maximale de l'effectif
+        let mut joueur = self.joueurs_disponibles[joueur_idx].clone();
+        joueur.contrat = Contrat::pour_valeur(joueur.valeur_marchande());
+        equipe.ajouter_joueur(joueur)?;
+        self.joueurs_disponibles.remove(joueur_idx);
         Ok(())
     }
 
@@ -114,10 +260,12 @@ impl EtatJeu {
 
         // Équipe 1: Roland, Loïc, David, Thibault, Henry, Romain, Théo, Franck
         let mut equipe1 = Equipe::new(1, "Les Scientifiques Rouges");
+        equipe1.budget = 500_000;
         let ids_equipe1 = [1u32, 2, 3, 4, 5, 6, 7, 8]; // Roland, Loïc, David, Thibault, Henry, Romain, Théo, Franck
 
         // Équipe 2: Aurélien, Lucien, Joffrey, Yacine, Djilani, Médéric, Guillaume
         let mut equipe2 = Equipe::new(2, "Les Chercheurs Bleus");
+        equipe2.budget = 500_000;
         let ids_equipe2 = [9u32, 10, 11, 12, 13, 14, 15]; // Aurélien, Lucien, Joffrey, Yacine, Djilani, Médéric, Guillaume
 
         for j in tous_joueurs {
@@ -132,24 +280,163 @@ impl EtatJeu {
         equipe1.selectionner_titulaires_auto();
         equipe2.selectionner_titulaires_auto();
 
+        // Chaque équipe part avec un inventaire de power-ups plein
+        for equipe in [&mut equipe1, &mut equipe2] {
+            while !equipe.inventaire_power_ups.est_plein() {
+                equipe.inventaire_power_ups.ajouter(TypePowerUp::generer_aleatoire());
+            }
+        }
+
         self.equipes = vec![equipe1, equipe2];
+        self.championnat = Championnat::new(1, &self.equipes);
+        self.coupe = None;
+        self.graine_monde = None;
+        self.rng_mercato = GenerateurAleatoire::new(GenerateurAleatoire::graine_aleatoire());
+        self.marche = MarcheTransferts::new();
+        self.club_consulte_idx = 0;
+        self.message_transfert = None;
+        self.contre_offre = None;
         self.joueurs_disponibles.clear();
     }
 
+    /// Compléter les équipes de démo par des clubs générés jusqu'à `nb_equipes`,
+    /// au niveau moyen des joueurs réels. Une même graine redonne la même ligue.
+    pub fn generer_monde(&mut self, graine: u64, nb_equipes: usize) -> Result<(), String> {
+        if !(TAILLE_LIGUE_MIN..=TAILLE_LIGUE_MAX).contains(&nb_equipes) {
+            return Err(format!("Une ligue compte entre {} et {} équipes", TAILLE_LIGUE_MIN, TAILLE_LIGUE_MAX));
+        }
+        self.initialiser_equipes_demo();
+
+        let joueurs: Vec<&Joueur> = self.equipes.iter().flat_map(|e| e.joueurs.iter()).collect();
+        let note_moyenne = joueurs.iter().map(|j| j.stats_effectives.note_globale()).sum::<f32>() / joueurs.len().max(1) as f32;
+
+        let mut generateur = GenerateurMonde::new(graine).apres(&self.equipes);
+        let clubs = generateur.generer_clubs(nb_equipes - self.equipes.len(), note_moyenne);
+        self.equipes.extend(clubs);
+        self.championnat = Championnat::new(1, &self.equipes);
+        self.graine_monde = Some(graine);
+        // Flux distinct de celui qui a généré les clubs
+        self.rng_mercato = GenerateurAleatoire::new(graine.wrapping_add(1));
+        Ok(())
+    }
+
     pub fn synchroniser_match_vers_equipes(&mut self) {
         if let Some(ref match_fini) = self.match_actuel {
             if match_fini.periode == crate::models::match_engine::PeriodeMatch::Termine {
                 let domicile_id = match_fini.equipe_domicile.id;
                 let exterieur_id = match_fini.equipe_exterieur.id;
 
+                let (buts_d, buts_e) = (match_fini.score_domicile, match_fini.score_exterieur);
+                let mut vainqueur_coupe = None;
+                // Un match hors calendrier (amical) ne compte pas au classement et ne rapporte rien
+                let officiel = match match_fini.competition {
+                    Competition::Championnat => {
+                        self.championnat.enregistrer_resultat(domicile_id, exterieur_id, buts_d, buts_e).is_ok()
+                    }
+                    Competition::Coupe => match self.coupe.as_mut() {
+                        Some(coupe) => {
+                            let deja_gagnee = coupe.vainqueur.is_some();
+                            let ok = coupe.enregistrer_resultat(domicile_id, exterieur_id, buts_d, buts_e, match_fini.get_vainqueur()).is_ok();
+                            if !deja_gagnee {
+                                vainqueur_coupe = coupe.vainqueur.map(|id| (id, coupe.nom.clone()));
+                            }
+                            ok
+                        }
+                        None => false,
+                    },
+                };
+
                 if let Some(equipe) = self.equipes.iter_mut().find(|e| e.id == domicile_id) {
                     *equipe = match_fini.equipe_domicile.clone();
                 }
                 if let Some(equipe) = self.equipes.iter_mut().find(|e| e.id == exterieur_id) {
                     *equipe = match_fini.equipe_exterieur.clone();
                 }
+
+                // Les joueurs récupèrent jusqu'au prochain match, sans repartir forcément à 100%
+                let jours_repos = self.jours_repos_entre_matchs;
+                for equipe in self.equipes.iter_mut().filter(|e| e.id == domicile_id || e.id == exterieur_id) {
+                    equipe.recuperer_entre_matchs(jours_repos);
+                }
+
+                // Salaires, recette au guichet et prime du vainqueur de la coupe
+                let saison = self.championnat.saison;
+                if officiel {
+                    let noms = (match_fini.equipe_domicile.nom.clone(), match_fini.equipe_exterieur.nom.clone());
+                    if let Some(equipe) = self.equipes.iter_mut().find(|e| e.id == domicile_id) {
+                        equipe.regler_match(saison, true, &noms.1);
+                    }
+                    if let Some(equipe) = self.equipes.iter_mut().find(|e| e.id == exterieur_id) {
+                        equipe.regler_match(saison, false, &noms.0);
+                    }
+                }
+                if let Some((id, nom_coupe)) = vainqueur_coupe {
+                    if let Some(equipe) = self.equipes.iter_mut().find(|e| e.id == id) {
+                        equipe.enregistrer_operation(saison, NatureOperation::Primes, format!("Victoire en {}", nom_coupe), PRIME_COUPE as i64);
+                    }
+                }
+
+                self.suivre_fenetre_transferts();
+            }
+        }
+    }
+
+    /// Après un résultat de championnat : la trêve ouvre le mercato d'hiver, où les clubs IA
+    /// passent une fois
+    fn suivre_fenetre_transferts(&mut self) {
+        self.marche.actualiser_fenetre(&self.championnat);
+        if self.marche.fenetre(&self.championnat) == Some(FenetreTransferts::Hiver) {
+            self.lancer_mercato_ia();
+        }
+    }
+
+    /// Un club est forfait s'il n'a pas cinq titulaires disponibles
+    fn est_forfait(&self, equipe_id: u32) -> bool {
+        match self.get_equipe(equipe_id) {
+            Some(equipe) => {
+                let titulaires = equipe.get_titulaires();
+                titulaires.len() < 5 || titulaires.iter().any(|j| !j.est_disponible())
             }
+            None => true,
+        }
+    }
+
+    /// Archiver la saison terminée et lancer la suivante (mercato d'été compris)
+    pub fn cloturer_saison(&mut self) -> Result<(), String> {
+        self.championnat.cloturer_saison(&mut self.equipes)?;
+        // Fins de contrat : les joueurs libérés rejoignent la liste des joueurs disponibles
+        let club_joueur = self.equipe_joueur_id();
+        for equipe in self.equipes.iter_mut() {
+            let partants = equipe.echeance_contrats(Some(equipe.id) != club_joueur);
+            self.joueurs_disponibles.extend(partants);
         }
+        self.lancer_mercato_ia();
+        Ok(())
+    }
+
+    /// Proposer une prolongation de 2 saisons à un joueur du club en fin de contrat
+    pub fn prolonger_contrat(&mut self, joueur_id: u32, salaire: u32) -> Result<ReponseOffre, String> {
+        let club_id = self.equipe_joueur_id().ok_or("Aucun club à diriger")?;
+        let equipe = self.equipes.iter_mut().find(|e| e.id == club_id).ok_or("Équipe non trouvée")?;
+        equipe.prolonger_contrat(joueur_id, 2, salaire)
+    }
+
+    /// Transferts entre clubs IA, une fois par fenêtre ouverte
+    pub fn lancer_mercato_ia(&mut self) -> usize {
+        let exclus = self.equipe_joueur_id();
+        self.marche.mercato_ia(&mut self.equipes, &self.championnat, exclus, &mut self.rng_mercato)
+    }
+
+    /// Offre du club du joueur ; une contre-offre est gardée en attente de sa réponse
+    pub fn faire_offre(&mut self, vendeur_id: u32, joueur_id: u32, montant: u32) -> Result<ReponseOffre, String> {
+        let acheteur_id = self.equipe_joueur_id().ok_or("Aucun club à diriger")?;
+        let offre = OffreTransfert { joueur_id, vendeur_id, acheteur_id, montant };
+        let reponse = self.marche.proposer(&mut self.equipes, &self.championnat, offre)?;
+        self.contre_offre = match reponse {
+            ReponseOffre::ContreOffre(montant) => Some(OffreTransfert { montant, ..offre }),
+            _ => None,
+        };
+        Ok(reponse)
     }
 }
 
@@ -172,3 +459,161 @@ pub struct EvtSubstitution {
     pub sortant_id: u32,
     pub entrant_id: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_un_carton_rouge_ne_bloque_pas_la_journee_suivante() {
+        let mut etat = EtatJeu::default();
+        etat.initialiser_equipes_demo();
+        etat.equipe_selectionnee_idx = Some(0);
+
+        // Journée N : un titulaire du club IA est expulsé
+        etat.creer_match(1, 2).unwrap();
+        let m = etat.match_actuel.as_mut().unwrap();
+        let expulse_id = m.equipe_exterieur.get_titulaires()[0].id;
+        m.demarrer();
+        m.equipe_exterieur.get_joueur_mut(expulse_id).unwrap().expulser(2);
+        m.simuler_jusqu_a_la_fin();
+        etat.synchroniser_match_vers_equipes();
+        assert!(!etat.get_equipe(2).unwrap().get_joueur(expulse_id).unwrap().est_disponible());
+
+        // Journée N+1 : le club IA aligne un onze complet sans le suspendu
+        etat.get_equipe_mut(1).unwrap().selectionner_titulaires_auto();
+        etat.creer_match(2, 1).unwrap();
+        let ia = &etat.match_actuel.as_ref().unwrap().equipe_domicile;
+        assert_eq!(ia.get_titulaires().len(), 5);
+        assert!(ia.get_titulaires().iter().all(|j| j.id != expulse_id));
+    }
+
+    #[test]
+    fn test_les_rencontres_ia_se_jouent_avant_celle_du_joueur() {
+        let mut etat = EtatJeu::default();
+        etat.generer_monde(4, 8).unwrap();
+        // Club du joueur dont la première rencontre n'ouvre pas le calendrier
+        let club_id = etat.championnat.calendrier.iter().rev().find(|r| r.journee == 1).unwrap().domicile_id;
+        etat.equipe_selectionnee_idx = etat.equipes.iter().position(|e| e.id == club_id);
+        assert!(!etat.championnat.prochaine_rencontre().unwrap().concerne(club_id));
+
+        etat.creer_prochain_match().unwrap();
+        let m = etat.match_actuel.as_ref().unwrap();
+        assert!(m.equipe_domicile.id == club_id || m.equipe_exterieur.id == club_id);
+        assert_eq!(etat.championnat.prochaine_rencontre().map(|r| r.concerne(club_id)), Some(true));
+        assert!(etat.championnat.calendrier.iter().filter(|r| r.est_jouee()).all(|r| !r.concerne(club_id)));
+        // Le club adverse est dirigé par l'IA, celui du joueur ne l'est pas
+        let ia_a_domicile = m.equipe_exterieur.id == club_id;
+        assert_eq!(m.entraineur_domicile.is_some(), ia_a_domicile);
+        assert_eq!(m.entraineur_exterieur.is_some(), !ia_a_domicile);
+    }
+
+    #[test]
+    fn test_mercato_ia_reproductible_avec_la_graine() {
+        let historique = |graine| {
+            let mut etat = EtatJeu::default();
+            etat.generer_monde(graine, 10).unwrap();
+            for e in &mut etat.equipes {
+                e.budget = 3_000_000;
+                e.reputation = 0.5;
+            }
+            etat.lancer_mercato_ia();
+            etat.marche.historique
+        };
+        let reference = historique(24);
+        assert!(!reference.is_empty());
+        assert_eq!(historique(24), reference);
+    }
+
+    #[test]
+    fn test_la_treve_jouee_par_l_ia_laisse_le_joueur_recruter() {
+        let mut etat = EtatJeu::default();
+        etat.generer_monde(6, 8).unwrap();
+        // Club du joueur absent de la rencontre qui boucle la phase aller
+        let mi_saison = etat.championnat.calendrier.len() / 2;
+        let derniere_aller = etat.championnat.calendrier[mi_saison - 1].clone();
+        let club_id = etat.equipes.iter().map(|e| e.id).find(|id| !derniere_aller.concerne(*id)).unwrap();
+        etat.equipe_selectionnee_idx = etat.equipes.iter().position(|e| e.id == club_id);
+
+        let mut treve = false;
+        while etat.championnat.calendrier.iter().filter(|r| r.est_jouee()).count() <= mi_saison {
+            // Le joueur recompose son onze entre deux matchs
+            etat.get_equipe_mut(club_id).unwrap().selectionner_titulaires_auto();
+            match etat.creer_prochain_match() {
+                Ok(()) => {
+                    let m = etat.match_actuel.as_mut().unwrap();
+                    m.simuler_jusqu_a_la_fin();
+                    etat.synchroniser_match_vers_equipes();
+                }
+                Err(_) => {
+                    treve = true;
+                    assert_eq!(etat.marche.fenetre(&etat.championnat), Some(FenetreTransferts::Hiver));
+                    break;
+                }
+            }
+        }
+        assert!(treve, "La boucle doit s'arrêter à la trêve");
+
+        // Les rencontres IA suivantes ne referment pas le mercato avant le match du joueur
+        let rencontre = etat.championnat.prochaine_rencontre().unwrap().clone();
+        if !rencontre.concerne(club_id) {
+            etat.creer_match(rencontre.domicile_id, rencontre.exterieur_id).unwrap();
+            etat.match_actuel.as_mut().unwrap().simuler_jusqu_a_la_fin();
+            etat.synchroniser_match_vers_equipes();
+        }
+        let (vendeur_id, joueur_id) = etat.equipes.iter()
+            .filter(|e| e.id != club_id)
+            .find_map(|e| e.joueurs.first().map(|j| (e.id, j.id)))
+            .unwrap();
+        assert!(etat.faire_offre(vendeur_id, joueur_id, 5_000).is_ok());
+
+        // La demande suivante reprend la saison et referme la fenêtre
+        etat.get_equipe_mut(club_id).unwrap().selectionner_titulaires_auto();
+        etat.creer_prochain_match().unwrap();
+        assert_eq!(etat.marche.fenetre_ouverte, None);
+    }
+
+    #[test]
+    fn test_un_club_ia_incomplet_perd_par_forfait() {
+        let mut etat = EtatJeu::default();
+        etat.generer_monde(4, 8).unwrap();
+        let premiere = etat.championnat.calendrier[0].clone();
+        let club_id = etat.equipes.iter().map(|e| e.id).find(|id| !premiere.concerne(*id)).unwrap();
+        etat.equipe_selectionnee_idx = etat.equipes.iter().position(|e| e.id == club_id);
+
+        // Le club qui reçoit la première rencontre n'a plus que quatre joueurs disponibles
+        let decimee = etat.get_equipe_mut(premiere.domicile_id).unwrap();
+        for j in decimee.joueurs.iter_mut().skip(4) {
+            j.expulser(1);
+            j.cloturer_match();
+        }
+
+        etat.creer_prochain_match().unwrap();
+        let rencontre = &etat.championnat.calendrier[0];
+        assert_eq!(rencontre.score, Some((0, 3)));
+        let m = etat.match_actuel.as_ref().unwrap();
+        assert!(m.equipe_domicile.id == club_id || m.equipe_exterieur.id == club_id);
+        // Le forfait a valu match purgé pour les suspendus
+        assert!(etat.get_equipe(premiere.domicile_id).unwrap().joueurs.iter().all(|j| j.est_disponible()));
+    }
+
+    #[test]
+    fn test_ids_de_joueurs_uniques_dans_la_ligue() {
+        let mut etat = EtatJeu::default();
+        etat.generer_monde(9, 8).unwrap();
+        let mut ids: Vec<u32> = etat.equipes.iter().flat_map(|e| e.joueurs.iter().map(|j| j.id)).collect();
+        let total = ids.len();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), total);
+
+        // Un joueur libre dont l'id est déjà pris ne peut rejoindre aucun club
+        let mut doublon = etat.equipes[1].joueurs[0].clone();
+        doublon.prenom = "Doublon".to_string();
+        etat.joueurs_disponibles.push(doublon.clone());
+        let idx = etat.joueurs_disponibles.len() - 1;
+        let club_id = etat.equipes[0].id;
+        assert!(etat.ajouter_joueur_a_equipe(idx, club_id).is_err());
+        assert!(etat.equipes[1].ajouter_joueur(doublon).is_err());
+    }
+}
@@ -0,0 +1,312 @@
+use serde::{Deserialize, Serialize};
+use crate::models::team::Equipe;
+use crate::models::finances::{prime_classement, NatureOperation};
+
+/// Rencontre du calendrier, avec son score une fois jouée
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rencontre {
+    pub journee: u32,
+    pub domicile_id: u32,
+    pub exterieur_id: u32,
+    pub score: Option<(u32, u32)>,  // (domicile, extérieur)
+}
+
+impl Rencontre {
+    fn new(journee: u32, domicile_id: u32, exterieur_id: u32) -> Self {
+        Self { journee, domicile_id, exterieur_id, score: None }
+    }
+
+    pub fn est_jouee(&self) -> bool {
+        self.score.is_some()
+    }
+
+    pub fn concerne(&self, equipe_id: u32) -> bool {
+        self.domicile_id == equipe_id || self.exterieur_id == equipe_id
+    }
+}
+
+/// Ligne du classement, calculée à partir des résultats enregistrés
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LigneClassement {
+    pub equipe_id: u32,
+    pub nom: String,
+    pub victoires: u32,
+    pub nuls: u32,
+    pub defaites: u32,
+    pub buts_marques: u32,
+    pub buts_encaisses: u32,
+}
+
+impl LigneClassement {
+    fn new(equipe_id: u32, nom: &str) -> Self {
+        Self { equipe_id, nom: nom.to_string(), victoires: 0, nuls: 0, defaites: 0, buts_marques: 0, buts_encaisses: 0 }
+    }
+
+    fn ajouter_resultat(&mut self, marques: u32, encaisses: u32) {
+        self.buts_marques += marques;
+        self.buts_encaisses += encaisses;
+        match marques.cmp(&encaisses) {
+            std::cmp::Ordering::Greater => self.victoires += 1,
+            std::cmp::Ordering::Equal => self.nuls += 1,
+            std::cmp::Ordering::Less => self.defaites += 1,
+        }
+    }
+
+    pub fn joues(&self) -> u32 {
+        self.victoires + self.nuls + self.defaites
+    }
+
+    pub fn points(&self) -> u32 {
+        self.victoires * 3 + self.nuls
+    }
+
+    pub fn difference_buts(&self) -> i32 {
+        self.buts_marques as i32 - self.buts_encaisses as i32
+    }
+
+    /// Critères de départage dans l'ordre : points, différence de buts, buts marqués
+    fn criteres(&self) -> (u32, i32, u32) {
+        (self.points(), self.difference_buts(), self.buts_marques)
+    }
+}
+
+/// Classement final d'une saison terminée
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaisonArchivee {
+    pub saison: u32,
+    pub classement: Vec<LigneClassement>,
+}
+
+impl SaisonArchivee {
+    pub fn champion(&self) -> Option<&LigneClassement> {
+        self.classement.first()
+    }
+}
+
+/// Championnat en matchs aller-retour : calendrier, journées et classement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Championnat {
+    pub saison: u32,
+    pub equipes: Vec<(u32, String)>,  // (id, nom) des participants
+    pub calendrier: Vec<Rencontre>,
+    pub archives: Vec<SaisonArchivee>,
+}
+
+impl Championnat {
+    pub fn new(saison: u32, equipes: &[Equipe]) -> Self {
+        let equipes: Vec<(u32, String)> = equipes.iter().map(|e| (e.id, e.nom.clone())).collect();
+        let ids: Vec<u32> = equipes.iter().map(|(id, _)| *id).collect();
+        Self {
+            saison,
+            equipes,
+            calendrier: generer_calendrier(&ids),
+            archives: Vec::new(),
+        }
+    }
+
+    pub fn nb_journees(&self) -> u32 {
+        self.calendrier.iter().map(|r| r.journee).max().unwrap_or(0)
+    }
+
+    /// Première journée comportant encore un match à jouer
+    pub fn journee_en_cours(&self) -> Option<u32> {
+        self.prochaine_rencontre().map(|r| r.journee)
+    }
+
+    pub fn rencontres_journee(&self, journee: u32) -> Vec<&Rencontre> {
+        self.calendrier.iter().filter(|r| r.journee == journee).collect()
+    }
+
+    pub fn prochaine_rencontre(&self) -> Option<&Rencontre> {
+        self.calendrier.iter().find(|r| !r.est_jouee())
+    }
+
+    pub fn prochaine_rencontre_de(&self, equipe_id: u32) -> Option<&Rencontre> {
+        self.calendrier.iter().find(|r| !r.est_jouee() && r.concerne(equipe_id))
+    }
+
+    pub fn est_terminee(&self) -> bool {
+        !self.calendrier.is_empty() && self.calendrier.iter().all(|r| r.est_jouee())
+    }
+
+    /// Reporter le score d'un match dans la première rencontre non jouée entre ces deux équipes
+    pub fn enregistrer_resultat(&mut self, domicile_id: u32, exterieur_id: u32, buts_domicile: u32, buts_exterieur: u32) -> Result<u32, String> {
+        let rencontre = self.calendrier.iter_mut()
+            .find(|r| !r.est_jouee() && r.domicile_id == domicile_id && r.exterieur_id == exterieur_id)
+            .ok_or("Aucune rencontre à jouer entre ces deux équipes")?;
+        rencontre.score = Some((buts_domicile, buts_exterieur));
+        Ok(rencontre.journee)
+    }
+
+    /// Tableau des équipes `ids`, limité aux rencontres qu'elles ont jouées entre elles
+    fn tableau(&self, ids: &[u32]) -> Vec<LigneClassement> {
+        let mut lignes: Vec<LigneClassement> = self.equipes.iter()
+            .filter(|(id, _)| ids.contains(id))
+            .map(|(id, nom)| LigneClassement::new(*id, nom))
+            .collect();
+        for r in &self.calendrier {
+            let Some((buts_dom, buts_ext)) = r.score else { continue; };
+            if !ids.contains(&r.domicile_id) || !ids.contains(&r.exterieur_id) { continue; }
+            for ligne in lignes.iter_mut() {
+                if ligne.equipe_id == r.domicile_id {
+                    ligne.ajouter_resultat(buts_dom, buts_ext);
+                } else if ligne.equipe_id == r.exterieur_id {
+                    ligne.ajouter_resultat(buts_ext, buts_dom);
+                }
+            }
+        }
+        lignes
+    }
+
+    /// Classement : points, différence de buts, buts marqués, puis confrontations directes
+    pub fn classement(&self) -> Vec<LigneClassement> {
+        let ids: Vec<u32> = self.equipes.iter().map(|(id, _)| *id).collect();
+        let mut lignes = self.tableau(&ids);
+        lignes.sort_by_key(|l| std::cmp::Reverse(l.criteres()));
+
+        // Départager les égalités par le mini-classement des matchs entre équipes ex aequo
+        let mut debut = 0;
+        while debut < lignes.len() {
+            let criteres = lignes[debut].criteres();
+            let fin = debut + lignes[debut..].iter().take_while(|l| l.criteres() == criteres).count();
+            if fin - debut > 1 {
+                let ex_aequo: Vec<u32> = lignes[debut..fin].iter().map(|l| l.equipe_id).collect();
+                let confrontations = self.tableau(&ex_aequo);
+                let critere_direct = |id: u32| confrontations.iter()
+                    .find(|l| l.equipe_id == id)
+                    .map(|l| l.criteres())
+                    .unwrap_or_default();
+                lignes[debut..fin].sort_by(|a, b| {
+                    critere_direct(b.equipe_id).cmp(&critere_direct(a.equipe_id))
+                        .then_with(|| a.nom.cmp(&b.nom))
+                });
+            }
+            debut = fin;
+        }
+        lignes
+    }
+
+    /// Fin de saison : archiver le classement final, remettre les compteurs des équipes
+    /// à zéro et établir le calendrier de la saison suivante
+    pub fn cloturer_saison(&mut self, equipes: &mut [Equipe]) -> Result<(), String> {
+        if !self.est_terminee() {
+            return Err("La saison n'est pas terminée".to_string());
+        }
+        let classement = self.classement();
+        // Primes de fin de saison selon le rang final
+        for (rang, ligne) in classement.iter().enumerate() {
+            if let Some(equipe) = equipes.iter_mut().find(|e| e.id == ligne.equipe_id) {
+                let prime = prime_classement(classement.len(), rang + 1);
+                equipe.enregistrer_operation(self.saison, NatureOperation::Primes,
+                    format!("Prime de championnat ({}e)", rang + 1), prime as i64);
+            }
+        }
+        self.archives.push(SaisonArchivee { saison: self.saison, classement });
+
+        for equipe in equipes.iter_mut() {
+            equipe.reinitialiser_saison();
+        }
+        let archives = std::mem::take(&mut self.archives);
+        *self = Championnat::new(self.saison + 1, equipes);
+        self.archives = archives;
+        Ok(())
+    }
+}
+
+/// Calendrier aller-retour (méthode du cercle) : chaque équipe joue une fois par journée,
+/// sauf l'équipe exemptée quand le nombre d'équipes est impair
+pub fn generer_calendrier(equipes_ids: &[u32]) -> Vec<Rencontre> {
+    let mut ids: Vec<Option<u32>> = equipes_ids.iter().map(|id| Some(*id)).collect();
+    if ids.len() < 2 {
+        return Vec::new();
+    }
+    if ids.len() % 2 == 1 {
+        ids.push(None);
+    }
+    let n = ids.len();
+    let nb_tours = (n - 1) as u32;
+
+    let mut calendrier = Vec::new();
+    for tour in 0..nb_tours {
+        for i in 0..n / 2 {
+            if let (Some(a), Some(b)) = (ids[i], ids[n - 1 - i]) {
+                // Alterner domicile et extérieur d'une journée à l'autre
+                let (domicile, exterieur) = match (tour as usize + i) % 2 {
+                    0 => (a, b),
+                    _ => (b, a),
+                };
+                calendrier.push(Rencontre::new(tour + 1, domicile, exterieur));
+            }
+        }
+        ids[1..].rotate_right(1);
+    }
+
+    // Phase retour : mêmes affiches, terrains inversés
+    let retour: Vec<Rencontre> = calendrier.iter()
+        .map(|r| Rencontre::new(r.journee + nb_tours, r.exterieur_id, r.domicile_id))
+        .collect();
+    calendrier.extend(retour);
+    calendrier
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn creer_championnat(nb_equipes: u32) -> (Championnat, Vec<Equipe>) {
+        let equipes: Vec<Equipe> = (1..=nb_equipes).map(|id| Equipe::new(id, &format!("Équipe {}", id))).collect();
+        (Championnat::new(1, &equipes), equipes)
+    }
+
+    #[test]
+    fn test_calendrier_aller_retour() {
+        for nb_equipes in [2u32, 5, 6] {
+            let (championnat, _) = creer_championnat(nb_equipes);
+            let nb_tours = if nb_equipes % 2 == 0 { nb_equipes - 1 } else { nb_equipes };
+            assert_eq!(championnat.nb_journees(), 2 * nb_tours);
+            assert_eq!(championnat.calendrier.len() as u32, nb_equipes * (nb_equipes - 1));
+
+            // Chaque affiche est jouée une fois sur chaque terrain
+            for a in 1..=nb_equipes {
+                for b in (1..=nb_equipes).filter(|b| *b != a) {
+                    let n = championnat.calendrier.iter().filter(|r| r.domicile_id == a && r.exterieur_id == b).count();
+                    assert_eq!(n, 1, "{} contre {}", a, b);
+                }
+            }
+            // Personne ne joue deux fois la même journée
+            for journee in 1..=championnat.nb_journees() {
+                let rencontres = championnat.rencontres_journee(journee);
+                for id in 1..=nb_equipes {
+                    assert!(rencontres.iter().filter(|r| r.concerne(id)).count() <= 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_classement_departage_et_fin_de_saison() {
+        let (mut championnat, mut equipes) = creer_championnat(3);
+        assert!(championnat.cloturer_saison(&mut equipes).is_err());
+
+        // 1 et 2 terminent à égalité parfaite (4 pts, -1, 2 buts) : 2 a remporté la double confrontation
+        let resultats = [((1, 2), (1, 2)), ((2, 1), (0, 0)), ((1, 3), (1, 0)), ((3, 1), (1, 0)), ((2, 3), (0, 1)), ((3, 2), (1, 0))];
+        for ((dom, ext), (bd, be)) in resultats {
+            championnat.enregistrer_resultat(dom, ext, bd, be).unwrap();
+        }
+        assert!(championnat.enregistrer_resultat(1, 2, 0, 0).is_err());
+        assert!(championnat.est_terminee());
+
+        let classement = championnat.classement();
+        let ordre: Vec<u32> = classement.iter().map(|l| l.equipe_id).collect();
+        assert_eq!(ordre, vec![3, 2, 1]);
+        assert_eq!(classement[1].criteres(), classement[2].criteres());
+
+        equipes[0].victoires = 2;
+        championnat.cloturer_saison(&mut equipes).unwrap();
+        assert_eq!(championnat.saison, 2);
+        assert_eq!(championnat.archives[0].champion().map(|l| l.equipe_id), Some(3));
+        assert!(championnat.calendrier.iter().all(|r| !r.est_jouee()));
+        assert!(championnat.classement().iter().all(|l| l.joues() == 0));
+        assert_eq!(equipes[0].victoires, 0);
+    }
+}
@@ -0,0 +1,315 @@
+use serde::{Deserialize, Serialize};
+use crate::models::championnat::Championnat;
+use crate::models::team::Equipe;
+
+/// Format des confrontations de la coupe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FormatConfrontation {
+    #[default]
+    MatchSec,    // Un seul match, chez la tête de série
+    AllerRetour, // Deux matchs, retour chez la tête de série, au score cumulé
+}
+
+impl FormatConfrontation {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            FormatConfrontation::MatchSec => "Match sec",
+            FormatConfrontation::AllerRetour => "Aller-retour",
+        }
+    }
+
+    pub fn nb_manches(&self) -> usize {
+        match self {
+            FormatConfrontation::MatchSec => 1,
+            FormatConfrontation::AllerRetour => 2,
+        }
+    }
+}
+
+/// Score d'un match de coupe
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Manche {
+    pub domicile_id: u32,
+    pub exterieur_id: u32,
+    pub buts_domicile: u32,
+    pub buts_exterieur: u32,
+}
+
+/// Match de coupe à jouer, avec le score de l'aller s'il s'agit d'un retour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AfficheCoupe {
+    pub tour: usize,
+    pub domicile_id: u32,
+    pub exterieur_id: u32,
+    pub buts_aller: Option<(u32, u32)>,  // (domicile, extérieur) de ce match-ci
+    pub decisive: bool,                  // Il faut un vainqueur à l'issue de ce match
+}
+
+/// Confrontation du tableau ; `equipe_b` vide au premier tour signifie que `equipe_a` est exemptée
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Confrontation {
+    pub equipe_a: Option<u32>,  // Mieux classée : reçoit le match sec ou le retour
+    pub equipe_b: Option<u32>,
+    pub manches: Vec<Manche>,
+    pub vainqueur: Option<u32>,
+}
+
+impl Confrontation {
+    fn vide() -> Self {
+        Self { equipe_a: None, equipe_b: None, manches: Vec::new(), vainqueur: None }
+    }
+
+    pub fn est_exemption(&self) -> bool {
+        self.equipe_a.is_some() && self.equipe_b.is_none() && self.vainqueur.is_some()
+    }
+
+    /// Buts cumulés (a, b) sur les matchs déjà joués
+    pub fn cumul(&self) -> (u32, u32) {
+        let a = self.equipe_a.unwrap_or(0);
+        self.manches.iter().fold((0, 0), |(buts_a, buts_b), m| {
+            if m.domicile_id == a {
+                (buts_a + m.buts_domicile, buts_b + m.buts_exterieur)
+            } else {
+                (buts_a + m.buts_exterieur, buts_b + m.buts_domicile)
+            }
+        })
+    }
+}
+
+/// Coupe à élimination directe : tableau tiré selon les têtes de série
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Coupe {
+    pub nom: String,
+    pub format: FormatConfrontation,
+    pub tours: Vec<Vec<Confrontation>>,  // Du premier tour à la finale
+    pub vainqueur: Option<u32>,
+}
+
+impl Coupe {
+    /// Tirer le tableau : `tetes_de_serie` va de la meilleure à la moins bonne équipe.
+    /// Les meilleures sont exemptées du premier tour quand le nombre d'équipes
+    /// n'est pas une puissance de 2, et ne peuvent se croiser qu'en fin de tableau.
+    pub fn tirer(nom: &str, tetes_de_serie: &[u32], format: FormatConfrontation) -> Result<Self, String> {
+        if tetes_de_serie.len() < 2 {
+            return Err("Il faut au moins 2 équipes pour une coupe".to_string());
+        }
+        let taille = tetes_de_serie.len().next_power_of_two();
+        let places = ordre_du_tableau(taille);
+
+        let premier_tour: Vec<Confrontation> = places.chunks(2)
+            .map(|paire| {
+                let equipe_a = tetes_de_serie.get(paire[0]).copied();
+                let equipe_b = tetes_de_serie.get(paire[1]).copied();
+                let vainqueur = if equipe_b.is_none() { equipe_a } else { None };
+                Confrontation { equipe_a, equipe_b, manches: Vec::new(), vainqueur }
+            })
+            .collect();
+
+        let mut tours = vec![premier_tour];
+        while tours.last().map_or(0, |t| t.len()) > 1 {
+            let nb = tours.last().map_or(0, |t| t.len()) / 2;
+            tours.push((0..nb).map(|_| Confrontation::vide()).collect());
+        }
+
+        let mut coupe = Self { nom: nom.to_string(), format, tours, vainqueur: None };
+        // Les exemptés passent directement au tour suivant
+        for idx in 0..coupe.tours[0].len() {
+            if let Some(vainqueur) = coupe.tours[0][idx].vainqueur {
+                coupe.qualifier(0, idx, vainqueur);
+            }
+        }
+        Ok(coupe)
+    }
+
+    pub fn nom_tour(&self, tour: usize) -> String {
+        match self.tours.len() - tour {
+            1 => "Finale".to_string(),
+            2 => "Demi-finales".to_string(),
+            3 => "Quarts de finale".to_string(),
+            4 => "Huitièmes de finale".to_string(),
+            _ => format!("{}e tour", tour + 1),
+        }
+    }
+
+    pub fn est_terminee(&self) -> bool {
+        self.vainqueur.is_some()
+    }
+
+    /// Premier tour dont une confrontation reste à jouer
+    pub fn tour_en_cours(&self) -> Option<usize> {
+        self.prochaine_affiche().map(|a| a.tour)
+    }
+
+    /// Prochain match à jouer, dans l'ordre du tableau
+    pub fn prochaine_affiche(&self) -> Option<AfficheCoupe> {
+        let nb_manches = self.format.nb_manches();
+        for (tour, confrontations) in self.tours.iter().enumerate() {
+            for c in confrontations.iter().filter(|c| c.vainqueur.is_none()) {
+                let (Some(a), Some(b)) = (c.equipe_a, c.equipe_b) else { continue; };
+                let manche = c.manches.len();
+                let decisive = manche + 1 == nb_manches;
+                // La tête de série reçoit le dernier match
+                let (domicile_id, exterieur_id) = if decisive { (a, b) } else { (b, a) };
+                let buts_aller = (manche > 0).then(|| {
+                    let (buts_a, buts_b) = c.cumul();
+                    if domicile_id == a { (buts_a, buts_b) } else { (buts_b, buts_a) }
+                });
+                return Some(AfficheCoupe { tour, domicile_id, exterieur_id, buts_aller, decisive });
+            }
+        }
+        None
+    }
+
+    /// Enregistrer le score du prochain match entre ces deux équipes. Sur le match décisif,
+    /// `vainqueur` départage une égalité au cumul (prolongation, tirs au but).
+    pub fn enregistrer_resultat(&mut self, domicile_id: u32, exterieur_id: u32, buts_domicile: u32, buts_exterieur: u32, vainqueur: Option<u32>) -> Result<(), String> {
+        let affiche = self.prochaine_affiche()
+            .filter(|a| a.domicile_id == domicile_id && a.exterieur_id == exterieur_id)
+            .ok_or("Ce match n'est pas le prochain match de la coupe")?;
+        let (tour, idx) = self.tours[affiche.tour].iter().position(|c| {
+            c.vainqueur.is_none() && c.equipe_a.is_some_and(|a| a == domicile_id || a == exterieur_id)
+                && c.equipe_b.is_some_and(|b| b == domicile_id || b == exterieur_id)
+        }).map(|idx| (affiche.tour, idx)).ok_or("Confrontation introuvable")?;
+
+        let confrontation = &mut self.tours[tour][idx];
+        confrontation.manches.push(Manche { domicile_id, exterieur_id, buts_domicile, buts_exterieur });
+        if !affiche.decisive {
+            return Ok(());
+        }
+
+        let (buts_a, buts_b) = confrontation.cumul();
+        let (a, b) = (confrontation.equipe_a.unwrap_or(0), confrontation.equipe_b.unwrap_or(0));
+        let qualifie = match buts_a.cmp(&buts_b) {
+            std::cmp::Ordering::Greater => a,
+            std::cmp::Ordering::Less => b,
+            std::cmp::Ordering::Equal => match vainqueur {
+                Some(id) if id == a || id == b => id,
+                _ => {
+                    confrontation.manches.pop();
+                    return Err("Égalité au cumul : la confrontation doit désigner un vainqueur".to_string());
+                }
+            },
+        };
+        confrontation.vainqueur = Some(qualifie);
+        self.qualifier(tour, idx, qualifie);
+        Ok(())
+    }
+
+    /// Placer le qualifié dans la confrontation du tour suivant (ou le sacrer)
+    fn qualifier(&mut self, tour: usize, idx: usize, equipe_id: u32) {
+        match self.tours.get_mut(tour + 1) {
+            Some(suivant) => {
+                let place = &mut suivant[idx / 2];
+                match idx % 2 {
+                    0 => place.equipe_a = Some(equipe_id),
+                    _ => place.equipe_b = Some(equipe_id),
+                }
+            }
+            None => self.vainqueur = Some(equipe_id),
+        }
+    }
+}
+
+/// Ordre des têtes de série (indices à partir de 0) dans un tableau de `taille` places :
+/// 1 et 2 ne peuvent se rencontrer qu'en finale, 1 à 4 qu'en demi-finales, etc.
+fn ordre_du_tableau(taille: usize) -> Vec<usize> {
+    let mut ordre = vec![0];
+    while ordre.len() < taille {
+        let n = ordre.len() * 2;
+        ordre = ordre.iter().flat_map(|&s| [s, n - 1 - s]).collect();
+    }
+    ordre
+}
+
+/// Critère de désignation des têtes de série
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CritereTetesDeSerie {
+    #[default]
+    Reputation,
+    Classement,  // Classement actuel du championnat
+}
+
+impl CritereTetesDeSerie {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            CritereTetesDeSerie::Reputation => "Réputation",
+            CritereTetesDeSerie::Classement => "Classement du championnat",
+        }
+    }
+
+    pub fn suivant(&self) -> Self {
+        match self {
+            CritereTetesDeSerie::Reputation => CritereTetesDeSerie::Classement,
+            CritereTetesDeSerie::Classement => CritereTetesDeSerie::Reputation,
+        }
+    }
+
+    /// Identifiants des équipes, de la meilleure tête de série à la moins bonne
+    pub fn ordonner(&self, equipes: &[Equipe], championnat: &Championnat) -> Vec<u32> {
+        match self {
+            CritereTetesDeSerie::Reputation => {
+                let mut triees: Vec<&Equipe> = equipes.iter().collect();
+                triees.sort_by(|a, b| b.reputation.partial_cmp(&a.reputation).unwrap_or(std::cmp::Ordering::Equal));
+                triees.iter().map(|e| e.id).collect()
+            }
+            CritereTetesDeSerie::Classement => championnat.classement().iter()
+                .map(|l| l.equipe_id)
+                .filter(|id| equipes.iter().any(|e| e.id == *id))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jouer_coupe(coupe: &mut Coupe) {
+        // Tous les matchs finissent 1-1 et la meilleure tête de série (plus petit id) passe aux tirs au but
+        while let Some(affiche) = coupe.prochaine_affiche() {
+            let favori = affiche.domicile_id.min(affiche.exterieur_id);
+            coupe.enregistrer_resultat(affiche.domicile_id, affiche.exterieur_id, 1, 1, Some(favori)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_tableau_avec_exemptions() {
+        assert_eq!(ordre_du_tableau(8), vec![0, 7, 3, 4, 1, 6, 2, 5]);
+
+        let tetes_de_serie: Vec<u32> = (1..=6).collect();
+        let mut coupe = Coupe::tirer("Coupe des Labos", &tetes_de_serie, FormatConfrontation::MatchSec).unwrap();
+        assert_eq!(coupe.tours.len(), 3);
+        // 1 et 2 sont exemptés et attendent en demi-finale
+        let exemptes: Vec<u32> = coupe.tours[0].iter().filter(|c| c.est_exemption()).filter_map(|c| c.equipe_a).collect();
+        assert_eq!(exemptes, vec![1, 2]);
+        assert_eq!(coupe.tours[1][0].equipe_a, Some(1));
+        assert_eq!(coupe.nom_tour(1), "Demi-finales");
+
+        jouer_coupe(&mut coupe);
+        assert_eq!(coupe.vainqueur, Some(1));
+        assert_eq!(coupe.tours[2][0].equipe_b, Some(2));
+        assert!(coupe.prochaine_affiche().is_none());
+
+        // Le tableau se sauvegarde et se recharge tel quel
+        let json = serde_json::to_string(&coupe).unwrap();
+        assert_eq!(serde_json::from_str::<Coupe>(&json).unwrap(), coupe);
+    }
+
+    #[test]
+    fn test_aller_retour_au_score_cumule() {
+        let mut coupe = Coupe::tirer("Coupe", &[1, 2], FormatConfrontation::AllerRetour).unwrap();
+        // Aller chez l'outsider
+        let aller = coupe.prochaine_affiche().unwrap();
+        assert_eq!((aller.domicile_id, aller.exterieur_id, aller.decisive), (2, 1, false));
+        coupe.enregistrer_resultat(2, 1, 3, 1, None).unwrap();
+
+        let retour = coupe.prochaine_affiche().unwrap();
+        assert_eq!((retour.domicile_id, retour.buts_aller, retour.decisive), (1, Some((1, 3)), true));
+        assert!(coupe.enregistrer_resultat(2, 1, 0, 0, None).is_err());
+        // 3-1 au retour : 4-4 au cumul, il faut un vainqueur
+        assert!(coupe.enregistrer_resultat(1, 2, 3, 1, None).is_err());
+        coupe.enregistrer_resultat(1, 2, 3, 1, Some(2)).unwrap();
+        assert_eq!(coupe.vainqueur, Some(2));
+        assert_eq!(coupe.tours[0][0].cumul(), (4, 4));
+    }
+}
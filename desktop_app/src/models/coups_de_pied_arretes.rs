@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+use crate::models::match_engine::{EvenementMatch, ModeSimulation, MoteurMatch, ResultatTir};
+use crate::models::player::{Joueur, Position};
+use crate::models::simulation_spatiale::{DEMI_LARGEUR_TERRAIN, DEMI_LONGUEUR_TERRAIN};
+use crate::models::team::Equipe;
+
+/// Surface de réparation : profondeur depuis la ligne de but et demi-largeur (m)
+const PROFONDEUR_SURFACE: f32 = 12.0;
+const DEMI_LARGEUR_SURFACE: f32 = 10.0;
+/// En deçà de cette distance au but, le coup franc se tire directement
+const PORTEE_COUP_FRANC_DIRECT: f32 = 25.0;
+/// Distance du point de penalty (et des reprises de la tête) à la ligne de but
+const POINT_DE_PENALTY: f32 = 8.0;
+const ZONE_DE_REPRISE: f32 = 5.0;
+const QUALITE_PENALTY: f32 = 1.0;
+const QUALITE_COUP_FRANC: f32 = 0.35; // Le mur et la distance gênent la frappe
+const QUALITE_TETE: f32 = 0.25;
+/// Part des tirs arrêtés ou contrés qui sortent en corner
+pub const PROBA_CORNER: f32 = 0.5;
+/// Probabilité qu'une faute vaille un carton (avant les traits du fautif)
+pub const PROBA_CARTON_FAUTE: f32 = 0.15;
+
+/// Coup de pied arrêté accordé après une faute ou une sortie du ballon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoupDePiedArrete {
+    CoupFranc,
+    Penalty,
+    Corner,
+    RentreeDeTouche,
+}
+
+impl CoupDePiedArrete {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            CoupDePiedArrete::CoupFranc => "Coup franc",
+            CoupDePiedArrete::Penalty => "Penalty",
+            CoupDePiedArrete::Corner => "Corner",
+            CoupDePiedArrete::RentreeDeTouche => "Rentrée de touche",
+        }
+    }
+
+    pub fn get_emoji(&self) -> &'static str {
+        match self {
+            CoupDePiedArrete::CoupFranc => "🦶",
+            CoupDePiedArrete::Penalty => "🎯",
+            CoupDePiedArrete::Corner => "🚩",
+            CoupDePiedArrete::RentreeDeTouche => "↩️",
+        }
+    }
+
+    /// Qualités recherchées chez le tireur
+    pub fn note_tireur(&self, joueur: &Joueur) -> f32 {
+        let s = &joueur.stats_effectives;
+        match self {
+            CoupDePiedArrete::Penalty => s.precision * 0.7 + s.force * 0.3,
+            CoupDePiedArrete::CoupFranc => s.precision * 0.6 + s.creativite * 0.4,
+            CoupDePiedArrete::Corner | CoupDePiedArrete::RentreeDeTouche => s.precision * 0.5 + s.creativite * 0.5,
+        }
+    }
+}
+
+/// Tireurs désignés par l'entraîneur (à défaut, le meilleur joueur sur le terrain)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TireursDesignes {
+    pub penalty: Option<u32>,
+    pub coups_francs: Option<u32>,
+    pub corners: Option<u32>,  // Et rentrées de touche
+}
+
+impl TireursDesignes {
+    pub fn get(&self, coup: CoupDePiedArrete) -> Option<u32> {
+        match coup {
+            CoupDePiedArrete::Penalty => self.penalty,
+            CoupDePiedArrete::CoupFranc => self.coups_francs,
+            CoupDePiedArrete::Corner | CoupDePiedArrete::RentreeDeTouche => self.corners,
+        }
+    }
+
+    pub fn set(&mut self, coup: CoupDePiedArrete, joueur_id: Option<u32>) {
+        match coup {
+            CoupDePiedArrete::Penalty => self.penalty = joueur_id,
+            CoupDePiedArrete::CoupFranc => self.coups_francs = joueur_id,
+            CoupDePiedArrete::Corner | CoupDePiedArrete::RentreeDeTouche => self.corners = joueur_id,
+        }
+    }
+}
+
+/// Issue d'un coup de pied arrêté, pour relancer le jeu ensuite
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IssueCoupArrete {
+    But,
+    Degage,                        // Arrêté, manqué ou dégagé : ballon à la défense
+    JeuRepris { tireur_id: u32 },  // Remise en jeu simple, l'attaque garde le ballon
+}
+
+/// Le point (x, z) est-il dans la surface que défend l'équipe à domicile (`true`) ou extérieure ?
+pub fn dans_la_surface(x: f32, z: f32, defense_domicile: bool) -> bool {
+    // L'équipe à domicile défend le but en x = -50
+    let x_but = if defense_domicile { -DEMI_LONGUEUR_TERRAIN } else { DEMI_LONGUEUR_TERRAIN };
+    (x - x_but).abs() <= PROFONDEUR_SURFACE && z.abs() <= DEMI_LARGEUR_SURFACE
+}
+
+/// Point de corner du côté où le ballon est sorti
+pub fn point_de_corner(attaque_domicile: bool, z: f32) -> (f32, f32) {
+    let x = if attaque_domicile { DEMI_LONGUEUR_TERRAIN } else { -DEMI_LONGUEUR_TERRAIN };
+    (x, DEMI_LARGEUR_TERRAIN * if z < 0.0 { -1.0 } else { 1.0 })
+}
+
+/// Meilleur joueur de champ de la tête sur le terrain (hors `exclus`)
+fn meilleur_de_la_tete(equipe: &Equipe, exclus: &[u32]) -> Option<(u32, f32)> {
+    equipe.get_titulaires().into_iter()
+        .filter(|j| j.position_actuelle != Position::Gardien && !exclus.contains(&j.id))
+        .map(|j| (j.id, j.stats_effectives.jeu_de_tete))
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+impl MoteurMatch {
+    /// Faute de `fautif_id` sur `victime_id` là où se trouve le ballon :
+    /// carton éventuel, puis coup franc (penalty dans la surface)
+    pub(crate) fn commettre_faute(&mut self, fautif_domicile: bool, fautif_id: u32, victime_id: u32) {
+        let minute = self.get_minute_actuelle();
+        let equipe_id = if fautif_domicile { self.equipe_domicile.id } else { self.equipe_exterieur.id };
+        let (x, z) = (self.ballon_x, self.ballon_z);
+        let coup = if dans_la_surface(x, z, fautif_domicile) {
+            CoupDePiedArrete::Penalty
+        } else {
+            CoupDePiedArrete::CoupFranc
+        };
+
+        self.consigner(EvenementMatch::Faute { minute, equipe_id, fautif_id, victime_id, coup });
+        self.sanctionner_faute(fautif_domicile, fautif_id, minute);
+        self.accorder_coup_de_pied_arrete(coup, !fautif_domicile, x, z);
+    }
+
+    /// Jouer un coup de pied arrêté puis, en mode spatial, relancer le jeu en conséquence
+    pub(crate) fn accorder_coup_de_pied_arrete(&mut self, coup: CoupDePiedArrete, attaque_domicile: bool, x: f32, z: f32) {
+        let issue = self.jouer_coup_de_pied_arrete(coup, attaque_domicile, x, z);
+        if self.mode_simulation == ModeSimulation::Spatiale && self.en_jeu {
+            self.reprendre_apres_coup_arrete(attaque_domicile, x, z, issue);
+        }
+    }
+
+    /// Tir arrêté ou contré : le ballon sort parfois en corner
+    pub(crate) fn corner_apres_tir(&mut self, attaque_domicile: bool, z: f32) -> bool {
+        if self.rng.gen::<f32>() >= PROBA_CORNER { return false; }
+        let (x, z) = point_de_corner(attaque_domicile, z);
+        self.accorder_coup_de_pied_arrete(CoupDePiedArrete::Corner, attaque_domicile, x, z);
+        true
+    }
+
+    /// Exécuter le coup de pied arrêté : la précision du tireur, et le jeu de tête
+    /// sur les centres, décident de l'issue
+    pub(crate) fn jouer_coup_de_pied_arrete(&mut self, coup: CoupDePiedArrete, attaque_domicile: bool, x: f32, z: f32) -> IssueCoupArrete {
+        let minute = self.get_minute_actuelle();
+        let (attaque, defense) = if attaque_domicile {
+            (&self.equipe_domicile, &self.equipe_exterieur)
+        } else {
+            (&self.equipe_exterieur, &self.equipe_domicile)
+        };
+        let equipe_id = attaque.id;
+        let Some(tireur_id) = attaque.choisir_tireur(coup) else { return IssueCoupArrete::Degage; };
+        let precision = attaque.get_joueur(tireur_id).map(|j| j.stats_effectives.precision / 100.0).unwrap_or(0.5);
+        let centre = meilleur_de_la_tete(attaque, &[tireur_id]);
+        let tete_defense = meilleur_de_la_tete(defense, &[]).map(|(_, t)| t).unwrap_or(50.0);
+
+        self.consigner(EvenementMatch::CoupDePiedArrete { minute, equipe_id, tireur_id, coup });
+
+        let x_but = if attaque_domicile { DEMI_LONGUEUR_TERRAIN } else { -DEMI_LONGUEUR_TERRAIN };
+        let distance_but = ((x_but - x).powi(2) + z * z).sqrt();
+        // Le tir part de l'endroit où se trouve le ballon
+        let sens = x_but.signum();
+        let resultat = match coup {
+            CoupDePiedArrete::Penalty => {
+                (self.ballon_x, self.ballon_z) = (x_but - sens * POINT_DE_PENALTY, 0.0);
+                self.resoudre_tir(attaque_domicile, tireur_id, None, QUALITE_PENALTY, minute, Some(coup))
+            }
+            CoupDePiedArrete::CoupFranc if distance_but < PORTEE_COUP_FRANC_DIRECT => {
+                // Frappe directe par-dessus le mur
+                (self.ballon_x, self.ballon_z) = (x, z);
+                let qualite = QUALITE_COUP_FRANC * (1.0 - distance_but / PORTEE_COUP_FRANC_DIRECT) * (0.5 + precision);
+                self.resoudre_tir(attaque_domicile, tireur_id, None, qualite, minute, Some(coup))
+            }
+            CoupDePiedArrete::Corner => {
+                // Duel aérien entre les meilleurs joueurs de tête des deux équipes
+                let Some((cible_id, tete_attaque)) = centre else { return IssueCoupArrete::Degage; };
+                let prob_centre = (0.1 + 0.35 * precision + (tete_attaque - tete_defense) / 200.0).clamp(0.05, 0.8);
+                if self.rng.gen::<f32>() >= prob_centre {
+                    return IssueCoupArrete::Degage;
+                }
+                (self.ballon_x, self.ballon_z) = (x_but - sens * ZONE_DE_REPRISE, 0.0);
+                self.resoudre_tir(attaque_domicile, cible_id, Some(tireur_id), QUALITE_TETE, minute, Some(coup))
+            }
+            // Coup franc lointain ou rentrée de touche : simple remise en jeu
+            _ => return IssueCoupArrete::JeuRepris { tireur_id },
+        };
+        if resultat == ResultatTir::But { IssueCoupArrete::But } else { IssueCoupArrete::Degage }
+    }
+}
@@ -0,0 +1,227 @@
+use rand::Rng;
+use crate::models::match_engine::{EvenementMatch, MoteurMatch};
+use crate::models::power_up::ModificateursPowerUp;
+use crate::models::scientific_domain::ScientificDomain;
+
+/// Découverte qu'une paire de domaines peut faire en plein match
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decouverte {
+    pub nom: String,
+    pub modificateurs: ModificateursPowerUp,
+    pub duree: f32, // Secondes de match, à compatibilité parfaite
+}
+
+impl Decouverte {
+    fn new(nom: &str, modificateurs: ModificateursPowerUp, duree: f32) -> Self {
+        Self { nom: nom.to_string(), modificateurs, duree }
+    }
+
+    /// Effet obtenu par une paire : plus les domaines s'accordent, plus il est fort et durable
+    pub fn effet(&self, compatibilite: f32) -> (ModificateursPowerUp, f32) {
+        let c = compatibilite.clamp(0.0, 1.0);
+        let m = &self.modificateurs;
+        let attenuer = |v: f32| 1.0 + (v - 1.0) * c;
+        let modificateurs = ModificateursPowerUp {
+            vitesse: attenuer(m.vitesse),
+            force: attenuer(m.force),
+            precision: attenuer(m.precision),
+            endurance: attenuer(m.endurance),
+            intelligence: attenuer(m.intelligence),
+            creativite: attenuer(m.creativite),
+            defense: attenuer(m.defense),
+            attaque: attenuer(m.attaque),
+            jeu_de_tete: attenuer(m.jeu_de_tete),
+        };
+        (modificateurs, self.duree * c)
+    }
+}
+
+/// Notions qu'un domaine apporte à une découverte commune
+fn apports(domaine: ScientificDomain) -> [(&'static str, ModificateursPowerUp); 2] {
+    use ScientificDomain::*;
+    let m = ModificateursPowerUp::default();
+    match domaine {
+        Informatique => [
+            ("Algorithme prédictif", ModificateursPowerUp { intelligence: 1.2, ..m }),
+            ("Calcul parallèle", ModificateursPowerUp { vitesse: 1.1, intelligence: 1.1, ..m }),
+        ],
+        PhysiqueMecanique => [
+            ("Balistique", ModificateursPowerUp { force: 1.15, attaque: 1.1, ..m }),
+            ("Levier parfait", ModificateursPowerUp { force: 1.2, ..m }),
+        ],
+        BiologieChimie => [
+            ("Réflexe enzymatique", ModificateursPowerUp { vitesse: 1.15, creativite: 1.05, ..m }),
+            ("Membrane sélective", ModificateursPowerUp { precision: 1.15, ..m }),
+        ],
+        PhysiqueChimie => [
+            ("Équilibre thermodynamique", ModificateursPowerUp { precision: 1.1, force: 1.1, ..m }),
+            ("Changement d'état", ModificateursPowerUp { vitesse: 1.15, ..m }),
+        ],
+        Mathematiques => [
+            ("Géométrie du jeu", ModificateursPowerUp { intelligence: 1.1, precision: 1.1, ..m }),
+            ("Théorème du placement", ModificateursPowerUp { defense: 1.1, intelligence: 1.1, ..m }),
+        ],
+        Electronique => [
+            ("Circuit réflexe", ModificateursPowerUp { vitesse: 1.15, precision: 1.05, ..m }),
+            ("Signal sans bruit", ModificateursPowerUp { precision: 1.15, ..m }),
+        ],
+        BiologieMedecine => [
+            ("Métabolisme optimisé", ModificateursPowerUp { endurance: 1.25, ..m }),
+            ("Cicatrisation accélérée", ModificateursPowerUp { endurance: 1.1, force: 1.1, ..m }),
+        ],
+        Chimie => [
+            ("Catalyse", ModificateursPowerUp { creativite: 1.2, ..m }),
+            ("Réaction en chaîne", ModificateursPowerUp { attaque: 1.1, creativite: 1.1, ..m }),
+        ],
+        MathematiquesBancaire => [
+            ("Modèle de risque", ModificateursPowerUp { intelligence: 1.1, creativite: 1.1, ..m }),
+            ("Effet de levier", ModificateursPowerUp { attaque: 1.15, ..m }),
+        ],
+        AidesSubventions => [
+            ("Redistribution", ModificateursPowerUp { endurance: 1.1, creativite: 1.1, ..m }),
+            ("Fonds d'urgence", ModificateursPowerUp { endurance: 1.2, ..m }),
+        ],
+        Cyberscurite => [
+            ("Pare-feu", ModificateursPowerUp { defense: 1.2, ..m }),
+            ("Détection d'intrusion", ModificateursPowerUp { defense: 1.1, intelligence: 1.1, ..m }),
+        ],
+        ElectroniqueBancaire => [
+            ("Trading haute fréquence", ModificateursPowerUp { vitesse: 1.2, ..m }),
+            ("Arbitrage éclair", ModificateursPowerUp { vitesse: 1.1, attaque: 1.1, ..m }),
+        ],
+        AgroalimentaireGeologie => [
+            ("Stratigraphie", ModificateursPowerUp { defense: 1.1, endurance: 1.1, ..m }),
+            ("Sol fertile", ModificateursPowerUp { endurance: 1.2, ..m }),
+        ],
+    }
+}
+
+/// Produit de deux jeux de modificateurs
+fn combiner(a: &ModificateursPowerUp, b: &ModificateursPowerUp) -> ModificateursPowerUp {
+    ModificateursPowerUp {
+        vitesse: a.vitesse * b.vitesse,
+        force: a.force * b.force,
+        precision: a.precision * b.precision,
+        endurance: a.endurance * b.endurance,
+        intelligence: a.intelligence * b.intelligence,
+        creativite: a.creativite * b.creativite,
+        defense: a.defense * b.defense,
+        attaque: a.attaque * b.attaque,
+        jeu_de_tete: a.jeu_de_tete * b.jeu_de_tete,
+    }
+}
+
+/// Catalogue des découvertes d'une paire de domaines (l'ordre de la paire est indifférent).
+/// Les grandes synergies ont leurs propres percées ; les autres paires croisent leurs notions.
+pub fn catalogue_decouvertes(a: ScientificDomain, b: ScientificDomain) -> Vec<Decouverte> {
+    use ScientificDomain::*;
+    let m = ModificateursPowerUp::default();
+    match (a, b) {
+        (Informatique, Mathematiques) | (Mathematiques, Informatique) => vec![
+            Decouverte::new("Calcul tactique optimisé par algorithme", ModificateursPowerUp { intelligence: 1.4, precision: 1.15, ..m }, 120.0),
+            Decouverte::new("Preuve automatique du hors-jeu", ModificateursPowerUp { defense: 1.25, intelligence: 1.2, ..m }, 100.0),
+        ],
+        (BiologieChimie, Chimie) | (Chimie, BiologieChimie) => vec![
+            Decouverte::new("Synergie moléculaire", ModificateursPowerUp { creativite: 1.35, vitesse: 1.15, ..m }, 110.0),
+            Decouverte::new("Réaction catalytique explosive", ModificateursPowerUp { attaque: 1.3, creativite: 1.2, ..m }, 80.0),
+        ],
+        (PhysiqueMecanique, PhysiqueChimie) | (PhysiqueChimie, PhysiqueMecanique) => vec![
+            Decouverte::new("Équation du mouvement parfaitement calculée", ModificateursPowerUp { precision: 1.3, force: 1.2, ..m }, 100.0),
+            Decouverte::new("Transfert d'énergie sans perte", ModificateursPowerUp { force: 1.35, endurance: 1.1, ..m }, 90.0),
+        ],
+        (Electronique, ElectroniqueBancaire) | (ElectroniqueBancaire, Electronique) => vec![
+            Decouverte::new("Circuit neuronal activé", ModificateursPowerUp { vitesse: 1.35, precision: 1.1, ..m }, 90.0),
+            Decouverte::new("Supraconduction à température ambiante", ModificateursPowerUp { vitesse: 1.25, endurance: 1.2, ..m }, 110.0),
+        ],
+        (MathematiquesBancaire, ElectroniqueBancaire) | (ElectroniqueBancaire, MathematiquesBancaire) => vec![
+            Decouverte::new("Algorithme de trading infaillible", ModificateursPowerUp { attaque: 1.3, vitesse: 1.15, ..m }, 90.0),
+        ],
+        (BiologieMedecine, BiologieChimie) | (BiologieChimie, BiologieMedecine) => vec![
+            Decouverte::new("Hormone de l'effort isolée", ModificateursPowerUp { endurance: 1.4, ..m }, 120.0),
+            Decouverte::new("Vaccin contre la fatigue", ModificateursPowerUp { endurance: 1.25, force: 1.15, ..m }, 100.0),
+        ],
+        (Cyberscurite, Informatique) | (Informatique, Cyberscurite) => vec![
+            Decouverte::new("Chiffrement du jeu de passes", ModificateursPowerUp { intelligence: 1.25, precision: 1.15, ..m }, 100.0),
+            Decouverte::new("Défense en profondeur", ModificateursPowerUp { defense: 1.4, ..m }, 110.0),
+        ],
+        (PhysiqueChimie, Chimie) | (Chimie, PhysiqueChimie) => vec![
+            Decouverte::new("Combustion contrôlée", ModificateursPowerUp { force: 1.2, creativite: 1.2, ..m }, 100.0),
+        ],
+        (AgroalimentaireGeologie, BiologieChimie) | (BiologieChimie, AgroalimentaireGeologie) => vec![
+            Decouverte::new("Ration minérale parfaite", ModificateursPowerUp { endurance: 1.3, defense: 1.1, ..m }, 110.0),
+        ],
+        (AidesSubventions, MathematiquesBancaire) | (MathematiquesBancaire, AidesSubventions) => vec![
+            Decouverte::new("Budget participatif", ModificateursPowerUp { creativite: 1.25, endurance: 1.15, ..m }, 100.0),
+        ],
+        _ if a == b => {
+            let [(n1, m1), (n2, m2)] = apports(a);
+            vec![
+                Decouverte::new(&format!("{} et {}", n1, n2), combiner(&m1, &m2), 90.0),
+                Decouverte::new(&format!("{} affiné", n1), m1, 100.0),
+                Decouverte::new(&format!("{} affiné", n2), m2, 100.0),
+            ]
+        }
+        _ => {
+            let (a, b) = if (a as u8) <= (b as u8) { (a, b) } else { (b, a) };
+            let (apports_a, apports_b) = (apports(a), apports(b));
+            apports_a.iter()
+                .flat_map(|(na, ma)| apports_b.iter().map(move |(nb, mb)| {
+                    Decouverte::new(&format!("{} × {}", na, nb), combiner(ma, mb), 90.0)
+                }))
+                .collect()
+        }
+    }
+}
+
+impl MoteurMatch {
+    /// Une paire de coéquipiers sur le terrain fait une découverte issue de leurs domaines.
+    /// Les paires les plus compatibles trouvent plus souvent, et les bonus sont temporaires.
+    pub(crate) fn generer_decouverte_scientifique(&mut self, minute: u32) {
+        let est_domicile = self.rng.gen_bool(0.5);
+        let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
+        let equipe_id = equipe.id;
+
+        let terrain: Vec<_> = equipe.joueurs.iter().filter(|j| j.sur_le_terrain).collect();
+        let mut paires = Vec::new();
+        for (i, a) in terrain.iter().enumerate() {
+            for b in &terrain[i + 1..] {
+                paires.push((a.id, b.id, a.domaine, b.domaine, a.domaine.compatibility_with(&b.domaine)));
+            }
+        }
+        if paires.is_empty() { return; }
+
+        // Tirage pondéré par la compatibilité (au carré pour favoriser les synergies)
+        let total: f32 = paires.iter().map(|p| p.4 * p.4).sum();
+        let mut tirage = self.rng.gen::<f32>() * total;
+        let mut paire = paires[paires.len() - 1];
+        for p in &paires {
+            if tirage < p.4 * p.4 {
+                paire = *p;
+                break;
+            }
+            tirage -= p.4 * p.4;
+        }
+        let (id_a, id_b, domaine_a, domaine_b, compatibilite) = paire;
+
+        let catalogue = catalogue_decouvertes(domaine_a, domaine_b);
+        let decouverte = &catalogue[self.rng.gen_range(0..catalogue.len())];
+        let (modificateurs, duree) = decouverte.effet(compatibilite);
+
+        let equipe = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+        for j in equipe.joueurs.iter_mut().filter(|j| j.sur_le_terrain) {
+            j.ajouter_bonus_temporaire(&decouverte.nom, modificateurs, duree);
+        }
+
+        let nom = |id: u32| equipe.get_joueur(id).map(|j| j.prenom.clone()).unwrap_or_default();
+        let description = format!("{} et {} ({} × {}) : {} !",
+            nom(id_a), nom(id_b), domaine_a.get_name(), domaine_b.get_name(), decouverte.nom);
+
+        self.consigner(EvenementMatch::DecouverteScientifique {
+            minute,
+            equipe_id,
+            joueurs_ids: [id_a, id_b],
+            description,
+            duree,
+        });
+    }
+}
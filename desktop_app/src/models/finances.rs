@@ -0,0 +1,294 @@
+use serde::{Deserialize, Serialize};
+use crate::models::player::Joueur;
+use crate::models::team::Equipe;
+use crate::models::transferts::{ReponseOffre, TAILLE_EFFECTIF_MIN};
+
+/// Prime versée au vainqueur de la coupe
+pub const PRIME_COUPE: u32 = 100_000;
+
+/// Contrat d'un joueur avec son club
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contrat {
+    pub salaire: u32,            // Par match officiel disputé par le club
+    pub saisons_restantes: u32,  // Saison en cours comprise
+    pub clause_liberatoire: Option<u32>,
+}
+
+impl Contrat {
+    /// Contrat de 3 saisons, salaire et clause indexés sur la valeur du joueur
+    pub fn pour_valeur(valeur: u32) -> Self {
+        Self {
+            salaire: salaire_pour_valeur(valeur),
+            saisons_restantes: 3,
+            clause_liberatoire: Some(valeur * 2),
+        }
+    }
+
+    /// Le contrat se termine à la fin de la saison en cours
+    pub fn expire(&self) -> bool {
+        self.saisons_restantes <= 1
+    }
+}
+
+/// Salaire par match correspondant à une valeur marchande (arrondi à la centaine)
+pub fn salaire_pour_valeur(valeur: u32) -> u32 {
+    (valeur / 60 / 100).max(5) * 100
+}
+
+/// Nature d'une ligne du grand livre
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NatureOperation {
+    Salaires,
+    Billetterie,
+    Primes,
+    Transfert,
+}
+
+impl NatureOperation {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            NatureOperation::Salaires => "Salaires",
+            NatureOperation::Billetterie => "Billetterie",
+            NatureOperation::Primes => "Primes",
+            NatureOperation::Transfert => "Transferts",
+        }
+    }
+}
+
+/// Ligne du grand livre d'un club (montant positif = recette)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationFinanciere {
+    pub saison: u32,
+    pub nature: NatureOperation,
+    pub libelle: String,
+    pub montant: i64,
+}
+
+/// Prime de fin de championnat selon le rang (1 = champion)
+pub fn prime_classement(nb_equipes: usize, rang: usize) -> u32 {
+    let ecart = nb_equipes.saturating_sub(rang) as u32;
+    20_000 + 180_000 * ecart / (nb_equipes.max(2) - 1) as u32
+}
+
+impl Joueur {
+    /// Salaire demandé pour prolonger, ou `None` si le joueur veut partir.
+    /// Un joueur heureux demande moins, un joueur performant davantage.
+    pub fn exigences_renouvellement(&self) -> Option<u32> {
+        if self.moral < 0.6 {
+            return None;
+        }
+        let performance = self.note_moyenne().map(|n| 1.0 + (n - 6.0) * 0.1).unwrap_or(1.0).clamp(0.8, 1.4);
+        let humeur = 1.6 - self.moral * 0.6;
+        let base = salaire_pour_valeur(self.valeur_marchande()).max(self.contrat.salaire) as f32;
+        Some(((base * performance * humeur / 100.0).round() as u32).max(5) * 100)
+    }
+}
+
+impl Equipe {
+    /// Inscrire une opération au grand livre et l'appliquer au budget
+    pub fn enregistrer_operation(&mut self, saison: u32, nature: NatureOperation, libelle: String, montant: i64) {
+        self.budget += montant;
+        self.finances.push(OperationFinanciere { saison, nature, libelle, montant });
+    }
+
+    pub fn masse_salariale(&self) -> u32 {
+        self.joueurs.iter().map(|j| j.contrat.salaire).sum()
+    }
+
+    /// Club dans le rouge : plus aucun recrutement
+    pub fn est_sous_embargo(&self) -> bool {
+        self.budget < 0
+    }
+
+    /// Total de la saison par nature d'opération
+    pub fn bilan_saison(&self, saison: u32) -> Vec<(NatureOperation, i64)> {
+        let mut bilan: Vec<(NatureOperation, i64)> = Vec::new();
+        for op in self.finances.iter().filter(|op| op.saison == saison) {
+            match bilan.iter_mut().find(|(n, _)| *n == op.nature) {
+                Some((_, total)) => *total += op.montant,
+                None => bilan.push((op.nature, op.montant)),
+            }
+        }
+        bilan
+    }
+
+    /// Salaires du match, et recette au guichet pour le club qui reçoit
+    pub fn regler_match(&mut self, saison: u32, a_domicile: bool, adversaire: &str) {
+        let salaires = self.masse_salariale() as i64;
+        self.enregistrer_operation(saison, NatureOperation::Salaires, format!("Salaires (vs {})", adversaire), -salaires);
+        if a_domicile {
+            let recette = 10_000 + (self.reputation * 30_000.0) as i64;
+            self.enregistrer_operation(saison, NatureOperation::Billetterie, format!("Recette (vs {})", adversaire), recette);
+        }
+    }
+
+    /// Négocier la prolongation d'un contrat qui arrive à échéance
+    pub fn prolonger_contrat(&mut self, joueur_id: u32, saisons: u32, salaire: u32) -> Result<ReponseOffre, String> {
+        let joueur = self.get_joueur_mut(joueur_id).ok_or("Joueur introuvable")?;
+        if !joueur.contrat.expire() {
+            return Err(format!("Le contrat de {} court encore {} saisons", joueur.prenom, joueur.contrat.saisons_restantes));
+        }
+        let Some(demande) = joueur.exigences_renouvellement() else {
+            return Ok(ReponseOffre::Refusee(format!("{} ne souhaite pas prolonger", joueur.prenom)));
+        };
+        if salaire >= demande {
+            // Saison en cours + saisons ajoutées
+            joueur.contrat = Contrat {
+                salaire,
+                saisons_restantes: joueur.contrat.saisons_restantes + saisons,
+                clause_liberatoire: Some(joueur.valeur_marchande() * 2),
+            };
+            Ok(ReponseOffre::Acceptee)
+        } else if salaire as f32 >= demande as f32 * 0.85 {
+            Ok(ReponseOffre::ContreOffre(demande))
+        } else {
+            Ok(ReponseOffre::Refusee(format!("{} demande {} par match", joueur.prenom, demande)))
+        }
+    }
+
+    /// Fin de saison : les contrats avancent d'une saison et les joueurs en fin de contrat
+    /// partent. Un club IA prolonge ses titulaires qui le veulent bien ; un effectif trop
+    /// court garde ses joueurs une saison de plus, à leurs conditions. Retourne les partants.
+    pub fn echeance_contrats(&mut self, gestion_ia: bool) -> Vec<Joueur> {
+        let mut partants = Vec::new();
+        let ids: Vec<u32> = self.joueurs.iter().map(|j| j.id).collect();
+        for id in ids {
+            let Some(joueur) = self.get_joueur(id) else { continue };
+            if !joueur.contrat.expire() {
+                if let Some(j) = self.get_joueur_mut(id) { j.contrat.saisons_restantes -= 1; }
+                continue;
+            }
+            let demande = joueur.exigences_renouvellement();
+            let prolongation = match demande {
+                Some(salaire) if gestion_ia && joueur.sur_le_terrain && !self.est_sous_embargo() => Some((salaire, 2)),
+                _ if self.joueurs.len() <= TAILLE_EFFECTIF_MIN => Some((demande.unwrap_or(joueur.contrat.salaire), 1)),
+                _ => None,
+            };
+            match prolongation {
+                Some((salaire, saisons)) => {
+                    if let Some(j) = self.get_joueur_mut(id) {
+                        let clause = Some(j.valeur_marchande() * 2);
+                        j.contrat = Contrat { salaire, saisons_restantes: saisons, clause_liberatoire: clause };
+                    }
+                }
+                None => {
+                    if let Some(mut j) = self.retirer_joueur(id) {
+                        j.sur_le_terrain = false;
+                        partants.push(j);
+                    }
+                }
+            }
+        }
+        if self.get_titulaires().len() < 5 {
+            self.selectionner_titulaires_auto();
+        }
+        partants
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::championnat::Championnat;
+    use crate::models::player::creer_joueurs_reels;
+    use crate::models::transferts::{MarcheTransferts, OffreTransfert};
+
+    fn club(id: u32, joueurs: Vec<Joueur>) -> Equipe {
+        let mut e = Equipe::new(id, &format!("Club {}", id));
+        for j in joueurs {
+            e.ajouter_joueur(j).unwrap();
+        }
+        e.selectionner_titulaires_auto();
+        e
+    }
+
+    #[test]
+    fn test_contrats_et_renouvellement() {
+        let mut equipe = club(1, creer_joueurs_reels().into_iter().take(9).collect());
+        let id = equipe.joueurs[0].id;
+
+        // Contrat en cours : pas de négociation
+        assert!(equipe.prolonger_contrat(id, 2, 1_000_000).is_err());
+        equipe.joueurs[0].contrat.saisons_restantes = 1;
+
+        // Le moral et les performances pèsent sur les exigences
+        let joueur = &mut equipe.joueurs[0];
+        let demande = joueur.exigences_renouvellement().unwrap();
+        joueur.moral = 1.4;
+        let demande_heureux = joueur.exigences_renouvellement().unwrap();
+        joueur.historique_notes = vec![8.5, 9.0];
+        let demande_star = joueur.exigences_renouvellement().unwrap();
+        assert!(demande_heureux < demande && demande_star > demande_heureux);
+        joueur.moral = 0.5;
+        assert_eq!(joueur.exigences_renouvellement(), None, "Un joueur malheureux veut partir");
+        joueur.moral = 1.0;
+        let demande = joueur.exigences_renouvellement().unwrap();
+
+        assert!(matches!(equipe.prolonger_contrat(id, 2, demande / 2), Ok(ReponseOffre::Refusee(_))));
+        assert_eq!(equipe.prolonger_contrat(id, 2, demande * 9 / 10), Ok(ReponseOffre::ContreOffre(demande)));
+        assert_eq!(equipe.prolonger_contrat(id, 2, demande), Ok(ReponseOffre::Acceptee));
+        assert_eq!(equipe.joueurs[0].contrat.saisons_restantes, 3);
+
+        // Fin de saison : les contrats expirés non prolongés partent, sauf effectif trop court
+        for j in equipe.joueurs.iter_mut().skip(1) {
+            j.contrat.saisons_restantes = 1;
+        }
+        let partants = equipe.echeance_contrats(false);
+        assert_eq!(partants.len(), 9 - TAILLE_EFFECTIF_MIN);
+        assert_eq!(equipe.joueurs.len(), TAILLE_EFFECTIF_MIN);
+        assert_eq!(equipe.get_joueur(id).unwrap().contrat.saisons_restantes, 2);
+        assert_eq!(equipe.get_titulaires().len(), 5);
+        assert!(partants.iter().all(|j| !j.sur_le_terrain));
+
+        // Un club IA garde ses titulaires qui acceptent de rester
+        let mut ia = club(2, creer_joueurs_reels());
+        for j in &mut ia.joueurs {
+            j.contrat.saisons_restantes = 1;
+        }
+        let partants = ia.echeance_contrats(true);
+        assert!(ia.get_titulaires().len() == 5 && partants.iter().all(|j| ia.get_joueur(j.id).is_none()));
+        assert!(ia.get_titulaires().iter().all(|j| j.contrat.saisons_restantes == 2));
+    }
+
+    #[test]
+    fn test_grand_livre_primes_et_embargo() {
+        let mut joueurs = creer_joueurs_reels();
+        let mut equipes = vec![club(1, joueurs.drain(..7).collect()), club(2, joueurs)];
+        let mut championnat = Championnat::new(1, &equipes);
+
+        // Match : salaires pour les deux, recette pour le club qui reçoit
+        let (budget_1, budget_2) = (equipes[0].budget, equipes[1].budget);
+        equipes[0].regler_match(1, true, "Club 2");
+        equipes[1].regler_match(1, false, "Club 1");
+        assert_eq!(equipes[0].finances.len(), 2);
+        assert_eq!(equipes[1].budget, budget_2 - equipes[1].masse_salariale() as i64);
+        let total: i64 = equipes[0].bilan_saison(1).iter().map(|(_, m)| m).sum();
+        assert_eq!(equipes[0].budget, budget_1 + total, "Le budget suit le grand livre");
+
+        // Primes de fin de championnat : le champion touche le plus
+        championnat.enregistrer_resultat(1, 2, 2, 0).unwrap();
+        championnat.enregistrer_resultat(2, 1, 0, 1).unwrap();
+        let avant = equipes[0].budget;
+        championnat.cloturer_saison(&mut equipes).unwrap();
+        assert_eq!(equipes[0].budget - avant, prime_classement(2, 1) as i64);
+        assert!(prime_classement(10, 1) > prime_classement(10, 10));
+
+        // Dans le rouge : interdiction de recruter, même au prix demandé
+        let mut marche = MarcheTransferts::new();
+        let cible = equipes[1].joueurs[0].clone();
+        let offre = OffreTransfert { joueur_id: cible.id, vendeur_id: 2, acheteur_id: 1, montant: 0 };
+        let dette = -equipes[0].budget - 1;
+        equipes[0].enregistrer_operation(2, NatureOperation::Salaires, "Dette".to_string(), dette);
+        assert!(equipes[0].est_sous_embargo());
+        assert!(marche.proposer(&mut equipes, &championnat, offre).is_err());
+
+        // Renfloué, le club peut lever la clause libératoire et le joueur change de contrat
+        let clause = cible.contrat.clause_liberatoire.unwrap();
+        equipes[0].enregistrer_operation(2, NatureOperation::Primes, "Mécène".to_string(), clause as i64 + 1);
+        let reponse = marche.proposer(&mut equipes, &championnat, OffreTransfert { montant: clause, ..offre });
+        assert_eq!(reponse, Ok(ReponseOffre::Acceptee));
+        assert_eq!(equipes[0].budget, 0);
+        assert!(equipes[1].finances.iter().any(|op| op.nature == NatureOperation::Transfert && op.montant == clause as i64));
+        assert_eq!(equipes[0].get_joueur(cible.id).unwrap().contrat.saisons_restantes, 3);
+    }
+}
@@ -1,15 +1,36 @@
 use serde::{Deserialize, Serialize};
 use rand::Rng;
-use crate::models::team::Equipe;
-use crate::models::player::Position;
+use crate::models::team::{Equipe, ModificateursTactiques};
+use crate::models::player::{Blessure, ContexteMatch, GraviteBlessure, Position, RECUPERATION_BANC, SUSPENSION_ROUGE_DIRECT};
 use crate::models::power_up::TypePowerUp;
+use crate::models::aleatoire::GenerateurAleatoire;
+use crate::models::capacites::EffetActif;
+use crate::models::regles::RegleMatch;
+use crate::models::tirs_au_but::SeanceTirsAuBut;
+use crate::models::coups_de_pied_arretes::{CoupDePiedArrete, PROBA_CARTON_FAUTE};
+use crate::models::statistiques::StatistiquesMatch;
+use crate::models::entraineur_ia::EntraineurIA;
+
+/// Part du temps réglementaire après laquelle s'activent les traits de fin de match
+const DEBUT_FIN_DE_MATCH: f32 = 0.75;
+/// Part des cartons qui sont des rouges directs
+const PROBA_ROUGE_DIRECT: f32 = 0.06;
+/// Fautes par seconde de jeu en mode probabiliste
+const PROBA_FAUTE: f32 = 0.0075;
+/// Part des tirs manqués contrés par la défense en mode probabiliste
+const PROBA_TIR_CONTRE: f32 = 0.2;
+/// Pas fixe de la simulation (s de jeu) : le résultat ne dépend ni des images par seconde
+/// ni de la vitesse d'affichage
+pub const PAS_SIMULATION: f32 = 0.1;
 
 /// Période du match
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PeriodeMatch {
     PremiereMitemps,
-    MiTemps,
-    DeuxiemeMitemps,
+    MiTemps,            // Pause entre deux périodes (ou avant la prolongation)
+    DeuxiemeMitemps,    // Périodes suivantes du temps réglementaire
+    Prolongation,
+    TirsAuBut,
     Termine,
 }
 
@@ -19,13 +40,62 @@ impl PeriodeMatch {
             PeriodeMatch::PremiereMitemps => "1ère mi-temps",
             PeriodeMatch::MiTemps => "Mi-temps",
             PeriodeMatch::DeuxiemeMitemps => "2ème mi-temps",
+            PeriodeMatch::Prolongation => "Prolongation",
+            PeriodeMatch::TirsAuBut => "Tirs au but",
             PeriodeMatch::Termine => "Terminé",
         }
     }
 }
 
+/// Compétition dans laquelle se joue le match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Competition {
+    #[default]
+    Championnat,  // Compte dans le bilan des équipes (victoires, nuls, défaites)
+    Coupe,        // Élimination directe : n'entre pas dans le bilan de saison
+}
+
+/// Mode de simulation du match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModeSimulation {
+    /// Simulation positionnelle : chaque joueur se déplace, passes, duels et tirs
+    /// découlent des positions, de la vitesse et de l'intelligence
+    Spatiale,
+    /// Ancien mode : ballon en marche aléatoire et buts tirés au sort
+    Probabiliste,
+}
+
+impl ModeSimulation {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            ModeSimulation::Spatiale => "Simulation spatiale",
+            ModeSimulation::Probabiliste => "Simulation probabiliste",
+        }
+    }
+}
+
+/// Issue d'un tir
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResultatTir {
+    But,
+    Arrete,
+    NonCadre,
+    Contre,
+}
+
+impl ResultatTir {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            ResultatTir::But => "but",
+            ResultatTir::Arrete => "arrêté",
+            ResultatTir::NonCadre => "non cadré",
+            ResultatTir::Contre => "contré",
+        }
+    }
+}
+
 /// Événement survenu pendant le match
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EvenementMatch {
     But {
         minute: u32,
@@ -33,6 +103,7 @@ pub enum EvenementMatch {
         equipe_id: u32,
         passeur_id: Option<u32>,
         description: String,
+        coup_de_pied_arrete: Option<CoupDePiedArrete>, // But sur phase arrêtée
     },
     Substitution {
         minute: u32,
@@ -50,11 +121,27 @@ pub enum EvenementMatch {
         joueur_id: u32,
         raison: String,
     },
+    CartonRouge {
+        minute: u32,
+        equipe_id: u32,
+        joueur_id: u32,
+        raison: String,
+        second_jaune: bool,
+    },
+    Blessure {
+        minute: u32,
+        equipe_id: u32,
+        joueur_id: u32,
+        nature: String,
+        gravite: GraviteBlessure,
+        matchs_absence: u32,
+    },
     DecouverteScientifique {
         minute: u32,
         equipe_id: u32,
+        joueurs_ids: [u32; 2],  // Paire à l'origine de la découverte
         description: String,
-        bonus: f32,
+        duree: f32,             // Durée du bonus accordé à l'équipe (s)
     },
     SauvetageGardien {
         minute: u32,
@@ -65,6 +152,45 @@ pub enum EvenementMatch {
         joueur_id: u32,
         description: String,
     },
+    CapaciteUtilisee {
+        minute: u32,
+        equipe_id: u32,
+        joueur_id: u32,
+        nom: String,
+    },
+    TirAuBut {
+        minute: u32,
+        equipe_id: u32,
+        tireur_id: u32,
+        reussi: bool,
+    },
+    Tir {
+        minute: u32,
+        equipe_id: u32,
+        tireur_id: u32,
+        x: f32,                 // Position du ballon au moment du tir
+        z: f32,
+        resultat: ResultatTir,
+        xg: f32,                // Probabilité de but de l'occasion (buts attendus)
+    },
+    Faute {
+        minute: u32,
+        equipe_id: u32,         // Équipe fautive
+        fautif_id: u32,
+        victime_id: u32,
+        coup: CoupDePiedArrete, // Coup franc ou penalty
+    },
+    CoupDePiedArrete {
+        minute: u32,
+        equipe_id: u32,
+        tireur_id: u32,
+        coup: CoupDePiedArrete,
+    },
+    ChangementTactique {
+        minute: u32,
+        equipe_id: u32,
+        description: String,
+    },
 }
 
 impl EvenementMatch {
@@ -74,9 +200,17 @@ impl EvenementMatch {
             EvenementMatch::Substitution { minute, .. } => *minute,
             EvenementMatch::PowerUpUtilise { minute, .. } => *minute,
             EvenementMatch::CartonJaune { minute, .. } => *minute,
+            EvenementMatch::CartonRouge { minute, .. } => *minute,
+            EvenementMatch::Blessure { minute, .. } => *minute,
             EvenementMatch::DecouverteScientifique { minute, .. } => *minute,
             EvenementMatch::SauvetageGardien { minute, .. } => *minute,
             EvenementMatch::BelleAction { minute, .. } => *minute,
+            EvenementMatch::CapaciteUtilisee { minute, .. } => *minute,
+            EvenementMatch::TirAuBut { minute, .. } => *minute,
+            EvenementMatch::Tir { minute, .. } => *minute,
+            EvenementMatch::Faute { minute, .. } => *minute,
+            EvenementMatch::CoupDePiedArrete { minute, .. } => *minute,
+            EvenementMatch::ChangementTactique { minute, .. } => *minute,
         }
     }
 
@@ -88,15 +222,35 @@ impl EvenementMatch {
                 format!("{}' ⚡ {}", minute, type_power_up.get_nom())
             },
             EvenementMatch::CartonJaune { minute, raison, .. } => format!("{}' 🟡 {}", minute, raison),
+            EvenementMatch::CartonRouge { minute, raison, second_jaune, .. } => {
+                if *second_jaune {
+                    format!("{}' 🟡🔴 Second avertissement : {}", minute, raison)
+                } else {
+                    format!("{}' 🔴 Expulsion : {}", minute, raison)
+                }
+            },
+            EvenementMatch::Blessure { minute, nature, matchs_absence, .. } => {
+                format!("{}' 🤕 {} ({} match(s) d'absence)", minute, nature, matchs_absence)
+            },
             EvenementMatch::DecouverteScientifique { minute, description, .. } => format!("{}' 🔬 {}", minute, description),
             EvenementMatch::SauvetageGardien { minute, .. } => format!("{}' 🧤 Arrêt du gardien", minute),
             EvenementMatch::BelleAction { minute, description, .. } => format!("{}' ✨ {}", minute, description),
+            EvenementMatch::CapaciteUtilisee { minute, nom, .. } => format!("{}' 🔬 {}", minute, nom),
+            EvenementMatch::TirAuBut { minute, reussi, .. } => {
+                format!("{}' 🎯 Tir au but {}", minute, if *reussi { "réussi" } else { "manqué" })
+            },
+            EvenementMatch::Tir { minute, resultat, xg, .. } => {
+                format!("{}' 👟 Tir {} (xG {:.2})", minute, resultat.get_nom(), xg)
+            },
+            EvenementMatch::Faute { minute, coup, .. } => format!("{}' 🚫 Faute : {}", minute, coup.get_nom().to_lowercase()),
+            EvenementMatch::CoupDePiedArrete { minute, coup, .. } => format!("{}' {} {}", minute, coup.get_emoji(), coup.get_nom()),
+            EvenementMatch::ChangementTactique { minute, description, .. } => format!("{}' 📋 {}", minute, description),
         }
     }
 }
 
 /// État de position d'un joueur pendant le match
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EtatJoueurMatch {
     pub joueur_id: u32,
     pub equipe_id: u32,
@@ -107,6 +261,8 @@ pub struct EtatJoueurMatch {
     pub a_le_ballon: bool,
     pub stamina: f32,
     pub actif: bool,
+    pub base_x: f32,       // Position de référence selon le rôle
+    pub base_z: f32,
 }
 
 /// Moteur de simulation de match
@@ -117,20 +273,52 @@ pub struct MoteurMatch {
     pub equipe_exterieur: Equipe,
     pub score_domicile: u32,
     pub score_exterieur: u32,
-    pub temps_ecoule: f32,          // En secondes (0 à 1200 = 20 minutes)
-    pub duree_match: f32,
+    pub temps_ecoule: f32,          // En secondes depuis le coup d'envoi
+    pub duree_match: f32,           // Temps réglementaire (s)
+    pub regles: RegleMatch,
     pub periode: PeriodeMatch,
+    pub numero_periode: u32,        // Période réglementaire en cours (à partir de 1)
     pub evenements: Vec<EvenementMatch>,
+    pub statistiques: StatistiquesMatch,
     pub ballon_x: f32,
     pub ballon_z: f32,
     pub en_jeu: bool,
+    pub coup_d_envoi_donne: bool,   // demarrer() n'est appelé qu'une fois par match
     pub vitesse_simulation: f32,    // Multiplicateur de vitesse
+    pub(crate) temps_a_simuler: f32, // Temps reçu pas encore simulé (moins d'un pas)
     pub bonus_domicile: f32,        // Bonus scientifique de l'équipe domicile
     pub bonus_exterieur: f32,
+    pub graine: u64,                // Graine du match (à joindre aux rapports de bug)
+    pub(crate) rng: GenerateurAleatoire, // Seule source d'aléa de la simulation
+    pub mode_simulation: ModeSimulation,
+    pub etats_joueurs: Vec<EtatJoueurMatch>,
+    pub(crate) temps_avant_action: f32, // Délai avant la prochaine décision du porteur
+    pub(crate) delai_duel: f32,         // Répit après une récupération de balle
+    pub(crate) dernier_passeur_id: Option<u32>, // Pour attribuer les passes décisives
+    pub(crate) temps_possession: f32,   // Depuis la dernière récupération (contres)
+    pub effets_actifs: Vec<EffetActif>, // Capacités spéciales en cours
+    pub capacites_auto_domicile: bool,  // L'IA déclenche les capacités de l'équipe
+    pub capacites_auto_exterieur: bool,
+    pub entraineur_domicile: Option<EntraineurIA>,  // Équipe dirigée par l'IA
+    pub entraineur_exterieur: Option<EntraineurIA>,
+    pub remplacements_domicile: u32,    // Changements effectués
+    pub remplacements_exterieur: u32,
+    pub joueurs_remplaces: Vec<u32>,    // Sortis sur changement (retour selon les règles)
+    pub seance_tirs_au_but: Option<SeanceTirsAuBut>,
+    pub tireurs_choisis_domicile: Vec<u32>,  // Ordre imposé des tireurs (complété automatiquement)
+    pub tireurs_choisis_exterieur: Vec<u32>,
+    pub competition: Competition,
+    pub buts_aller: Option<(u32, u32)>,  // Match retour : buts (domicile, extérieur) du match aller
 }
 
 impl MoteurMatch {
-    pub fn nouveau(id: u32, domicile: Equipe, exterieur: Equipe) -> Self {
+    pub fn nouveau(id: u32, domicile: Equipe, exterieur: Equipe, regles: RegleMatch) -> Self {
+        Self::avec_graine(id, domicile, exterieur, regles, GenerateurAleatoire::graine_aleatoire())
+    }
+
+    /// Créer un match reproductible : même graine + mêmes compositions + mêmes
+    /// commandes = mêmes événements et même score
+    pub fn avec_graine(id: u32, domicile: Equipe, exterieur: Equipe, regles: RegleMatch, graine: u64) -> Self {
         let mut m = Self {
             id,
             equipe_domicile: domicile,
@@ -138,15 +326,41 @@ impl MoteurMatch {
             score_domicile: 0,
             score_exterieur: 0,
             temps_ecoule: 0.0,
-            duree_match: 1200.0, // 20 minutes
+            duree_match: regles.duree_reglementaire(),
+            regles,
             periode: PeriodeMatch::PremiereMitemps,
+            numero_periode: 1,
             evenements: Vec::new(),
+            statistiques: StatistiquesMatch::default(),
             ballon_x: 0.0,
             ballon_z: 0.0,
             en_jeu: false,
+            coup_d_envoi_donne: false,
             vitesse_simulation: 1.0,
+            temps_a_simuler: 0.0,
             bonus_domicile: 0.0,
             bonus_exterieur: 0.0,
+            graine,
+            rng: GenerateurAleatoire::new(graine),
+            mode_simulation: ModeSimulation::Spatiale,
+            etats_joueurs: Vec::new(),
+            temps_avant_action: 0.0,
+            delai_duel: 0.0,
+            dernier_passeur_id: None,
+            temps_possession: 0.0,
+            effets_actifs: Vec::new(),
+            capacites_auto_domicile: true,
+            capacites_auto_exterieur: true,
+            entraineur_domicile: None,
+            entraineur_exterieur: None,
+            remplacements_domicile: 0,
+            remplacements_exterieur: 0,
+            joueurs_remplaces: Vec::new(),
+            seance_tirs_au_but: None,
+            tireurs_choisis_domicile: Vec::new(),
+            tireurs_choisis_exterieur: Vec::new(),
+            competition: Competition::default(),
+            buts_aller: None,
         };
         m.calculer_bonus_scientifiques();
         m
@@ -164,6 +378,7 @@ impl MoteurMatch {
 
     pub fn demarrer(&mut self) {
         self.en_jeu = true;
+        self.coup_d_envoi_donne = true;
         self.periode = PeriodeMatch::PremiereMitemps;
 
         // Mettre les titulaires sur le terrain
@@ -177,6 +392,9 @@ impl MoteurMatch {
                 j.matchs_joues += 1;
             }
         }
+
+        // Coup d'envoi pour l'équipe à domicile
+        self.engagement(true);
     }
 
     pub fn pause(&mut self) {
@@ -185,139 +403,352 @@ impl MoteurMatch {
 
     pub fn reprendre(&mut self) {
         if self.periode == PeriodeMatch::MiTemps {
-            self.periode = PeriodeMatch::DeuxiemeMitemps;
+            if self.numero_periode < self.regles.nb_periodes {
+                self.numero_periode += 1;
+                self.periode = PeriodeMatch::DeuxiemeMitemps;
+            } else {
+                self.periode = PeriodeMatch::Prolongation;
+            }
+            // Les équipes alternent l'engagement à chaque période
+            self.engagement(self.numero_periode % 2 == 1 && self.periode != PeriodeMatch::Prolongation);
         }
         self.en_jeu = true;
     }
 
+    /// Faire avancer le match de `delta` secondes réelles, découpées en pas fixes
     pub fn mise_a_jour(&mut self, delta: f32) {
         if !self.en_jeu { return; }
 
-        let delta_ajuste = delta * self.vitesse_simulation;
-        self.temps_ecoule += delta_ajuste;
+        self.temps_a_simuler += delta * self.vitesse_simulation;
+        // Petite tolérance : une somme de deltas n'atteint pas toujours exactement le pas
+        while self.en_jeu && self.temps_a_simuler + 1e-4 >= PAS_SIMULATION {
+            self.temps_a_simuler -= PAS_SIMULATION;
+            self.avancer_d_un_pas();
+        }
+        // Pause, mi-temps ou fin : le reliquat ne déborde pas sur la reprise
+        if !self.en_jeu {
+            self.temps_a_simuler = 0.0;
+        }
+    }
 
-        // Gestion des périodes
-        if self.temps_ecoule >= 600.0 && self.periode == PeriodeMatch::PremiereMitemps {
-            self.periode = PeriodeMatch::MiTemps;
-            self.en_jeu = false;
-            return;
+    /// Résoudre instantanément la suite du match, pauses comprises, avec les mêmes pas
+    /// qu'un match affiché : même graine et mêmes commandes donnent le même résultat
+    pub fn simuler_jusqu_a_la_fin(&mut self) {
+        if !self.coup_d_envoi_donne {
+            self.demarrer();
         }
-        if self.temps_ecoule >= 1200.0 && self.periode == PeriodeMatch::DeuxiemeMitemps {
-            self.terminer_match();
+        while self.periode != PeriodeMatch::Termine {
+            if !self.en_jeu {
+                self.reprendre();
+            }
+            self.avancer_d_un_pas();
+        }
+        self.temps_a_simuler = 0.0;
+    }
+
+    fn avancer_d_un_pas(&mut self) {
+        let delta_ajuste = PAS_SIMULATION;
+        if self.periode == PeriodeMatch::TirsAuBut {
+            self.mise_a_jour_tirs_au_but(delta_ajuste);
             return;
         }
+        self.temps_ecoule += delta_ajuste;
+
+        // Gestion des périodes
+        match self.periode {
+            PeriodeMatch::PremiereMitemps | PeriodeMatch::DeuxiemeMitemps
+                if self.temps_ecoule >= self.regles.duree_periode * self.numero_periode as f32 =>
+            {
+                let egalite = self.est_a_egalite();
+                if self.numero_periode < self.regles.nb_periodes || (egalite && self.regles.prolongation.is_some()) {
+                    self.periode = PeriodeMatch::MiTemps;
+                    self.en_jeu = false;
+                    for j in self.equipe_domicile.joueurs.iter_mut().chain(self.equipe_exterieur.joueurs.iter_mut()) {
+                        j.recuperer_mi_temps();
+                    }
+                } else {
+                    self.terminer_temps_de_jeu();
+                }
+                return;
+            }
+            PeriodeMatch::Prolongation
+                if self.temps_ecoule >= self.duree_match + self.regles.prolongation.unwrap_or(0.0) =>
+            {
+                self.terminer_temps_de_jeu();
+                return;
+            }
+            _ => {}
+        }
 
         // Simulation du gameplay
         self.simuler_jeu(delta_ajuste);
+        if !self.en_jeu { return; } // But en or
 
-        // Mise à jour stamina
+        // Mise à jour stamina, familiarité des postes et bonus temporaires
         self.mise_a_jour_stamina(delta_ajuste);
+        self.mise_a_jour_familiarite(delta_ajuste);
+        self.mise_a_jour_bonus(delta_ajuste);
+        self.mise_a_jour_capacites(delta_ajuste);
+        self.mise_a_jour_entraineurs(delta_ajuste);
+        self.mise_a_jour_contexte();
 
         // Événements aléatoires
         self.generer_evenements(delta_ajuste);
     }
 
     fn simuler_jeu(&mut self, delta: f32) {
-        let mut rng = rand::thread_rng();
+        match self.mode_simulation {
+            ModeSimulation::Spatiale => self.simuler_jeu_spatial(delta),
+            ModeSimulation::Probabiliste => self.simuler_jeu_probabiliste(delta),
+        }
+    }
 
+    fn simuler_jeu_probabiliste(&mut self, delta: f32) {
         // Déplacement du ballon (simulation simplifiée)
-        let bruit_x: f32 = rng.gen_range(-1.0..1.0) * delta * 8.0;
-        let bruit_z: f32 = rng.gen_range(-1.0..1.0) * delta * 8.0;
+        let bruit_x: f32 = self.rng.gen_range(-1.0..1.0) * delta * 8.0;
+        let bruit_z: f32 = self.rng.gen_range(-1.0..1.0) * delta * 8.0;
 
         self.ballon_x = (self.ballon_x + bruit_x).clamp(-48.0, 48.0);
         self.ballon_z = (self.ballon_z + bruit_z).clamp(-23.0, 23.0);
 
+        // Ballon sorti en touche : rentrée pour l'une ou l'autre équipe
+        if self.ballon_z.abs() >= 23.0 {
+            let pour_domicile = self.rng.gen_bool(0.5);
+            let (x, z) = (self.ballon_x, self.ballon_z);
+            self.accorder_coup_de_pied_arrete(CoupDePiedArrete::RentreeDeTouche, pour_domicile, x, z);
+            self.ballon_z = 20.0 * z.signum();
+        }
+
         // Influence des équipes sur le contrôle du ballon
-        let note_d = self.equipe_domicile.note_equipe() * (1.0 + self.bonus_domicile);
-        let note_e = self.equipe_exterieur.note_equipe() * (1.0 + self.bonus_exterieur);
+        let note_d = self.note_en_match(true);
+        let note_e = self.note_en_match(false);
         let total = note_d + note_e;
 
         if total > 0.0 {
-            let possession_d = note_d / total;
+            // Le pressing fait remonter le ballon dans le camp adverse
+            let recup_d = self.get_modificateurs(true).recuperation;
+            let recup_e = self.get_modificateurs(false).recuperation;
+            let possession_d = note_d * recup_d / (note_d * recup_d + note_e * recup_e);
             // Pousser le ballon vers l'un ou l'autre camp selon la domination
             let tendance = (possession_d - 0.5) * delta * 3.0;
             self.ballon_x = (self.ballon_x + tendance).clamp(-48.0, 48.0);
+            self.statistiques.ajouter_possession(possession_d, delta);
         }
     }
 
+    /// Note d'une équipe avec ses bonus, réduite si elle joue en infériorité numérique
+    fn note_en_match(&self, est_domicile: bool) -> f32 {
+        let (equipe, bonus) = if est_domicile {
+            (&self.equipe_domicile, self.bonus_domicile)
+        } else {
+            (&self.equipe_exterieur, self.bonus_exterieur)
+        };
+        let effectif_complet = equipe.formation.get_positions_requises().len() as f32;
+        let presents = equipe.get_titulaires().len() as f32;
+        equipe.note_equipe() * (1.0 + bonus) * (presents / effectif_complet).min(1.0)
+    }
+
+    /// Modificateurs tactiques d'une équipe face aux consignes et à la formation de l'autre
+    pub fn get_modificateurs(&self, est_domicile: bool) -> ModificateursTactiques {
+        let (equipe, adversaire) = if est_domicile {
+            (&self.equipe_domicile, &self.equipe_exterieur)
+        } else {
+            (&self.equipe_exterieur, &self.equipe_domicile)
+        };
+        let mut m = equipe.instructions.modificateurs(&adversaire.instructions);
+        m.exposition *= equipe.formation.couverture_defensive();
+        m.qualite_tir *= equipe.formation.avantage_contre(&adversaire.formation);
+        self.appliquer_effets_capacites(equipe.id, &mut m);
+        m
+    }
+
     fn mise_a_jour_stamina(&mut self, delta: f32) {
-        let intensite_d = self.equipe_domicile.instructions.intensite;
-        let intensite_e = self.equipe_exterieur.instructions.intensite;
+        // ~60 points sur un match complet à intensité normale
+        let conso_d = self.get_modificateurs(true).consommation_stamina;
+        let conso_e = self.get_modificateurs(false).consommation_stamina;
+
+        for (joueurs, conso) in [(&mut self.equipe_domicile.joueurs, conso_d), (&mut self.equipe_exterieur.joueurs, conso_e)] {
+            for j in joueurs.iter_mut() {
+                if j.sur_le_terrain {
+                    let avant = j.stamina;
+                    j.consommer_stamina(0.05 * conso * delta);
+                    self.statistiques.joueur_mut(j.id).stamina_depensee += avant - j.stamina;
+                } else {
+                    // Les remplaçants récupèrent sur le banc
+                    let recuperation = RECUPERATION_BANC * j.capacite_recuperation() * delta;
+                    j.recuperer_stamina(recuperation);
+                }
+            }
+        }
+    }
 
-        for j in &mut self.equipe_domicile.joueurs {
+    fn mise_a_jour_familiarite(&mut self, delta: f32) {
+        let minutes = delta / 60.0;
+        for j in self.equipe_domicile.joueurs.iter_mut().chain(self.equipe_exterieur.joueurs.iter_mut()) {
             if j.sur_le_terrain {
-                j.consommer_stamina(1.5 * intensite_d * delta);
+                j.jouer_minutes(minutes);
+                self.statistiques.joueur_mut(j.id).minutes += minutes;
             }
         }
+    }
+
+    fn mise_a_jour_bonus(&mut self, delta: f32) {
+        for j in self.equipe_domicile.joueurs.iter_mut().chain(self.equipe_exterieur.joueurs.iter_mut()) {
+            j.mise_a_jour_bonus(delta);
+        }
+    }
+
+    /// Informer les joueurs de la situation du match (traits conditionnels)
+    fn mise_a_jour_contexte(&mut self) {
+        let fin_de_match = self.temps_ecoule >= self.duree_match * DEBUT_FIN_DE_MATCH;
+        let provocation = |equipe: &Equipe| equipe.joueurs.iter()
+            .filter(|j| j.sur_le_terrain)
+            .map(|j| j.modificateur_intelligence_adversaires())
+            .product::<f32>();
+        let contexte_d = ContexteMatch {
+            fin_de_match,
+            equipe_menee: self.score_domicile < self.score_exterieur,
+            intelligence_subie: provocation(&self.equipe_exterieur),
+        };
+        let contexte_e = ContexteMatch {
+            fin_de_match,
+            equipe_menee: self.score_exterieur < self.score_domicile,
+            intelligence_subie: provocation(&self.equipe_domicile),
+        };
+        for j in &mut self.equipe_domicile.joueurs {
+            j.changer_contexte(contexte_d);
+        }
         for j in &mut self.equipe_exterieur.joueurs {
-            if j.sur_le_terrain {
-                j.consommer_stamina(1.5 * intensite_e * delta);
-            }
+            j.changer_contexte(contexte_e);
         }
     }
 
     fn generer_evenements(&mut self, delta: f32) {
-        let mut rng = rand::thread_rng();
         let minute = self.get_minute_actuelle();
 
         // Probabilité de but (ajustée par les notes d'équipe)
-        let note_d = self.equipe_domicile.note_equipe() * (1.0 + self.bonus_domicile);
-        let note_e = self.equipe_exterieur.note_equipe() * (1.0 + self.bonus_exterieur);
-        let total = note_d + note_e;
+        let note_d = self.note_en_match(true);
+        let note_e = self.note_en_match(false);
 
+        // En mode spatial, les tirs découlent des positions (voir simulation_spatiale)
         let prob_but_base = 0.0018 * delta;
 
-        if rng.gen::<f32>() < prob_but_base {
-            let c_domicile = note_d / total;
-            if rng.gen::<f32>() < c_domicile {
+        if self.mode_simulation == ModeSimulation::Probabiliste && self.rng.gen::<f32>() < prob_but_base {
+            let (mod_d, mod_e) = (self.get_modificateurs(true), self.get_modificateurs(false));
+            let occasions_d = note_d * mod_d.recuperation * mod_d.envie_tir;
+            let occasions_e = note_e * mod_e.recuperation * mod_e.envie_tir;
+            let c_domicile = occasions_d / (occasions_d + occasions_e);
+            if self.rng.gen::<f32>() < c_domicile {
                 self.tenter_but(true, minute);
             } else {
                 self.tenter_but(false, minute);
             }
         }
 
-        // Probabilité de carton jaune
-        let prob_carton = 0.0003 * delta;
-        if rng.gen::<f32>() < prob_carton {
-            self.generer_carton(minute);
+        // En mode spatial, les fautes naissent des duels (voir simulation_spatiale)
+        let prob_faute = PROBA_FAUTE * delta;
+        if self.mode_simulation == ModeSimulation::Probabiliste && self.rng.gen::<f32>() < prob_faute {
+            self.generer_faute();
+        }
+
+        // En mode probabiliste, les contacts ne sont pas simulés : tirer un joueur au hasard
+        let prob_contact = 0.2 * delta;
+        if self.mode_simulation == ModeSimulation::Probabiliste && self.rng.gen::<f32>() < prob_contact {
+            let est_domicile = self.rng.gen_bool(0.5);
+            let (equipe, adversaire) = if est_domicile {
+                (&self.equipe_domicile, &self.equipe_exterieur)
+            } else {
+                (&self.equipe_exterieur, &self.equipe_domicile)
+            };
+            let titulaires: Vec<u32> = equipe.get_titulaires().iter().map(|j| j.id).collect();
+            let intensite = adversaire.instructions.intensite;
+            if !titulaires.is_empty() {
+                let joueur_id = titulaires[self.rng.gen_range(0..titulaires.len())];
+                self.tester_blessure(est_domicile, joueur_id, intensite);
+            }
         }
 
         // Découvertes scientifiques (boost temporaire)
         let prob_decouverte = 0.0002 * delta;
-        if rng.gen::<f32>() < prob_decouverte {
+        if self.rng.gen::<f32>() < prob_decouverte {
             self.generer_decouverte_scientifique(minute);
         }
 
         // Belle action
         let prob_belle_action = 0.0005 * delta;
-        if rng.gen::<f32>() < prob_belle_action {
+        if self.rng.gen::<f32>() < prob_belle_action {
             self.generer_belle_action(minute);
         }
     }
 
     fn tenter_but(&mut self, est_domicile: bool, minute: u32) {
-        let mut rng = rand::thread_rng();
+        let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
+
+        // Le tireur est tiré au sort selon les postes privilégiés par la formation
+        let tireurs: Vec<(u32, f32)> = equipe.joueurs.iter()
+            .filter(|j| j.sur_le_terrain)
+            .map(|j| (j.id, equipe.formation.poids_tireur(j.position_actuelle)))
+            .filter(|(_, poids)| *poids > 0.0)
+            .collect();
+
+        let total: f32 = tireurs.iter().map(|(_, poids)| poids).sum();
+        if total <= 0.0 { return; }
+
+        let mut tirage = self.rng.gen::<f32>() * total;
+        let mut tireur_id = tireurs[tireurs.len() - 1].0;
+        for (id, poids) in &tireurs {
+            if tirage < *poids {
+                tireur_id = *id;
+                break;
+            }
+            tirage -= poids;
+        }
 
+        // Chercher un passeur potentiel
+        let passeurs: Vec<u32> = equipe.joueurs.iter()
+            .filter(|j| j.sur_le_terrain && j.id != tireur_id)
+            .map(|j| j.id)
+            .collect();
+        let passeur_id = if !passeurs.is_empty() && self.rng.gen_bool(0.6) {
+            Some(passeurs[self.rng.gen_range(0..passeurs.len())])
+        } else { None };
+
+        let qualite = self.get_modificateurs(est_domicile).qualite_tir
+            * self.get_modificateurs(!est_domicile).exposition;
+        let resultat = self.resoudre_tir(est_domicile, tireur_id, passeur_id, qualite, minute, None);
+        if matches!(resultat, ResultatTir::Arrete | ResultatTir::Contre) {
+            let z = self.ballon_z;
+            self.corner_apres_tir(est_domicile, z);
+        }
+    }
+
+    /// Résoudre un tir depuis la position du ballon : `qualite` module les chances
+    /// (distance, angle...), qui donnent aussi les buts attendus (xG) de l'occasion.
+    pub(crate) fn resoudre_tir(
+        &mut self,
+        est_domicile: bool,
+        tireur_id: u32,
+        passeur_id: Option<u32>,
+        qualite: f32,
+        minute: u32,
+        coup_de_pied_arrete: Option<CoupDePiedArrete>,
+    ) -> ResultatTir {
         let (equipe, equipe_adverse, equipe_id) = if est_domicile {
             (&self.equipe_domicile, &self.equipe_exterieur, self.equipe_domicile.id)
         } else {
             (&self.equipe_exterieur, &self.equipe_domicile, self.equipe_exterieur.id)
         };
 
-        // Chercher un attaquant ou milieu disponible
-        let tireurs: Vec<u32> = equipe.joueurs.iter()
-            .filter(|j| j.sur_le_terrain && matches!(j.position_actuelle, Position::Attaquant | Position::Milieu))
-            .map(|j| j.id)
-            .collect();
-
-        if tireurs.is_empty() { return; }
-
-        let tireur_id = tireurs[rng.gen_range(0..tireurs.len())];
-        let tireur = equipe.joueurs.iter().find(|j| j.id == tireur_id).unwrap();
+        let Some(tireur) = equipe.joueurs.iter().find(|j| j.id == tireur_id) else { return ResultatTir::NonCadre; };
 
         // Calculer la probabilité de réussite
         let precision_tir = tireur.stats_effectives.precision / 100.0;
-        let attaque = tireur.stats_effectives.attaque / 100.0;
+        // Sur corner, le ballon se reprend de la tête
+        let attaque = if coup_de_pied_arrete == Some(CoupDePiedArrete::Corner) {
+            tireur.stats_effectives.jeu_de_tete / 100.0
+        } else {
+            tireur.stats_effectives.attaque / 100.0
+        };
 
         // Résistance du gardien adverse
         let gardien = equipe_adverse.joueurs.iter()
@@ -329,28 +760,64 @@ impl MoteurMatch {
             0.4 // Gardien par défaut si absent
         };
 
-        let chance_reussite = (precision_tir * 0.5 + attaque * 0.5) * (1.0 - resistance_gardien * 0.6);
+        let gardien_id = gardien.map(|g| g.id);
+        let prenom_tireur = tireur.prenom.clone();
+        let finition = tireur.modificateur_finition();
+        let equipe_defense_id = equipe_adverse.id;
 
-        if rng.gen::<f32>() < chance_reussite {
-            // BUT !
-            // Chercher un passeur potentiel
-            let passeurs: Vec<u32> = equipe.joueurs.iter()
-                .filter(|j| j.sur_le_terrain && j.id != tireur_id)
-                .map(|j| j.id)
-                .collect();
-            let passeur_id = if !passeurs.is_empty() && rng.gen_bool(0.6) {
-                Some(passeurs[rng.gen_range(0..passeurs.len())])
-            } else { None };
+        // Capacités : un tir puissant trompe plus facilement le gardien, un pare-feu bloque tout
+        let puissance = self.consommer_tir_puissant(tireur_id);
+        let bloque = self.tirs_bloques(equipe_defense_id);
+        let chance_reussite = if bloque {
+            0.0
+        } else {
+            (precision_tir * 0.5 + attaque * 0.5) * (1.0 - resistance_gardien * 0.6 / puissance) * qualite * finition
+        };
+
+        // Un tir manqué peut être contré par un défenseur dans l'axe, sinon cadré selon la précision
+        let (x, z) = (self.ballon_x, self.ballon_z);
+        let proba_contre = match (coup_de_pied_arrete, self.mode_simulation) {
+            (Some(CoupDePiedArrete::Penalty), _) => 0.0,
+            (_, ModeSimulation::Spatiale) => 1.0 - 0.8_f32.powi(self.defenseurs_sur_trajectoire(equipe_id, x, z) as i32),
+            (_, ModeSimulation::Probabiliste) => PROBA_TIR_CONTRE,
+        };
+        let resultat = if self.rng.gen::<f32>() < chance_reussite {
+            ResultatTir::But
+        } else if bloque || self.rng.gen::<f32>() < proba_contre {
+            ResultatTir::Contre
+        } else if gardien_id.is_some() && self.rng.gen::<f32>() < 0.4 + 0.4 * precision_tir {
+            ResultatTir::Arrete
+        } else {
+            ResultatTir::NonCadre
+        };
+        self.consigner(EvenementMatch::Tir {
+            minute,
+            equipe_id,
+            tireur_id,
+            x,
+            z,
+            resultat,
+            xg: chance_reussite.clamp(0.0, 1.0),
+        });
 
+        if resultat == ResultatTir::But {
+            // BUT !
             // Générer une description de but créative
-            let descriptions = [
-                format!("Magnifique frappe de {} !", tireur.prenom),
-                format!("{} conclut brillamment !", tireur.prenom),
-                format!("Quel geste technique de {} !", tireur.prenom),
-                format!("{} ne rate pas !", tireur.prenom),
-                format!("But d'anthologie de {} !", tireur.prenom),
-            ];
-            let desc = descriptions[rng.gen_range(0..descriptions.len())].clone();
+            let desc = match coup_de_pied_arrete {
+                Some(CoupDePiedArrete::Penalty) => format!("{} transforme le penalty !", prenom_tireur),
+                Some(CoupDePiedArrete::CoupFranc) => format!("Coup franc direct de {} !", prenom_tireur),
+                Some(CoupDePiedArrete::Corner) => format!("{} reprend le corner de la tête !", prenom_tireur),
+                _ => {
+                    let descriptions = [
+                        format!("Magnifique frappe de {} !", prenom_tireur),
+                        format!("{} conclut brillamment !", prenom_tireur),
+                        format!("Quel geste technique de {} !", prenom_tireur),
+                        format!("{} ne rate pas !", prenom_tireur),
+                        format!("But d'anthologie de {} !", prenom_tireur),
+                    ];
+                    descriptions[self.rng.gen_range(0..descriptions.len())].clone()
+                }
+            };
 
             if est_domicile {
                 self.score_domicile += 1;
@@ -368,7 +835,7 @@ impl MoteurMatch {
                     j.moral = (j.moral + 0.05).min(1.5);
                 }
                 for j in &mut self.equipe_exterieur.joueurs {
-                    j.moral = (j.moral - 0.05).max(0.5);
+                    j.moral = (j.moral - 0.05 * j.modificateur_moral()).max(0.5);
                 }
             } else {
                 self.score_exterieur += 1;
@@ -384,103 +851,175 @@ impl MoteurMatch {
                     j.moral = (j.moral + 0.05).min(1.5);
                 }
                 for j in &mut self.equipe_domicile.joueurs {
-                    j.moral = (j.moral - 0.05).max(0.5);
+                    j.moral = (j.moral - 0.05 * j.modificateur_moral()).max(0.5);
                 }
             }
 
-            self.evenements.push(EvenementMatch::But {
+            self.consigner(EvenementMatch::But {
                 minute,
                 buteur_id: tireur_id,
                 equipe_id,
                 passeur_id,
                 description: desc,
+                coup_de_pied_arrete,
             });
-        } else if let Some(g) = gardien {
+            if self.periode == PeriodeMatch::Prolongation && self.regles.but_en_or {
+                self.terminer_match();
+            }
+        } else if let (ResultatTir::Arrete, Some(gardien_id)) = (resultat, gardien_id) {
             // Arrêt du gardien
-            let gardien_id = g.id;
-            self.evenements.push(EvenementMatch::SauvetageGardien {
+            self.consigner(EvenementMatch::SauvetageGardien {
                 minute,
                 gardien_id,
             });
         }
+        resultat
     }
 
-    fn generer_carton(&mut self, minute: u32) {
-        let mut rng = rand::thread_rng();
-
-        // Domaine à risque = Mathématiques (Théo fait des fautes)
-        let equipes = [
-            (self.equipe_domicile.id, true),
-            (self.equipe_exterieur.id, false),
-        ];
+    /// Buts attendus (xG) cumulés par une équipe sur ses tirs (hors tirs au but)
+    pub fn xg_equipe(&self, equipe_id: u32) -> f32 {
+        self.evenements.iter()
+            .filter_map(|e| match e {
+                EvenementMatch::Tir { equipe_id: id, xg, .. } if *id == equipe_id => Some(*xg),
+                _ => None,
+            })
+            .sum()
+    }
 
-        let (_equipe_id, est_domicile) = equipes[rng.gen_range(0..equipes.len())];
+    /// Buts attendus (xG) cumulés par un joueur
+    pub fn xg_joueur(&self, joueur_id: u32) -> f32 {
+        self.evenements.iter()
+            .filter_map(|e| match e {
+                EvenementMatch::Tir { tireur_id, xg, .. } if *tireur_id == joueur_id => Some(*xg),
+                _ => None,
+            })
+            .sum()
+    }
 
-        let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
-        let joueurs_terrain: Vec<u32> = equipe.joueurs.iter()
-            .filter(|j| j.sur_le_terrain)
-            .map(|j| j.id)
-            .collect();
+    /// Faute entre deux joueurs tirés au sort (mode probabiliste)
+    fn generer_faute(&mut self) {
+        let fautif_domicile = self.rng.gen_bool(0.5);
+        let (equipe, adversaire) = if fautif_domicile {
+            (&self.equipe_domicile, &self.equipe_exterieur)
+        } else {
+            (&self.equipe_exterieur, &self.equipe_domicile)
+        };
+        let fautifs: Vec<u32> = equipe.get_titulaires().iter().map(|j| j.id).collect();
+        let victimes: Vec<u32> = adversaire.get_titulaires().iter().map(|j| j.id).collect();
+        if fautifs.is_empty() || victimes.is_empty() { return; }
 
-        if joueurs_terrain.is_empty() { return; }
+        let fautif_id = fautifs[self.rng.gen_range(0..fautifs.len())];
+        let victime_id = victimes[self.rng.gen_range(0..victimes.len())];
+        self.commettre_faute(fautif_domicile, fautif_id, victime_id);
+    }
 
-        let joueur_id = joueurs_terrain[rng.gen_range(0..joueurs_terrain.len())];
-        let equipe_mut = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+    /// Carton éventuel pour l'auteur d'une faute
+    pub(crate) fn sanctionner_faute(&mut self, fautif_domicile: bool, joueur_id: u32, minute: u32) {
+        let equipe_mut = if fautif_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+        let equipe_id = equipe_mut.id;
 
         if let Some(j) = equipe_mut.joueurs.iter_mut().find(|j| j.id == joueur_id) {
-            // Théo a moins de chances de carton (charmeur d'arbitre)
-            let reduction = if j.traits.iter().any(|t| t.nom == "Charmeur d'Arbitre") { 0.3 } else { 1.0 };
-            if rng.gen::<f32>() > reduction * 0.7 { return; }
-
-            j.cartons_jaunes += 1;
-            let raisons = [
-                "Tacle trop appuyé",
-                "Contestation de décision",
-                "Retard de jeu",
-                "Faute tactique",
-            ];
-            let raison = raisons[rng.gen_range(0..raisons.len())].to_string();
-
-            self.evenements.push(EvenementMatch::CartonJaune {
-                minute,
-                joueur_id,
-                raison,
-            });
+            // Certains traits attirent ou évitent les cartons (Théo, charmeur d'arbitre) ;
+            // un joueur déjà averti se montre plus prudent
+            let prudence = if j.cartons_jaunes_match > 0 { 0.4 } else { 1.0 };
+            if self.rng.gen::<f32>() > (PROBA_CARTON_FAUTE * j.modificateur_cartons() * prudence).min(1.0) { return; }
+
+            let rouge_direct = self.rng.gen::<f32>() < PROBA_ROUGE_DIRECT;
+            let expulse = if rouge_direct {
+                j.expulser(SUSPENSION_ROUGE_DIRECT);
+                true
+            } else {
+                j.recevoir_carton_jaune()
+            };
+
+            let raisons = if rouge_direct {
+                &["Tacle dangereux", "Faute de dernier recours", "Geste d'humeur"][..]
+            } else {
+                &["Tacle trop appuyé", "Faute tactique", "Tirage de maillot", "Charge imprudente"][..]
+            };
+            let raison = raisons[self.rng.gen_range(0..raisons.len())].to_string();
+
+            if expulse {
+                // L'équipe termine le match à un de moins
+                self.retirer_du_terrain(equipe_id, joueur_id);
+                self.consigner(EvenementMatch::CartonRouge {
+                    minute,
+                    equipe_id,
+                    joueur_id,
+                    raison,
+                    second_jaune: !rouge_direct,
+                });
+            } else {
+                self.consigner(EvenementMatch::CartonJaune {
+                    minute,
+                    joueur_id,
+                    raison,
+                });
+            }
         }
     }
 
-    fn generer_decouverte_scientifique(&mut self, minute: u32) {
-        let mut rng = rand::thread_rng();
-        let est_domicile = rng.gen_bool(0.5);
-        let equipe_id = if est_domicile { self.equipe_domicile.id } else { self.equipe_exterieur.id };
-
-        let decouvertes = [
-            ("Synergie moléculaire découverte !", 0.1),
-            ("Calcul tactique optimisé par algorithme !", 0.08),
-            ("Réaction catalytique explosive !", 0.12),
-            ("Équation du mouvement parfaitement calculée !", 0.09),
-            ("Circuit neuronal activé !", 0.07),
-        ];
-
-        let (desc, bonus) = decouvertes[rng.gen_range(0..decouvertes.len())];
-
-        // Appliquer le bonus temporaire à l'équipe
+    /// Contact subi par un joueur : il peut se blesser selon sa fatigue, sa force
+    /// et l'intensité du contact (engagement de l'équipe adverse compris)
+    pub(crate) fn tester_blessure(&mut self, est_domicile: bool, joueur_id: u32, intensite: f32) {
+        let minute = self.get_minute_actuelle();
         let equipe = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
-        for j in equipe.joueurs.iter_mut().filter(|j| j.sur_le_terrain) {
-            j.forme = (j.forme + bonus).min(1.5);
+        let equipe_id = equipe.id;
+        let Some(j) = equipe.get_joueur_mut(joueur_id) else { return; };
+        if !j.sur_le_terrain || self.rng.gen::<f32>() >= j.risque_blessure(intensite) {
+            return;
         }
 
-        self.evenements.push(EvenementMatch::DecouverteScientifique {
+        // Les contacts les plus engagés provoquent les blessures les plus graves
+        let tirage = self.rng.gen::<f32>() / intensite.max(0.5);
+        let gravite = if tirage < 0.08 {
+            GraviteBlessure::Grave
+        } else if tirage < 0.35 {
+            GraviteBlessure::Moyenne
+        } else {
+            GraviteBlessure::Legere
+        };
+        let natures = match gravite {
+            GraviteBlessure::Legere => &["Contusion", "Crampe sévère", "Cheville tordue"][..],
+            GraviteBlessure::Moyenne => &["Élongation", "Entorse", "Déchirure légère"][..],
+            GraviteBlessure::Grave => &["Déchirure musculaire", "Entorse grave du genou", "Fracture de fatigue"][..],
+        };
+        let nature = natures[self.rng.gen_range(0..natures.len())].to_string();
+        let (min, max) = gravite.duree_matchs();
+        let matchs_absence = self.rng.gen_range(min..=max);
+
+        j.blesser(Blessure {
+            nature: nature.clone(),
+            gravite,
+            matchs_restants: matchs_absence,
+            survenue_en_match: true,
+        });
+        self.consigner(EvenementMatch::Blessure {
             minute,
             equipe_id,
-            description: desc.to_string(),
-            bonus,
+            joueur_id,
+            nature,
+            gravite,
+            matchs_absence,
         });
+
+        // Le blessé est remplacé, ou son équipe finit à un de moins
+        let equipe = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+        let poste = equipe.get_joueur(joueur_id).map(|j| j.position_actuelle).unwrap_or(Position::Milieu);
+        let exclus = if self.regles.retour_autorise { &[][..] } else { &self.joueurs_remplaces[..] };
+        let remplace = equipe.meilleur_remplacant(poste, exclus)
+            .is_some_and(|entrant_id| self.faire_substitution(equipe_id, joueur_id, entrant_id).is_ok());
+        if !remplace {
+            let equipe = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+            if let Some(j) = equipe.get_joueur_mut(joueur_id) {
+                j.sur_le_terrain = false;
+            }
+            self.retirer_du_terrain(equipe_id, joueur_id);
+        }
     }
 
     fn generer_belle_action(&mut self, minute: u32) {
-        let mut rng = rand::thread_rng();
-        let est_domicile = rng.gen_bool(0.5);
+        let est_domicile = self.rng.gen_bool(0.5);
         let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
 
         let joueurs_terrain: Vec<u32> = equipe.joueurs.iter()
@@ -489,7 +1028,7 @@ impl MoteurMatch {
             .collect();
 
         if joueurs_terrain.is_empty() { return; }
-        let joueur_id = joueurs_terrain[rng.gen_range(0..joueurs_terrain.len())];
+        let joueur_id = joueurs_terrain[self.rng.gen_range(0..joueurs_terrain.len())];
 
         let actions = [
             "Dribble dévastateur !",
@@ -499,20 +1038,43 @@ impl MoteurMatch {
             "Reprise de volée technique !",
             "Passe en profondeur parfaite !",
         ];
-        let desc = actions[rng.gen_range(0..actions.len())].to_string();
+        let desc = actions[self.rng.gen_range(0..actions.len())].to_string();
 
-        self.evenements.push(EvenementMatch::BelleAction {
+        self.consigner(EvenementMatch::BelleAction {
             minute,
             joueur_id,
             description: desc,
         });
     }
 
-    fn terminer_match(&mut self) {
+    /// Fin du temps de jeu : tirs au but en cas d'égalité si les règles le prévoient
+    fn terminer_temps_de_jeu(&mut self) {
+        if self.est_a_egalite() && self.regles.tirs_au_but {
+            self.demarrer_tirs_au_but();
+        } else {
+            self.terminer_match();
+        }
+    }
+
+    pub(crate) fn terminer_match(&mut self) {
         self.en_jeu = false;
         self.periode = PeriodeMatch::Termine;
 
-        // Mettre à jour les statistiques des équipes
+        // Les matchs de coupe n'entrent pas dans le bilan de saison
+        if self.competition == Competition::Championnat {
+            self.comptabiliser_bilan();
+        }
+
+        self.attribuer_notes();
+
+        // Suspensions : purgées par ceux qui ont manqué ce match, effectives pour les sanctionnés
+        for j in self.equipe_domicile.joueurs.iter_mut().chain(self.equipe_exterieur.joueurs.iter_mut()) {
+            j.cloturer_match();
+        }
+    }
+
+    /// Reporter le résultat dans les statistiques de saison des équipes
+    fn comptabiliser_bilan(&mut self) {
         match self.score_domicile.cmp(&self.score_exterieur) {
             std::cmp::Ordering::Greater => {
                 self.equipe_domicile.victoires += 1;
@@ -534,6 +1096,17 @@ impl MoteurMatch {
         self.equipe_exterieur.buts_encaisses += self.score_domicile;
     }
 
+    /// Score cumulé (domicile, extérieur), match aller compris
+    pub fn score_cumule(&self) -> (u32, u32) {
+        let (aller_d, aller_e) = self.buts_aller.unwrap_or((0, 0));
+        (self.score_domicile + aller_d, self.score_exterieur + aller_e)
+    }
+
+    fn est_a_egalite(&self) -> bool {
+        let (d, e) = self.score_cumule();
+        d == e
+    }
+
     pub fn get_minute_actuelle(&self) -> u32 {
         (self.temps_ecoule / 60.0) as u32
     }
@@ -544,9 +1117,25 @@ impl MoteurMatch {
         format!("{:02}:{:02}", minutes, secondes)
     }
 
+    /// Nom de la période en cours, numérotée si le match n'est pas en deux mi-temps
+    pub fn get_nom_periode(&self) -> String {
+        match self.periode {
+            PeriodeMatch::PremiereMitemps | PeriodeMatch::DeuxiemeMitemps if self.regles.nb_periodes != 2 => {
+                format!("Période {}/{}", self.numero_periode, self.regles.nb_periodes)
+            }
+            PeriodeMatch::MiTemps if self.regles.nb_periodes != 2 => "Pause".to_string(),
+            periode => periode.get_nom().to_string(),
+        }
+    }
+
     pub fn get_vainqueur(&self) -> Option<u32> {
         if self.periode != PeriodeMatch::Termine { return None; }
-        match self.score_domicile.cmp(&self.score_exterieur) {
+        // À égalité (sur l'ensemble des deux matchs), la séance de tirs au but départage les équipes
+        let (score_d, score_e) = match &self.seance_tirs_au_but {
+            Some(seance) if self.est_a_egalite() => seance.score(),
+            _ => self.score_cumule(),
+        };
+        match score_d.cmp(&score_e) {
             std::cmp::Ordering::Greater => Some(self.equipe_domicile.id),
             std::cmp::Ordering::Less => Some(self.equipe_exterieur.id),
             std::cmp::Ordering::Equal => None,
@@ -554,14 +1143,29 @@ impl MoteurMatch {
     }
 
     pub fn get_score_affichage(&self) -> String {
-        format!("{} - {}", self.score_domicile, self.score_exterieur)
+        let score = match &self.seance_tirs_au_but {
+            Some(seance) => {
+                let (tab_d, tab_e) = seance.score();
+                format!("{} - {} ({} - {} t.a.b.)", self.score_domicile, self.score_exterieur, tab_d, tab_e)
+            }
+            None => format!("{} - {}", self.score_domicile, self.score_exterieur),
+        };
+        match self.buts_aller {
+            Some(_) => {
+                let (cumul_d, cumul_e) = self.score_cumule();
+                format!("{} [cumul {} - {}]", score, cumul_d, cumul_e)
+            }
+            None => score,
+        }
     }
 
     pub fn get_evenements_recents(&self, n: usize) -> Vec<&EvenementMatch> {
         self.evenements.iter().rev().take(n).collect()
     }
 
-    pub fn faire_substitution(&mut self, equipe_id: u32, sortant_id: u32, entrant_id: u32) -> Result<(), String> {
+    /// Activer le power-up n° `index` de l'inventaire de l'équipe sur un joueur du terrain
+    pub fn activer_power_up(&mut self, equipe_id: u32, joueur_id: u32, index: usize) -> Result<TypePowerUp, String> {
+        let minute = self.get_minute_actuelle();
         let equipe = if equipe_id == self.equipe_domicile.id {
             &mut self.equipe_domicile
         } else if equipe_id == self.equipe_exterieur.id {
@@ -570,9 +1174,52 @@ impl MoteurMatch {
             return Err("Équipe non trouvée".to_string());
         };
 
+        let joueur = equipe.get_joueur(joueur_id).ok_or("Joueur non trouvé")?;
+        if !joueur.sur_le_terrain {
+            return Err("Le joueur n'est pas sur le terrain".to_string());
+        }
+        let type_power_up = equipe.inventaire_power_ups.utiliser(index)
+            .ok_or("Power-up non disponible")?;
+
+        if let Some(joueur) = equipe.get_joueur_mut(joueur_id) {
+            joueur.ajouter_bonus_temporaire(type_power_up.get_nom(), type_power_up.get_modificateurs(), type_power_up.get_duree());
+        }
+
+        self.consigner(EvenementMatch::PowerUpUtilise {
+            minute,
+            joueur_id,
+            type_power_up,
+        });
+
+        Ok(type_power_up)
+    }
+
+    pub fn faire_substitution(&mut self, equipe_id: u32, sortant_id: u32, entrant_id: u32) -> Result<(), String> {
+        let (equipe, effectues) = if equipe_id == self.equipe_domicile.id {
+            (&mut self.equipe_domicile, &mut self.remplacements_domicile)
+        } else if equipe_id == self.equipe_exterieur.id {
+            (&mut self.equipe_exterieur, &mut self.remplacements_exterieur)
+        } else {
+            return Err("Équipe non trouvée".to_string());
+        };
+
+        if !self.regles.remplacement_possible(*effectues) {
+            return Err("Plus de remplacement autorisé".to_string());
+        }
+        if !self.regles.retour_autorise && self.joueurs_remplaces.contains(&entrant_id) {
+            return Err("Un joueur remplacé ne peut pas revenir en jeu".to_string());
+        }
+
         equipe.faire_substitution(sortant_id, entrant_id)?;
+        *effectues += 1;
+        self.joueurs_remplaces.push(sortant_id);
 
-        self.evenements.push(EvenementMatch::Substitution {
+        // Le remplaçant reprend la place du sortant sur le terrain
+        if let Some(etat) = self.etats_joueurs.iter_mut().find(|e| e.joueur_id == sortant_id) {
+            etat.joueur_id = entrant_id;
+        }
+
+        self.consigner(EvenementMatch::Substitution {
             minute: self.get_minute_actuelle(),
             equipe_id,
             sortant_id,
@@ -582,3 +1229,892 @@ impl MoteurMatch {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::player::{creer_joueurs_reels, Joueur};
+    use crate::models::team::{Formation, InstructionsTactiques};
+    use crate::models::player::EffetCapacite;
+    use crate::models::power_up::ModificateursPowerUp;
+    use crate::models::simulation_spatiale::{DEMI_LARGEUR_TERRAIN, DEMI_LONGUEUR_TERRAIN};
+    use crate::models::regles::Remplacements;
+    use crate::models::coups_de_pied_arretes::IssueCoupArrete;
+    use crate::models::entraineur_ia::{NiveauDifficulte, StyleEntraineur};
+    use crate::models::decouvertes::catalogue_decouvertes;
+    use crate::models::scientific_domain::ScientificDomain;
+
+    fn creer_equipes_test() -> (Equipe, Equipe) {
+        let mut equipe1 = Equipe::new(1, "Test Rouge");
+        let mut equipe2 = Equipe::new(2, "Test Bleu");
+        for j in creer_joueurs_reels() {
+            if j.id <= 8 {
+                let _ = equipe1.ajouter_joueur(j);
+            } else {
+                let _ = equipe2.ajouter_joueur(j);
+            }
+        }
+        equipe1.selectionner_titulaires_auto();
+        equipe2.selectionner_titulaires_auto();
+        (equipe1, equipe2)
+    }
+
+    fn jouer_match_complet(moteur: &mut MoteurMatch) {
+        moteur.demarrer();
+        while moteur.periode != PeriodeMatch::Termine {
+            moteur.mise_a_jour(0.1);
+            if moteur.periode == PeriodeMatch::MiTemps {
+                moteur.reprendre();
+            }
+        }
+    }
+
+    /// Match entre les deux équipes de test, prêt à démarrer
+    fn nouveau_match(graine: u64, regles: RegleMatch) -> MoteurMatch {
+        let (e1, e2) = creer_equipes_test();
+        MoteurMatch::avec_graine(1, e1, e2, regles, graine)
+    }
+
+    /// Match joué jusqu'au bout entre les deux équipes de test
+    fn match_joue(graine: u64, regles: RegleMatch) -> MoteurMatch {
+        let mut m = nouveau_match(graine, regles);
+        jouer_match_complet(&mut m);
+        m
+    }
+
+    #[test]
+    fn test_meme_graine_meme_match() {
+        let m1 = match_joue(42, RegleMatch::default());
+        let m2 = match_joue(42, RegleMatch::default());
+        assert_eq!(m1.evenements, m2.evenements);
+        assert_eq!(m1.get_score_affichage(), m2.get_score_affichage());
+    }
+
+    #[test]
+    fn test_graines_differentes_matchs_differents() {
+        let reference = match_joue(1, RegleMatch::default());
+        let au_moins_un_different = (2..10)
+            .any(|graine| match_joue(graine, RegleMatch::default()).evenements != reference.evenements);
+        assert!(au_moins_un_different, "Les graines doivent produire des matchs différents");
+    }
+
+    #[test]
+    fn test_reprise_apres_serialisation() {
+        let mut original = nouveau_match(2024, RegleMatch::default());
+        original.demarrer();
+        for _ in 0..3000 {
+            original.mise_a_jour(0.1);
+        }
+
+        // Sauvegarder en plein match puis rejouer la suite des deux côtés
+        let json = serde_json::to_string(&original).unwrap();
+        let mut restaure: MoteurMatch = serde_json::from_str(&json).unwrap();
+        assert_eq!(restaure.graine, 2024);
+
+        for m in [&mut original, &mut restaure] {
+            while m.periode != PeriodeMatch::Termine {
+                m.mise_a_jour(0.1);
+                if m.periode == PeriodeMatch::MiTemps {
+                    m.reprendre();
+                }
+            }
+        }
+        assert_eq!(original.evenements, restaure.evenements);
+        assert_eq!(original.score_domicile, restaure.score_domicile);
+        assert_eq!(original.score_exterieur, restaure.score_exterieur);
+    }
+
+    #[test]
+    fn test_spatial_un_seul_porteur_et_ballon_dans_le_terrain() {
+        let mut m = nouveau_match(99, RegleMatch::default());
+        m.demarrer();
+        assert_eq!(m.etats_joueurs.len(), 10);
+        while m.periode != PeriodeMatch::Termine {
+            m.mise_a_jour(0.1);
+            if m.periode == PeriodeMatch::MiTemps {
+                m.reprendre();
+            }
+            assert!(m.etats_joueurs.iter().filter(|e| e.a_le_ballon).count() <= 1);
+            assert!(m.ballon_x.abs() <= DEMI_LONGUEUR_TERRAIN && m.ballon_z.abs() <= DEMI_LARGEUR_TERRAIN);
+        }
+    }
+
+    #[test]
+    fn test_spatial_produit_des_tirs() {
+        let tirs: u32 = (0..5).map(|graine| {
+            let m = match_joue(graine, RegleMatch::default());
+            let tirs = m.statistiques.domicile.tirs + m.statistiques.exterieur.tirs;
+            // Chaque tentative compte, qu'elle finisse au fond, arrêtée, à côté ou contrée
+            let evenements = m.evenements.iter().filter(|e| matches!(e, EvenementMatch::Tir { .. })).count();
+            assert_eq!(tirs as usize, evenements);
+            assert!(m.score_domicile + m.score_exterieur <= tirs);
+            tirs
+        }).sum();
+        assert!(tirs >= 20, "Trop peu de tirs en simulation spatiale : {}", tirs);
+    }
+
+    #[test]
+    fn test_mode_probabiliste_toujours_disponible() {
+        let mut m = nouveau_match(5, RegleMatch::default());
+        m.mode_simulation = ModeSimulation::Probabiliste;
+        jouer_match_complet(&mut m);
+        assert_eq!(m.periode, PeriodeMatch::Termine);
+    }
+
+    #[test]
+    fn test_substitution_remplace_l_etat_du_joueur() {
+        let (e1, e2) = creer_equipes_test();
+        let sortant = e1.joueurs.iter().find(|j| j.sur_le_terrain && j.position_actuelle != Position::Gardien).unwrap().id;
+        let entrant = e1.joueurs.iter().find(|j| !j.sur_le_terrain).unwrap().id;
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 11);
+        m.demarrer();
+        m.faire_substitution(1, sortant, entrant).unwrap();
+        assert!(m.etats_joueurs.iter().any(|e| e.joueur_id == entrant));
+        assert!(!m.etats_joueurs.iter().any(|e| e.joueur_id == sortant));
+        // Le remplaçant prend le poste du sortant
+        let poste_sortant = m.equipe_domicile.get_joueur(sortant).unwrap().position_actuelle;
+        assert_eq!(m.equipe_domicile.get_joueur(entrant).unwrap().position_actuelle, poste_sortant);
+    }
+
+    #[test]
+    fn test_instructions_tiennent_compte_de_l_adversaire() {
+        let contre = InstructionsTactiques { contre_attaque: true, possession: false, ..Default::default() };
+        let ligne_haute = InstructionsTactiques { ligne_haute: true, ..Default::default() };
+        let neutre = InstructionsTactiques::default();
+
+        // Une ligne haute est plus exposée face à une équipe qui contre
+        assert!(ligne_haute.modificateurs(&contre).exposition > ligne_haute.modificateurs(&neutre).exposition);
+        // ... et le contre est plus dangereux face à une ligne haute
+        assert!(contre.modificateurs(&ligne_haute).bonus_contre > contre.modificateurs(&neutre).bonus_contre);
+
+        let tirs_loin = InstructionsTactiques { tirs_a_distance: true, ..Default::default() };
+        assert!(tirs_loin.modificateurs(&neutre).portee_tir > neutre.modificateurs(&neutre).portee_tir);
+    }
+
+    #[test]
+    fn test_pressing_haut_fatigue_davantage() {
+        let stamina_moyenne = |pressing: bool| {
+            let (mut e1, e2) = creer_equipes_test();
+            e1.instructions.pressing_haut = pressing;
+            let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 8);
+            jouer_match_complet(&mut m);
+            let titulaires = m.equipe_domicile.get_titulaires();
+            titulaires.iter().map(|j| j.stamina).sum::<f32>() / titulaires.len() as f32
+        };
+        assert!(stamina_moyenne(true) < stamina_moyenne(false));
+    }
+
+    #[test]
+    fn test_formations_profils_distincts() {
+        // Chaque formation place ses joueurs différemment
+        let avant_112 = Formation::F112.position_de_base(Position::Attaquant, 0, 2);
+        let arriere_211 = Formation::F211.position_de_base(Position::Defenseur, 0, 2);
+        assert!(avant_112.0 > 0.0 && arriere_211.0 < 0.0);
+        assert_ne!(Formation::F1111.position_de_base(Position::Milieu, 0, 2), Formation::F121.position_de_base(Position::Milieu, 0, 2));
+
+        // Les attaquants tirent davantage, le gardien jamais
+        assert!(Formation::F121.poids_tireur(Position::Attaquant) > Formation::F121.poids_tireur(Position::Defenseur));
+        assert_eq!(Formation::F121.poids_tireur(Position::Gardien), 0.0);
+
+        assert!(Formation::F211.couverture_defensive() < Formation::F112.couverture_defensive());
+        assert!(Formation::F121.avantage_contre(&Formation::F112) > 1.0);
+    }
+
+    #[test]
+    fn test_2_1_1_concede_moins_d_occasions_que_1_1_2() {
+        let tirs_concedes = |formation: Formation| -> usize {
+            (0..10).map(|graine| {
+                let (mut e1, e2) = creer_equipes_test();
+                e1.formation = formation;
+                e1.selectionner_titulaires_auto();
+                let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), graine);
+                jouer_match_complet(&mut m);
+                m.evenements.iter()
+                    .filter(|e| match e {
+                        EvenementMatch::But { equipe_id, .. } => *equipe_id == 2,
+                        EvenementMatch::SauvetageGardien { gardien_id, .. } => *gardien_id <= 8,
+                        _ => false,
+                    })
+                    .count()
+            }).sum()
+        };
+        assert!(tirs_concedes(Formation::F211) < tirs_concedes(Formation::F112));
+    }
+
+    #[test]
+    fn test_power_up_applique_puis_expire() {
+        let (mut e1, e2) = creer_equipes_test();
+        e1.inventaire_power_ups.ajouter(TypePowerUp::VitesseQuantique);
+        // Un joueur à son poste, dont les stats ne bougent pas avec le temps de jeu
+        let joueur_id = e1.joueurs.iter().find(|j| j.sur_le_terrain && j.position_actuelle == j.position_preferee).unwrap().id;
+        let vitesse_initiale = e1.get_joueur(joueur_id).unwrap().stats_effectives.vitesse;
+
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 3);
+        m.demarrer();
+        assert_eq!(m.activer_power_up(1, joueur_id, 0), Ok(TypePowerUp::VitesseQuantique));
+        assert!(m.equipe_domicile.inventaire_power_ups.disponibles.is_empty());
+        assert!(m.evenements.iter().any(|e| matches!(e, EvenementMatch::PowerUpUtilise { joueur_id: id, .. } if *id == joueur_id)));
+        assert!(m.equipe_domicile.get_joueur(joueur_id).unwrap().stats_effectives.vitesse > vitesse_initiale);
+
+        // Inventaire vide : plus rien à activer
+        assert!(m.activer_power_up(1, joueur_id, 0).is_err());
+
+        // Au-delà de la durée, le bonus disparaît
+        let duree = TypePowerUp::VitesseQuantique.get_duree();
+        let mut t = 0.0;
+        while t < duree + 1.0 {
+            m.mise_a_jour(0.5);
+            t += 0.5;
+        }
+        let joueur = m.equipe_domicile.get_joueur(joueur_id).unwrap();
+        assert!(joueur.bonus_temporaires.is_empty());
+        assert_eq!(joueur.stats_effectives.vitesse, vitesse_initiale);
+    }
+
+    #[test]
+    fn test_capacite_pare_feu_bloque_les_tirs() {
+        let (e1, mut e2) = creer_equipes_test();
+        let defenseur = e2.joueurs.iter_mut().find(|j| j.sur_le_terrain).unwrap();
+        defenseur.capacite_speciale.effet = EffetCapacite::BlocageTirs { duree: 20.0 };
+        let defenseur_id = defenseur.id;
+        let tireur_id = e1.joueurs.iter().find(|j| j.sur_le_terrain).unwrap().id;
+
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 4);
+        m.capacites_auto_domicile = false;
+        m.capacites_auto_exterieur = false;
+        m.demarrer();
+        m.utiliser_capacite(2, defenseur_id).unwrap();
+        assert!(m.evenements.iter().any(|e| matches!(e, EvenementMatch::CapaciteUtilisee { joueur_id, .. } if *joueur_id == defenseur_id)));
+        // En recharge : impossible de relancer tout de suite
+        assert!(m.utiliser_capacite(2, defenseur_id).is_err());
+
+        for _ in 0..50 {
+            assert_ne!(m.resoudre_tir(true, tireur_id, None, 1.0, 1, None), ResultatTir::But);
+        }
+
+        // Le pare-feu expire au bout de sa durée
+        m.mise_a_jour_capacites(21.0);
+        assert!(!m.tirs_bloques(2));
+    }
+
+    #[test]
+    fn test_capacite_collective_et_tir_puissant() {
+        let (mut e1, e2) = creer_equipes_test();
+        let lanceur = e1.joueurs.iter_mut().find(|j| j.sur_le_terrain).unwrap();
+        lanceur.capacite_speciale.effet = EffetCapacite::BonusEquipe {
+            modificateurs: ModificateursPowerUp { vitesse: 1.15, ..Default::default() },
+            duree: 30.0,
+        };
+        let lanceur_id = lanceur.id;
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 4);
+        m.capacites_auto_domicile = false;
+        m.capacites_auto_exterieur = false;
+        m.utiliser_capacite(1, lanceur_id).unwrap();
+        assert!(m.equipe_domicile.get_titulaires().iter().all(|j| j.bonus_temporaires.len() == 1));
+
+        // Le tir puissant ne sert qu'une fois
+        m.effets_actifs.push(EffetActif {
+            equipe_id: 1,
+            joueur_id: lanceur_id,
+            effet: EffetCapacite::TirPuissant { multiplicateur: 2.0, duree: 30.0 },
+            temps_restant: 30.0,
+        });
+        assert_eq!(m.consommer_tir_puissant(lanceur_id), 2.0);
+        assert_eq!(m.consommer_tir_puissant(lanceur_id), 1.0);
+    }
+
+    #[test]
+    fn test_ia_declenche_les_capacites() {
+        let m = match_joue(6, RegleMatch::default());
+        let utilisations = m.evenements.iter()
+            .filter(|e| matches!(e, EvenementMatch::CapaciteUtilisee { .. }))
+            .count();
+        assert!(utilisations > 5, "Trop peu de capacités déclenchées : {}", utilisations);
+    }
+
+    #[test]
+    fn test_frappe_approximative_de_roland() {
+        let buts = |avec_traits: bool| {
+            let (mut e1, e2) = creer_equipes_test();
+            let roland = e1.joueurs.iter_mut().find(|j| j.prenom == "Roland").unwrap();
+            if !avec_traits {
+                roland.traits.retain(|t| t.nom != "Frappe Approximative");
+            }
+            let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 9);
+            (0..400).filter(|_| m.resoudre_tir(true, 1, None, 1.0, 1, None) == ResultatTir::But).count()
+        };
+        assert!(buts(true) < buts(false));
+    }
+
+    #[test]
+    fn test_expulsion_joue_a_un_de_moins_et_suspend() {
+        let mut m = nouveau_match(12, RegleMatch::default());
+        m.demarrer();
+        let fautif_id = m.equipe_exterieur.get_titulaires()[1].id;
+        while !m.evenements.iter().any(|e| matches!(e, EvenementMatch::CartonRouge { .. })) {
+            m.sanctionner_faute(false, fautif_id, 5);
+        }
+        let Some(EvenementMatch::CartonRouge { equipe_id, joueur_id, .. }) = m.evenements.iter()
+            .find(|e| matches!(e, EvenementMatch::CartonRouge { .. })).cloned() else { unreachable!() };
+
+        let est_domicile = equipe_id == m.equipe_domicile.id;
+        let equipe = if est_domicile { &m.equipe_domicile } else { &m.equipe_exterieur };
+        assert_eq!(equipe.get_titulaires().len(), 4);
+        assert!(!m.etats_joueurs.iter().any(|e| e.joueur_id == joueur_id));
+        assert!(m.note_en_match(est_domicile) < equipe.note_equipe() * (1.0 + if est_domicile { m.bonus_domicile } else { m.bonus_exterieur }));
+
+        // Impossible de le faire revenir en jeu
+        let sortant_id = equipe.get_titulaires()[0].id;
+        assert!(m.faire_substitution(equipe_id, sortant_id, joueur_id).is_err());
+
+        // La suspension vaut pour le match suivant
+        m.terminer_match();
+        let equipe = if est_domicile { &m.equipe_domicile } else { &m.equipe_exterieur };
+        let expulse = equipe.get_joueur(joueur_id).unwrap();
+        assert!(expulse.suspendu && !expulse.est_disponible());
+    }
+
+    #[test]
+    fn test_cartons_et_blessures_visent_le_camp_donne() {
+        let (e1, e2) = creer_equipes_test();
+        let id_domicile = e1.get_titulaires()[1].id;
+        let id_exterieur = e2.get_titulaires()[1].id;
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 12);
+        m.demarrer();
+
+        for _ in 0..1000 {
+            if m.equipe_exterieur.get_joueur(id_exterieur).unwrap().expulse { break; }
+            m.sanctionner_faute(false, id_exterieur, 5);
+        }
+        assert!(m.equipe_exterieur.get_joueur(id_exterieur).unwrap().expulse);
+        assert!(m.equipe_domicile.joueurs.iter().all(|j| !j.expulse && j.cartons_jaunes_match == 0));
+        assert!(!m.etats_joueurs.iter().any(|e| e.joueur_id == id_exterieur));
+
+        for _ in 0..1000 {
+            if m.equipe_domicile.get_joueur(id_domicile).unwrap().blesse { break; }
+            m.tester_blessure(true, id_domicile, 50.0);
+        }
+        assert!(m.equipe_domicile.get_joueur(id_domicile).unwrap().blesse);
+        assert!(m.equipe_exterieur.joueurs.iter().all(|j| !j.blesse));
+    }
+
+    #[test]
+    fn test_blessure_force_le_remplacement() {
+        let (e1, e2) = creer_equipes_test();
+        // L'équipe 2 n'a que deux remplaçants
+        let remplacants: Vec<u32> = e2.get_remplacants().iter().map(|j| j.id).collect();
+        assert!(!remplacants.is_empty());
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 3);
+        m.demarrer();
+
+        let blesser = |m: &mut MoteurMatch| {
+            let victime = m.equipe_exterieur.get_titulaires()[0].id;
+            while !m.equipe_exterieur.get_joueur(victime).unwrap().blesse {
+                m.tester_blessure(false, victime, 50.0);
+            }
+            victime
+        };
+
+        // Tant qu'il reste des remplaçants, le blessé est remplacé
+        for _ in &remplacants {
+            let victime = blesser(&mut m);
+            assert!(!m.equipe_exterieur.get_joueur(victime).unwrap().sur_le_terrain);
+            assert_eq!(m.equipe_exterieur.get_titulaires().len(), 5);
+        }
+        assert!(m.evenements.iter().any(|e| matches!(e, EvenementMatch::Substitution { .. })));
+
+        // Ensuite l'équipe joue à un de moins
+        let victime = blesser(&mut m);
+        assert_eq!(m.equipe_exterieur.get_titulaires().len(), 4);
+        assert!(!m.etats_joueurs.iter().any(|e| e.joueur_id == victime));
+
+        // La blessure se prolonge au-delà du match
+        m.terminer_match();
+        assert!(!m.equipe_exterieur.get_joueur(victime).unwrap().est_disponible());
+    }
+
+    #[test]
+    fn test_recuperation_mi_temps_et_banc() {
+        let (e1, e2) = creer_equipes_test();
+        let remplacant_id = e1.get_remplacants()[0].id;
+        let titulaire_id = e1.get_titulaires()[0].id;
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 6);
+        m.equipe_domicile.get_joueur_mut(remplacant_id).unwrap().stamina = 40.0;
+        m.demarrer();
+        while m.temps_ecoule + 0.5 < 600.0 {
+            m.mise_a_jour(0.5);
+        }
+        // Le remplaçant a récupéré sur le banc
+        assert!(m.equipe_domicile.get_joueur(remplacant_id).unwrap().stamina > 40.0);
+
+        // La pause fait remonter la stamina des titulaires
+        let avant_pause = m.equipe_domicile.get_joueur(titulaire_id).unwrap().stamina;
+        m.mise_a_jour(0.5);
+        assert_eq!(m.periode, PeriodeMatch::MiTemps);
+        assert!(m.equipe_domicile.get_joueur(titulaire_id).unwrap().stamina > avant_pause);
+    }
+
+    #[test]
+    fn test_regles_durees_et_periodes() {
+        let m = match_joue(8, RegleMatch::session_5v5());
+        assert!(m.temps_ecoule >= 3000.0 && m.temps_ecoule < 3001.0);
+
+        // Trois tiers-temps : deux pauses
+        let regles = RegleMatch { duree_periode: 300.0, nb_periodes: 3, ..RegleMatch::default() };
+        let mut m = nouveau_match(8, regles);
+        m.demarrer();
+        let mut pauses = 0;
+        while m.periode != PeriodeMatch::Termine {
+            m.mise_a_jour(0.1);
+            if m.periode == PeriodeMatch::MiTemps {
+                pauses += 1;
+                m.reprendre();
+            }
+        }
+        assert_eq!(pauses, 2);
+        assert_eq!(m.numero_periode, 3);
+        assert!(m.temps_ecoule < 901.0);
+    }
+
+    #[test]
+    fn test_prolongation_et_but_en_or() {
+        let mut m = nouveau_match(8, RegleMatch::soiree_coupe());
+        m.mode_simulation = ModeSimulation::Probabiliste;
+        m.demarrer();
+        while m.periode != PeriodeMatch::Termine && m.periode != PeriodeMatch::Prolongation {
+            m.mise_a_jour(0.1);
+            // Égalité forcée à la fin du temps réglementaire
+            m.score_exterieur = m.score_domicile;
+            if m.periode == PeriodeMatch::MiTemps {
+                m.reprendre();
+            }
+        }
+        assert_eq!(m.periode, PeriodeMatch::Prolongation);
+
+        // Le premier but de la prolongation termine le match
+        while m.resoudre_tir(true, m.equipe_domicile.get_titulaires()[1].id, None, 1.0, 41, None) != ResultatTir::But {}
+        assert_eq!(m.periode, PeriodeMatch::Termine);
+        assert_eq!(m.get_vainqueur(), Some(m.equipe_domicile.id));
+    }
+
+    #[test]
+    fn test_remplacements_limites_sans_retour() {
+        let (e1, e2) = creer_equipes_test();
+        let regles = RegleMatch { remplacements: Remplacements::Limites(2), retour_autorise: false, ..RegleMatch::default() };
+        let titulaires: Vec<u32> = e1.get_titulaires().iter().map(|j| j.id).collect();
+        let remplacants: Vec<u32> = e1.get_remplacants().iter().map(|j| j.id).collect();
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, regles, 8);
+        m.demarrer();
+
+        m.faire_substitution(1, titulaires[1], remplacants[0]).unwrap();
+        // Le joueur remplacé ne peut pas revenir
+        assert!(m.faire_substitution(1, remplacants[0], titulaires[1]).is_err());
+        m.faire_substitution(1, titulaires[2], remplacants[1]).unwrap();
+        // Quota atteint
+        assert!(m.faire_substitution(1, titulaires[3], remplacants[2]).is_err());
+
+        // Avec les changements volants, tout est permis
+        let mut m = nouveau_match(8, RegleMatch::default());
+        m.faire_substitution(1, titulaires[1], remplacants[0]).unwrap();
+        m.faire_substitution(1, remplacants[0], titulaires[1]).unwrap();
+        m.faire_substitution(1, titulaires[1], remplacants[0]).unwrap();
+    }
+
+    #[test]
+    fn test_seance_tirs_au_but_terminee() {
+        let seance = |d: &[bool], e: &[bool]| SeanceTirsAuBut {
+            tirs_domicile: d.to_vec(),
+            tirs_exterieur: e.to_vec(),
+            ..Default::default()
+        };
+        // 3-0 après trois tirs chacun : l'extérieur ne peut plus revenir
+        assert!(seance(&[true; 3], &[false; 3]).est_terminee());
+        assert!(!seance(&[true; 3], &[false; 2]).est_terminee());
+        // 5-5 : mort subite, on attend que les deux équipes aient tiré
+        assert!(!seance(&[true; 5], &[true; 5]).est_terminee());
+        assert!(!seance(&[true; 6], &[true; 5]).est_terminee());
+        assert!(seance(&[true; 6], &[true, true, true, true, true, false]).est_terminee());
+    }
+
+    #[test]
+    fn test_tirs_au_but_a_nombre_egal_de_tireurs() {
+        let regles = RegleMatch { tirs_au_but: true, ..RegleMatch::default() };
+        let mut m = nouveau_match(6, regles);
+        m.demarrer();
+        // À quatre contre cinq, l'équipe au complet écarte un tireur
+        let expulse = m.equipe_domicile.get_titulaires()[1].id;
+        m.equipe_domicile.get_joueur_mut(expulse).unwrap().expulser(1);
+        m.demarrer_tirs_au_but();
+        let seance = m.seance_tirs_au_but.clone().unwrap();
+        assert_eq!((seance.ordre_domicile.len(), seance.ordre_exterieur.len()), (4, 4));
+        while m.periode != PeriodeMatch::Termine {
+            m.mise_a_jour(0.1);
+        }
+        let tireurs: Vec<u32> = m.evenements.iter()
+            .filter_map(|e| match e { EvenementMatch::TirAuBut { tireur_id, .. } => Some(*tireur_id), _ => None })
+            .collect();
+        assert!(!tireurs.is_empty() && !tireurs.contains(&expulse));
+
+        // Personne pour tirer d'un côté : pas de séance, et aucun tir fictif consigné
+        let mut m = nouveau_match(6, regles);
+        m.demarrer();
+        for j in &mut m.equipe_exterieur.joueurs {
+            j.sur_le_terrain = false;
+        }
+        m.demarrer_tirs_au_but();
+        assert_eq!(m.periode, PeriodeMatch::Termine);
+        assert!(!m.evenements.iter().any(|e| matches!(e, EvenementMatch::TirAuBut { .. })));
+    }
+
+    #[test]
+    fn test_tirs_au_but_departagent_un_match_nul() {
+        let regles = RegleMatch { tirs_au_but: true, ..RegleMatch::default() };
+        let mut m = nouveau_match(5, regles);
+        m.mode_simulation = ModeSimulation::Probabiliste;
+        let tireur = m.equipe_domicile.get_titulaires()[4].id;
+        m.definir_ordre_tireurs(1, vec![tireur]).unwrap();
+        m.demarrer();
+        while m.periode != PeriodeMatch::Termine && m.periode != PeriodeMatch::TirsAuBut {
+            m.score_exterieur = m.score_domicile;
+            m.mise_a_jour(0.1);
+            if m.periode == PeriodeMatch::MiTemps {
+                m.reprendre();
+            }
+        }
+        assert_eq!(m.periode, PeriodeMatch::TirsAuBut);
+        assert!(m.definir_ordre_tireurs(1, vec![]).is_err());
+        let score = (m.score_domicile, m.score_exterieur);
+
+        while m.periode != PeriodeMatch::Termine {
+            m.mise_a_jour(0.1);
+        }
+        let seance = m.seance_tirs_au_but.clone().unwrap();
+        assert!(seance.est_terminee());
+        // Le tireur désigné ouvre la séance
+        assert_eq!(seance.ordre_domicile[0], tireur);
+        assert!(m.evenements.iter().any(|e| matches!(e, EvenementMatch::TirAuBut { tireur_id, .. } if *tireur_id == tireur)));
+        // Le score du match n'est pas touché, mais il y a un vainqueur
+        assert_eq!((m.score_domicile, m.score_exterieur), score);
+        assert!(m.get_vainqueur().is_some());
+        assert!(m.get_score_affichage().contains("t.a.b."));
+    }
+
+    #[test]
+    fn test_faute_coup_franc_ou_penalty_selon_la_position() {
+        let (e1, mut e2) = creer_equipes_test();
+        let tireur_designe = e2.get_titulaires().iter().find(|j| j.position_actuelle != Position::Gardien).unwrap().id;
+        e2.designer_tireur(CoupDePiedArrete::Penalty, Some(tireur_designe)).unwrap();
+        assert!(e2.designer_tireur(CoupDePiedArrete::Penalty, Some(999)).is_err());
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 4);
+        m.mode_simulation = ModeSimulation::Probabiliste;
+        m.demarrer();
+        let fautif = m.equipe_domicile.get_titulaires()[1].id;
+        let victime = m.equipe_exterieur.get_titulaires()[1].id;
+
+        // Au milieu du terrain : coup franc
+        m.commettre_faute(true, fautif, victime);
+        assert!(matches!(m.evenements[0], EvenementMatch::Faute { coup: CoupDePiedArrete::CoupFranc, equipe_id: 1, .. }));
+
+        // Dans la surface que défend l'équipe à domicile : penalty, tiré par le tireur désigné
+        m.ballon_x = -45.0;
+        m.commettre_faute(true, fautif, victime);
+        assert!(m.evenements.iter().any(|e| matches!(e, EvenementMatch::Faute { coup: CoupDePiedArrete::Penalty, .. })));
+        assert!(m.evenements.iter().any(|e| matches!(e,
+            EvenementMatch::CoupDePiedArrete { coup: CoupDePiedArrete::Penalty, tireur_id, equipe_id: 2, .. } if *tireur_id == tireur_designe)));
+    }
+
+    #[test]
+    fn test_corners_decides_par_le_jeu_de_tete() {
+        let buts_sur_corner = |jeu_de_tete: f32| {
+            let (mut e1, e2) = creer_equipes_test();
+            for j in e1.joueurs.iter_mut() {
+                j.stats_effectives.jeu_de_tete = jeu_de_tete;
+            }
+            let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 6);
+            (0..2000)
+                .filter(|_| m.jouer_coup_de_pied_arrete(CoupDePiedArrete::Corner, true, 50.0, 25.0) == IssueCoupArrete::But)
+                .count()
+        };
+        assert!(buts_sur_corner(95.0) > buts_sur_corner(20.0));
+
+        // Le but est attribué au coup de pied arrêté
+        let mut m = nouveau_match(6, RegleMatch::default());
+        while m.jouer_coup_de_pied_arrete(CoupDePiedArrete::Corner, true, 50.0, 25.0) != IssueCoupArrete::But {}
+        assert!(m.evenements.iter().any(|e| matches!(e,
+            EvenementMatch::But { coup_de_pied_arrete: Some(CoupDePiedArrete::Corner), passeur_id: Some(_), .. })));
+    }
+
+    #[test]
+    fn test_tirs_et_buts_attendus() {
+        let m = match_joue(21, RegleMatch::default());
+
+        let tirs: Vec<&EvenementMatch> = m.evenements.iter().filter(|e| matches!(e, EvenementMatch::Tir { .. })).collect();
+        let buts = m.evenements.iter().filter(|e| matches!(e, EvenementMatch::But { .. })).count();
+        let tirs_gagnants = tirs.iter().filter(|e| matches!(e, EvenementMatch::Tir { resultat: ResultatTir::But, .. })).count();
+        assert_eq!(tirs_gagnants, buts);
+        // Les tirs manqués laissent une trace
+        assert!(tirs.len() > buts);
+        assert!(tirs.iter().any(|e| matches!(e, EvenementMatch::Tir { resultat: ResultatTir::NonCadre | ResultatTir::Contre, .. })));
+        assert!(tirs.iter().all(|e| matches!(e, EvenementMatch::Tir { xg, x, .. } if (0.0..=1.0).contains(xg) && x.abs() <= DEMI_LONGUEUR_TERRAIN)));
+
+        // Totaux par équipe et par joueur
+        let (xg_d, xg_e) = (m.xg_equipe(1), m.xg_equipe(2));
+        assert!(xg_d > 0.0 && xg_e > 0.0);
+        let xg_joueurs_d: f32 = m.equipe_domicile.joueurs.iter().map(|j| m.xg_joueur(j.id)).sum();
+        assert!((xg_joueurs_d - xg_d).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_statistiques_tenues_pendant_le_match() {
+        let mut m = nouveau_match(13, RegleMatch::default());
+        m.demarrer();
+        while m.periode != PeriodeMatch::MiTemps {
+            m.mise_a_jour(0.1);
+        }
+
+        // Consultables dès la mi-temps
+        let stats = m.statistiques.clone();
+        assert!((stats.possession(true) + stats.possession(false) - 1.0).abs() < 1e-3);
+        let titulaire = m.equipe_domicile.get_titulaires()[1].id;
+        let joueur = stats.joueur(titulaire).unwrap();
+        assert!((joueur.minutes - 10.0).abs() < 0.1);
+        assert!(joueur.stamina_depensee > 0.0);
+        assert!(stats.joueur(m.equipe_domicile.get_remplacants()[0].id).is_none());
+
+        m.reprendre();
+        while m.periode != PeriodeMatch::Termine {
+            m.mise_a_jour(0.1);
+        }
+        let compter = |f: fn(&EvenementMatch) -> bool| m.evenements.iter().filter(|e| f(e)).count() as u32;
+        let (d, e) = (&m.statistiques.domicile, &m.statistiques.exterieur);
+        assert_eq!(d.tirs + e.tirs, compter(|e| matches!(e, EvenementMatch::Tir { .. })));
+        assert_eq!(d.arrets + e.arrets, compter(|e| matches!(e, EvenementMatch::SauvetageGardien { .. })));
+        assert_eq!(d.cartons_jaunes + e.cartons_jaunes, compter(|e| matches!(e, EvenementMatch::CartonJaune { .. })));
+        assert!(d.tirs_cadres <= d.tirs && d.tirs_cadres >= m.score_domicile);
+        assert!(d.temps_possession + e.temps_possession > 1100.0);
+
+        // Sauvegardées avec le match
+        let json = serde_json::to_string(&m.statistiques).unwrap();
+        assert_eq!(serde_json::from_str::<StatistiquesMatch>(&json).unwrap(), m.statistiques);
+    }
+
+    #[test]
+    fn test_notes_et_homme_du_match() {
+        let m = match_joue(21, RegleMatch::default());
+
+        // Chaque participant est noté, pas les remplaçants restés sur le banc
+        let notes: Vec<(u32, f32)> = m.statistiques.joueurs.iter()
+            .filter_map(|s| s.note.map(|n| (s.joueur_id, n)))
+            .collect();
+        assert!(notes.len() >= 10);
+        assert!(notes.iter().all(|(_, n)| (1.0..=10.0).contains(n)));
+        let meilleure = notes.iter().map(|(_, n)| *n).fold(f32::MIN, f32::max);
+        let homme_du_match = m.statistiques.homme_du_match.unwrap();
+        assert_eq!(m.statistiques.joueur(homme_du_match).unwrap().note, Some(meilleure));
+
+        // La note entre dans l'historique et fait progresser aussi le gardien
+        let gardien = m.equipe_domicile.joueurs.iter()
+            .find(|j| j.position_actuelle == Position::Gardien && !j.historique_notes.is_empty())
+            .unwrap();
+        assert_eq!(gardien.historique_notes.len(), 1);
+        assert!(gardien.experience > 0 || gardien.niveau > 1);
+        assert!(m.equipe_domicile.get_remplacants().iter().all(|j| j.note_moyenne().is_none()));
+    }
+
+    #[test]
+    fn test_entraineur_ia_reagit_au_score_et_a_la_fatigue() {
+        let (e1, mut e2) = creer_equipes_test();
+        e2.inventaire_power_ups.ajouter(TypePowerUp::ForceNewtonienne);
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 5);
+        m.confier_a_l_ia(false, NiveauDifficulte::Difficile, StyleEntraineur::Pragmatique);
+        m.demarrer();
+
+        // Mené à la 14e minute, avec un joueur de champ à bout de souffle
+        m.temps_ecoule = m.duree_match * 0.7;
+        m.score_domicile = 1;
+        let epuise = m.equipe_exterieur.get_titulaires().into_iter()
+            .find(|j| j.position_actuelle != Position::Gardien).unwrap().id;
+        m.equipe_exterieur.get_joueur_mut(epuise).unwrap().stamina = 5.0;
+        m.entraineur_exterieur.as_mut().unwrap().temps_avant_decision = 0.0;
+        m.mise_a_jour_entraineurs(0.1);
+
+        assert!(m.evenements.iter().any(|e| matches!(e, EvenementMatch::Substitution { sortant_id, .. } if *sortant_id == epuise)));
+        assert_eq!(m.equipe_exterieur.formation, Formation::F112);
+        assert!(m.equipe_exterieur.instructions.pressing_haut);
+        let attaquants = m.equipe_exterieur.get_titulaires().iter().filter(|j| j.position_actuelle == Position::Attaquant).count();
+        assert_eq!(attaquants, 2);
+        assert!(m.equipe_exterieur.inventaire_power_ups.disponibles.is_empty());
+        // L'équipe du joueur n'est pas touchée
+        assert_eq!(m.remplacements_domicile, 0);
+
+        // Devant au score en fin de match : retour au système de départ, on gère l'avance
+        m.score_exterieur = 3;
+        m.entraineur_exterieur.as_mut().unwrap().temps_avant_decision = 0.0;
+        m.mise_a_jour_entraineurs(0.1);
+        assert_eq!(m.equipe_exterieur.formation, Formation::F121);
+        assert!(m.equipe_exterieur.instructions.contre_attaque && !m.equipe_exterieur.instructions.ligne_haute);
+        assert!(m.evenements.iter().any(|e| matches!(e, EvenementMatch::ChangementTactique { equipe_id: 2, .. })));
+    }
+
+    #[test]
+    fn test_difficulte_regle_la_vitesse_de_reaction() {
+        let mut formations = Vec::new();
+        for niveau in [NiveauDifficulte::Facile, NiveauDifficulte::Difficile] {
+            let mut m = nouveau_match(5, RegleMatch::default());
+            m.confier_a_l_ia(false, niveau, StyleEntraineur::Pragmatique);
+            m.demarrer();
+            m.score_domicile = 2;
+            m.temps_ecoule = m.duree_match * 0.5;
+            m.mise_a_jour_entraineurs(25.0);
+            formations.push(m.equipe_exterieur.formation);
+        }
+        // Le niveau facile n'a pas encore relu le match, ni n'aurait réagi si tôt
+        assert_eq!(formations, vec![Formation::F121, Formation::F112]);
+    }
+
+    #[test]
+    fn test_resultat_independant_des_images_par_seconde() {
+        let reference = match_joue(77, RegleMatch::default());
+
+        // Images irrégulières, dont une très longue qui enjambe la mi-temps
+        let mut saccade = nouveau_match(77, RegleMatch::default());
+        saccade.demarrer();
+        let deltas = [0.016, 0.033, 0.25, 0.05, 1.7, 0.016, 45.0];
+        let mut i = 0;
+        while saccade.periode != PeriodeMatch::Termine {
+            saccade.mise_a_jour(deltas[i % deltas.len()]);
+            i += 1;
+            if saccade.periode == PeriodeMatch::MiTemps {
+                assert!(saccade.temps_ecoule < 600.2, "La mi-temps ne doit pas être sautée");
+                saccade.reprendre();
+            }
+        }
+        assert_eq!(saccade.evenements, reference.evenements);
+
+        // Accélérer l'affichage ne change pas le match
+        let mut accelere = nouveau_match(77, RegleMatch::default());
+        accelere.vitesse_simulation = 8.0;
+        accelere.demarrer();
+        while accelere.periode != PeriodeMatch::Termine {
+            accelere.mise_a_jour(1.0 / 60.0);
+            if accelere.periode == PeriodeMatch::MiTemps {
+                accelere.reprendre();
+            }
+        }
+        assert_eq!(accelere.get_score_affichage(), reference.get_score_affichage());
+        assert_eq!(accelere.evenements, reference.evenements);
+    }
+
+    #[test]
+    fn test_simulation_instantanee() {
+        let regles = RegleMatch::soiree_coupe();
+        let mut reference = nouveau_match(31, regles);
+        reference.confier_a_l_ia(false, NiveauDifficulte::Difficile, StyleEntraineur::Pragmatique);
+        jouer_match_complet(&mut reference);
+
+        // Même résultat d'un seul appel, y compris repris en cours de match
+        let mut instantane = nouveau_match(31, regles);
+        instantane.confier_a_l_ia(false, NiveauDifficulte::Difficile, StyleEntraineur::Pragmatique);
+        instantane.demarrer();
+        instantane.mise_a_jour(200.0);
+        instantane.simuler_jusqu_a_la_fin();
+        assert_eq!(instantane.periode, PeriodeMatch::Termine);
+        assert_eq!(instantane.evenements, reference.evenements);
+        assert_eq!(instantane.get_vainqueur(), reference.get_vainqueur());
+
+        // Un match déjà lancé n'est pas redémarré (aucun état spatial en mode probabiliste)
+        let mut m = nouveau_match(5, RegleMatch::default());
+        m.mode_simulation = ModeSimulation::Probabiliste;
+        m.demarrer();
+        m.simuler_jusqu_a_la_fin();
+        assert!(m.equipe_domicile.joueurs.iter().all(|j| j.matchs_joues <= 1));
+    }
+
+    #[test]
+    fn test_decouvertes_par_paire_et_temporaires() {
+        use ScientificDomain::*;
+        // Chaque paire a son catalogue, quel que soit l'ordre des domaines
+        assert_eq!(catalogue_decouvertes(Informatique, Mathematiques), catalogue_decouvertes(Mathematiques, Informatique));
+        assert_eq!(catalogue_decouvertes(Chimie, Cyberscurite), catalogue_decouvertes(Cyberscurite, Chimie));
+        assert_ne!(catalogue_decouvertes(Chimie, Cyberscurite), catalogue_decouvertes(Chimie, Electronique));
+        let decouverte = &catalogue_decouvertes(Informatique, Mathematiques)[0];
+        let (fort, long) = decouverte.effet(1.0);
+        let (faible, court) = decouverte.effet(0.5);
+        assert!(fort.intelligence > faible.intelligence && faible.intelligence > 1.0);
+        assert!(long > court);
+
+        let (e1, e2) = creer_equipes_test();
+        let formes: Vec<f32> = e1.joueurs.iter().chain(&e2.joueurs).map(|j| j.forme).collect();
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 17);
+        m.demarrer();
+        m.generer_decouverte_scientifique(3);
+        let Some(EvenementMatch::DecouverteScientifique { equipe_id, joueurs_ids, description, duree, .. }) = m.evenements.last().cloned() else {
+            panic!("Aucune découverte consignée");
+        };
+        let equipe = if equipe_id == m.equipe_domicile.id { &m.equipe_domicile } else { &m.equipe_exterieur };
+        for id in joueurs_ids {
+            let j = equipe.get_joueur(id).unwrap();
+            assert!(j.sur_le_terrain);
+            assert!(description.contains(&j.prenom), "{}", description);
+        }
+        assert!(equipe.get_titulaires().iter().all(|j| j.bonus_temporaires.len() == 1));
+
+        // Le bonus expire en cours de match et la forme n'a pas bougé
+        m.mise_a_jour(duree + 1.0);
+        let formes_apres: Vec<f32> = m.equipe_domicile.joueurs.iter().chain(&m.equipe_exterieur.joueurs).map(|j| j.forme).collect();
+        assert_eq!(formes_apres, formes);
+        assert!(m.equipe_domicile.joueurs.iter().chain(&m.equipe_exterieur.joueurs)
+            .all(|j| j.bonus_temporaires.iter().all(|b| b.temps_restant > 0.0 && !description.contains(&b.source))));
+    }
+
+    #[test]
+    fn test_match_de_coupe_hors_bilan_et_score_cumule() {
+        let regles = RegleMatch::default().pour_match_de_coupe(true);
+        // Le match retour se joue avec trois buts d'avance à l'aller pour l'équipe qui reçoit
+        let mut m = nouveau_match(8, regles);
+        m.competition = Competition::Coupe;
+        m.buts_aller = Some((3, 0));
+        m.simuler_jusqu_a_la_fin();
+
+        let (cumul_d, cumul_e) = m.score_cumule();
+        assert_eq!((cumul_d, cumul_e), (m.score_domicile + 3, m.score_exterieur));
+        if cumul_d != cumul_e {
+            assert!(m.seance_tirs_au_but.is_none());
+            let attendu = if cumul_d > cumul_e { m.equipe_domicile.id } else { m.equipe_exterieur.id };
+            assert_eq!(m.get_vainqueur(), Some(attendu));
+        }
+        assert!(m.get_vainqueur().is_some());
+        for equipe in [&m.equipe_domicile, &m.equipe_exterieur] {
+            assert_eq!((equipe.victoires, equipe.nuls, equipe.defaites, equipe.buts_marques), (0, 0, 0, 0));
+        }
+        assert!(m.get_score_affichage().contains("cumul"));
+    }
+
+    #[test]
+    fn test_provocateur_reduit_l_intelligence_adverse() {
+        let (e1, mut e2) = creer_equipes_test();
+        // Aurélien (Provocateur Dosé) sur le terrain côté extérieur
+        if !e2.get_joueur(9).unwrap().sur_le_terrain {
+            let sortant = e2.get_titulaires().iter()
+                .find(|j| j.position_actuelle != Position::Gardien).unwrap().id;
+            e2.faire_substitution(sortant, 9).unwrap();
+        }
+        let mut m = MoteurMatch::avec_graine(1, e1, e2, RegleMatch::default(), 3);
+        m.demarrer();
+        m.mise_a_jour(0.1);
+
+        let sans_provocation = |j: &Joueur| {
+            let mut j = j.clone();
+            j.changer_contexte(ContexteMatch { intelligence_subie: 1.0, ..j.contexte });
+            j.stats_effectives.intelligence
+        };
+        for j in &m.equipe_domicile.joueurs {
+            assert!((j.contexte.intelligence_subie - 0.9).abs() < 1e-6);
+            assert!(j.stats_effectives.intelligence < sans_provocation(j));
+        }
+        // Le trait ne pénalise pas ses propres coéquipiers
+        for j in &m.equipe_exterieur.joueurs {
+            assert_eq!(j.contexte.intelligence_subie, 1.0);
+        }
+    }
+}
@@ -3,9 +3,37 @@ pub mod player;
 pub mod team;
 pub mod match_engine;
 pub mod power_up;
+pub mod aleatoire;
+pub mod simulation_spatiale;
+pub mod capacites;
+pub mod regles;
+pub mod tirs_au_but;
+pub mod coups_de_pied_arretes;
+pub mod statistiques;
+pub mod entraineur_ia;
+pub mod decouvertes;
+pub mod championnat;
+pub mod coupe;
+pub mod generateur;
+pub mod transferts;
+pub mod finances;
 
 pub use scientific_domain::*;
 pub use player::*;
 pub use team::*;
 pub use match_engine::*;
 pub use power_up::*;
+pub use aleatoire::*;
+pub use simulation_spatiale::*;
+pub use capacites::*;
+pub use regles::*;
+pub use tirs_au_but::*;
+pub use coups_de_pied_arretes::*;
+pub use statistiques::*;
+pub use entraineur_ia::*;
+pub use decouvertes::*;
+pub use championnat::*;
+pub use coupe::*;
+pub use generateur::*;
+pub use transferts::*;
+pub use finances::*;
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 use crate::models::scientific_domain::ScientificDomain;
+use crate::models::power_up::ModificateursPowerUp;
+use crate::models::finances::Contrat;
 
 /// Attributs numériques d'un joueur (valeurs entre 0.0 et 100.0)
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -65,12 +67,103 @@ impl Position {
         }
     }
 
-    pub fn get_bonus_multiplicateur(&self) -> f32 {
+    /// Multiplicateur des stats selon la familiarité (0-100) avec ce poste
+    /// (un joueur de champ dans les buts est très pénalisé)
+    pub fn get_bonus_multiplicateur(&self, familiarite: f32) -> f32 {
+        let f = (familiarite / 100.0).clamp(0.0, 1.0);
         match self {
-            Position::Gardien => 1.0,
-            Position::Defenseur => 1.0,
-            Position::Milieu => 1.0,
-            Position::Attaquant => 1.0,
+            Position::Gardien => 0.5 + 0.5 * f,
+            Position::Defenseur | Position::Milieu | Position::Attaquant => 0.8 + 0.2 * f,
+        }
+    }
+
+    /// Écart entre deux postes de champ (défense → milieu → attaque)
+    fn rang_ligne(&self) -> Option<i32> {
+        match self {
+            Position::Gardien => None,
+            Position::Defenseur => Some(0),
+            Position::Milieu => Some(1),
+            Position::Attaquant => Some(2),
+        }
+    }
+}
+
+/// Familiarité d'un joueur avec chaque poste (0-100), qui progresse avec le temps de jeu
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FamiliaritePositions {
+    pub gardien: f32,
+    pub defenseur: f32,
+    pub milieu: f32,
+    pub attaquant: f32,
+}
+
+impl FamiliaritePositions {
+    /// Points gagnés par minute jouée à un poste
+    pub const GAIN_PAR_MINUTE: f32 = 0.25;
+
+    /// Familiarité initiale : parfaite au poste préféré, moindre sur les postes éloignés
+    pub fn pour_poste(preferee: Position) -> Self {
+        let valeur = |p: Position| match (preferee.rang_ligne(), p.rang_ligne()) {
+            _ if p == preferee => 100.0,
+            (_, None) => 10.0,
+            (None, Some(_)) => 40.0,
+            (Some(a), Some(b)) if (a - b).abs() == 1 => 70.0,
+            _ => 45.0,
+        };
+        Self {
+            gardien: valeur(Position::Gardien),
+            defenseur: valeur(Position::Defenseur),
+            milieu: valeur(Position::Milieu),
+            attaquant: valeur(Position::Attaquant),
+        }
+    }
+
+    pub fn get(&self, position: Position) -> f32 {
+        match position {
+            Position::Gardien => self.gardien,
+            Position::Defenseur => self.defenseur,
+            Position::Milieu => self.milieu,
+            Position::Attaquant => self.attaquant,
+        }
+    }
+
+    pub fn ajouter_minutes(&mut self, position: Position, minutes: f32) {
+        let valeur = match position {
+            Position::Gardien => &mut self.gardien,
+            Position::Defenseur => &mut self.defenseur,
+            Position::Milieu => &mut self.milieu,
+            Position::Attaquant => &mut self.attaquant,
+        };
+        *valeur = (*valeur + minutes * Self::GAIN_PAR_MINUTE).min(100.0);
+    }
+}
+
+/// Effet mécanique d'une capacité spéciale
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum EffetCapacite {
+    /// Bonus d'attributs pour le joueur qui lance la capacité
+    BonusJoueur { modificateurs: ModificateursPowerUp, duree: f32 },
+    /// Bonus d'attributs pour toute l'équipe sur le terrain
+    BonusEquipe { modificateurs: ModificateursPowerUp, duree: f32 },
+    /// Le prochain tir du joueur (dans la durée) est plus puissant
+    TirPuissant { multiplicateur: f32, duree: f32 },
+    /// Qualité des occasions de l'équipe augmentée
+    EfficaciteOffensive { multiplicateur: f32, duree: f32 },
+    /// Récupération du ballon de l'équipe augmentée
+    Recuperation { multiplicateur: f32, duree: f32 },
+    /// Tous les tirs adverses sont bloqués
+    BlocageTirs { duree: f32 },
+}
+
+impl EffetCapacite {
+    pub fn get_duree(&self) -> f32 {
+        match self {
+            EffetCapacite::BonusJoueur { duree, .. }
+            | EffetCapacite::BonusEquipe { duree, .. }
+            | EffetCapacite::TirPuissant { duree, .. }
+            | EffetCapacite::EfficaciteOffensive { duree, .. }
+            | EffetCapacite::Recuperation { duree, .. }
+            | EffetCapacite::BlocageTirs { duree } => *duree,
         }
     }
 }
@@ -83,16 +176,18 @@ pub struct CapaciteSpeciale {
     pub cooldown_max: f32,  // En secondes
     pub cooldown_actuel: f32,
     pub actif: bool,
+    pub effet: EffetCapacite,
 }
 
 impl CapaciteSpeciale {
-    pub fn new(nom: &str, description: &str, cooldown: f32) -> Self {
+    pub fn new(nom: &str, description: &str, cooldown: f32, effet: EffetCapacite) -> Self {
         Self {
             nom: nom.to_string(),
             description: description.to_string(),
             cooldown_max: cooldown,
             cooldown_actuel: 0.0,
             actif: false,
+            effet,
         }
     }
 
@@ -120,14 +215,131 @@ impl CapaciteSpeciale {
     }
 }
 
+/// Moment où un effet de trait s'applique
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConditionTrait {
+    Toujours,
+    FinDeMatch,   // Dernier quart du match
+    EquipeMenee,  // L'équipe du joueur est menée au score
+}
+
+/// Effet mécanique d'un trait de personnalité (multiplicateurs, 1.0 = neutre)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum EffetTrait {
+    /// Attributs du joueur, intégrés aux stats effectives
+    Attributs(ModificateursPowerUp),
+    /// Réussite des tirs
+    Finition(f32),
+    /// Réussite des passes
+    Passes(f32),
+    /// Consommation d'endurance
+    Stamina(f32),
+    /// Probabilité de prendre un carton
+    Cartons(f32),
+    /// Chances de récupérer le ballon en duel
+    Recuperation(f32),
+    /// Amplitude des baisses de moral
+    Moral(f32),
+    /// Poste tenu comme s'il était familier (familiarité minimale)
+    PosteMaitrise(Position, f32),
+    /// Intelligence des adversaires présents sur le terrain
+    IntelligenceAdversaires(f32),
+}
+
+/// Contexte de match qui conditionne certains effets de traits
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ContexteMatch {
+    pub fin_de_match: bool,
+    pub equipe_menee: bool,
+    /// Multiplicateur d'intelligence subi à cause des traits adverses (1.0 = neutre)
+    pub intelligence_subie: f32,
+}
+
+impl Default for ContexteMatch {
+    fn default() -> Self {
+        Self { fin_de_match: false, equipe_menee: false, intelligence_subie: 1.0 }
+    }
+}
+
+impl ContexteMatch {
+    pub fn verifie(&self, condition: ConditionTrait) -> bool {
+        match condition {
+            ConditionTrait::Toujours => true,
+            ConditionTrait::FinDeMatch => self.fin_de_match,
+            ConditionTrait::EquipeMenee => self.equipe_menee,
+        }
+    }
+}
+
 /// Trait de personnalité du joueur (bonus spécifiques)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TraitPersonnalite {
     pub nom: String,
     pub description: String,
-    pub effet: String,
+    pub effet: String, // Texte affiché
+    pub effets: Vec<(ConditionTrait, EffetTrait)>,
+}
+
+/// Bonus d'attributs temporaire (power-up, capacité...) qui expire après sa durée
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BonusTemporaire {
+    pub source: String,
+    pub modificateurs: ModificateursPowerUp,
+    pub temps_restant: f32, // En secondes de match
+}
+
+/// Gravité d'une blessure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraviteBlessure {
+    Legere,
+    Moyenne,
+    Grave,
 }
 
+impl GraviteBlessure {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            GraviteBlessure::Legere => "Légère",
+            GraviteBlessure::Moyenne => "Moyenne",
+            GraviteBlessure::Grave => "Grave",
+        }
+    }
+
+    /// Nombre de matchs d'indisponibilité (min, max)
+    pub fn duree_matchs(&self) -> (u32, u32) {
+        match self {
+            GraviteBlessure::Legere => (1, 1),
+            GraviteBlessure::Moyenne => (2, 3),
+            GraviteBlessure::Grave => (4, 6),
+        }
+    }
+}
+
+/// Blessure en cours d'un joueur
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Blessure {
+    pub nature: String,
+    pub gravite: GraviteBlessure,
+    pub matchs_restants: u32,     // Matchs à manquer avant le retour
+    pub survenue_en_match: bool,  // Le match où elle survient ne compte pas
+}
+
+/// Risque de blessure d'un contact d'intensité normale, pour un joueur frais et robuste
+pub const RISQUE_BLESSURE_BASE: f32 = 0.001;
+
+/// Stamina récupérée par jour de repos entre deux matchs (avant endurance et domaine)
+pub const RECUPERATION_PAR_JOUR: f32 = 10.0;
+/// Part de la stamina max récupérée pendant la mi-temps
+pub const RECUPERATION_MI_TEMPS: f32 = 0.12;
+/// Stamina récupérée par seconde de match sur le banc
+pub const RECUPERATION_BANC: f32 = 0.02;
+
+/// Avertissements cumulés qui entraînent un match de suspension
+pub const CARTONS_JAUNES_AVANT_SUSPENSION: u32 = 3;
+/// Matchs de suspension après un second avertissement / un carton rouge direct
+pub const SUSPENSION_SECOND_JAUNE: u32 = 1;
+pub const SUSPENSION_ROUGE_DIRECT: u32 = 2;
+
 /// Représentation complète d'un joueur
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Joueur {
@@ -136,14 +348,17 @@ pub struct Joueur {
     pub domaine: ScientificDomain,
     pub position_preferee: Position,
     pub position_actuelle: Position,
+    pub familiarite: FamiliaritePositions,
 
     // Statistiques de base (avant bonus)
     pub stats_base: PlayerStats,
-    // Statistiques effectives (après bonus domaine + forme + moral)
+    // Statistiques effectives (après bonus domaine et malus de poste)
     pub stats_effectives: PlayerStats,
 
     pub niveau: u32,
     pub experience: u32,
+    pub age: u32,
+    pub contrat: Contrat,
 
     // État physique
     pub stamina: f32,         // Endurance actuelle (0-100)
@@ -157,17 +372,28 @@ pub struct Joueur {
     pub matchs_joues: u32,
     pub cartons_jaunes: u32,
     pub cartons_rouges: u32,
+    pub historique_notes: Vec<f32>,  // Note sur 10 de chaque match disputé
 
     // État du joueur
     pub sur_le_terrain: bool,
     pub blesse: bool,
-    pub suspendu: bool,
+    pub suspendu: bool,           // Suspendu pour le prochain match (mis à jour en fin de match)
+    pub matchs_suspension: u32,   // Matchs de suspension restant à purger
+    pub cartons_jaunes_match: u32,
+    pub expulse: bool,            // Expulsé du match en cours
+    pub blessure: Option<Blessure>,
 
     // Capacité spéciale
     pub capacite_speciale: CapaciteSpeciale,
 
     // Traits de personnalité
     pub traits: Vec<TraitPersonnalite>,
+
+    // Bonus temporaires en cours (power-ups, capacités)
+    pub bonus_temporaires: Vec<BonusTemporaire>,
+
+    // Situation de match qui active les traits conditionnels
+    pub contexte: ContexteMatch,
 }
 
 impl Joueur {
@@ -176,16 +402,19 @@ impl Joueur {
         let stamina_max = 80.0 + stats_base.endurance * 0.2;
         let capacite = Self::get_capacite_pour_domaine(&domaine);
 
-        Self {
+        let mut joueur = Self {
             id,
             prenom: prenom.to_string(),
             domaine,
             position_preferee: position,
             position_actuelle: position,
+            familiarite: FamiliaritePositions::pour_poste(position),
             stats_base,
             stats_effectives,
             niveau: 1,
             experience: 0,
+            age: 25,
+            contrat: Contrat::pour_valeur(0),
             stamina: stamina_max,
             stamina_max,
             forme: 1.0,
@@ -195,12 +424,21 @@ impl Joueur {
             matchs_joues: 0,
             cartons_jaunes: 0,
             cartons_rouges: 0,
+            historique_notes: Vec::new(),
             sur_le_terrain: false,
             blesse: false,
             suspendu: false,
+            matchs_suspension: 0,
+            cartons_jaunes_match: 0,
+            expulse: false,
+            blessure: None,
             capacite_speciale: capacite,
             traits: Vec::new(),
-        }
+            bonus_temporaires: Vec::new(),
+            contexte: ContexteMatch::default(),
+        };
+        joueur.contrat = Contrat::pour_valeur(joueur.valeur_marchande());
+        joueur
     }
 
     pub fn note_globale(&self) -> f32 {
@@ -208,8 +446,26 @@ impl Joueur {
         base * self.forme * self.moral * (self.stamina / self.stamina_max).max(0.5)
     }
 
+    /// Valeur sur le marché des transferts : niveau de jeu, progression, âge et forme du moment
+    pub fn valeur_marchande(&self) -> u32 {
+        let note = self.stats_effectives.note_globale();
+        let base = 100_000.0 * (note / 70.0).powi(5);
+        let progression = 1.0 + (self.niveau.saturating_sub(1)) as f32 * 0.05;
+        // Les jeunes valent leur potentiel, la valeur décline après 29 ans
+        let age = match self.age {
+            0..=21 => 1.3,
+            22..=25 => 1.15,
+            26..=29 => 1.0,
+            a => (1.0 - (a - 29) as f32 * 0.12).max(0.3),
+        };
+        let forme = 0.8 + 0.2 * self.forme;
+        let valeur = base * progression * age * forme;
+        // Arrondi aux 5 000 près
+        ((valeur / 5_000.0).round() as u32).max(1) * 5_000
+    }
+
     pub fn consommer_stamina(&mut self, montant: f32) {
-        self.stamina = (self.stamina - montant).max(0.0);
+        self.stamina = (self.stamina - montant * self.modificateur_stamina()).max(0.0);
         // Impact sur la forme si très fatigué
         if self.stamina < 15.0 {
             self.forme = (self.forme - 0.002).max(0.5);
@@ -220,6 +476,24 @@ impl Joueur {
         self.stamina = (self.stamina + montant).min(self.stamina_max);
     }
 
+    /// Vitesse de récupération : meilleure avec de l'endurance, et pour les biologistes-médecins
+    pub fn capacite_recuperation(&self) -> f32 {
+        (0.6 + self.stats_effectives.endurance / 100.0 * 0.6) * self.domaine.get_recovery_multiplier()
+    }
+
+    pub fn recuperer_mi_temps(&mut self) {
+        self.recuperer_stamina(self.stamina_max * RECUPERATION_MI_TEMPS * self.capacite_recuperation());
+    }
+
+    /// Repos entre deux matchs : la fatigue non récupérée se reporte sur le match suivant
+    pub fn recuperer_entre_matchs(&mut self, jours_repos: u32) {
+        self.recuperer_stamina(RECUPERATION_PAR_JOUR * jours_repos as f32 * self.capacite_recuperation());
+        // La forme entamée par l'épuisement revient avec le repos
+        if self.forme < 1.0 {
+            self.forme = (self.forme + 0.03 * jours_repos as f32).min(1.0);
+        }
+    }
+
     pub fn marquer_but(&mut self) {
         self.buts += 1;
         self.experience += 100;
@@ -227,12 +501,94 @@ impl Joueur {
         self.verifier_montee_niveau();
     }
 
+    /// Avertissement : retourne `true` si c'est le second du match (expulsion)
+    #[allow(clippy::manual_is_multiple_of)] // is_multiple_of exige Rust 1.87
+    pub fn recevoir_carton_jaune(&mut self) -> bool {
+        self.cartons_jaunes_match += 1;
+        if self.cartons_jaunes_match >= 2 {
+            self.expulser(SUSPENSION_SECOND_JAUNE);
+            return true;
+        }
+        self.cartons_jaunes += 1;
+        // Cumul d'avertissements sur la saison
+        if self.cartons_jaunes % CARTONS_JAUNES_AVANT_SUSPENSION == 0 {
+            self.matchs_suspension += 1;
+        }
+        false
+    }
+
+    /// Expulsion : le joueur quitte le terrain sans être remplacé
+    pub fn expulser(&mut self, matchs: u32) {
+        self.cartons_rouges += 1;
+        self.matchs_suspension += matchs;
+        self.expulse = true;
+        self.sur_le_terrain = false;
+    }
+
+    /// Risque de se blesser sur un contact : la fatigue l'augmente, la force le réduit
+    pub fn risque_blessure(&self, intensite: f32) -> f32 {
+        let fatigue = 1.0 - self.stamina / self.stamina_max.max(1.0);
+        let robustesse = self.stats_effectives.force / 100.0;
+        RISQUE_BLESSURE_BASE * intensite * (1.0 + 2.0 * fatigue) * (1.5 - robustesse)
+    }
+
+    pub fn blesser(&mut self, blessure: Blessure) {
+        self.blessure = Some(blessure);
+        self.blesse = true;
+    }
+
+    /// Fin de match : un suspendu a purgé un match, les nouvelles sanctions prennent effet,
+    /// les blessés se rapprochent de leur retour
+    pub fn cloturer_match(&mut self) {
+        if self.suspendu {
+            self.matchs_suspension = self.matchs_suspension.saturating_sub(1);
+        }
+        self.suspendu = self.matchs_suspension > 0;
+
+        if let Some(blessure) = &mut self.blessure {
+            if blessure.survenue_en_match {
+                blessure.survenue_en_match = false;
+            } else {
+                blessure.matchs_restants = blessure.matchs_restants.saturating_sub(1);
+            }
+            if blessure.matchs_restants == 0 {
+                self.blessure = None;
+            }
+        }
+        self.blesse = self.blessure.is_some();
+
+        if self.suspendu || self.blesse {
+            self.sur_le_terrain = false;
+        }
+        self.expulse = false;
+        self.cartons_jaunes_match = 0;
+
+        // Les bonus de match (power-ups, capacités, découvertes) ne survivent pas au coup de sifflet final
+        if !self.bonus_temporaires.is_empty() {
+            self.bonus_temporaires.clear();
+            self.recalculer_stats_effectives();
+        }
+    }
+
     pub fn faire_passe_decisive(&mut self) {
         self.passes_decisives += 1;
         self.experience += 50;
         self.verifier_montee_niveau();
     }
 
+    /// Note d'après-match : nourrit l'expérience de tous les postes et fait varier le moral
+    pub fn enregistrer_note(&mut self, note: f32, homme_du_match: bool) {
+        self.historique_notes.push(note);
+        self.experience += (note * 20.0) as u32 + if homme_du_match { 100 } else { 0 };
+        self.moral = (self.moral + (note - 6.0) * 0.03).clamp(0.5, 1.5);
+        self.verifier_montee_niveau();
+    }
+
+    pub fn note_moyenne(&self) -> Option<f32> {
+        if self.historique_notes.is_empty() { return None; }
+        Some(self.historique_notes.iter().sum::<f32>() / self.historique_notes.len() as f32)
+    }
+
     fn verifier_montee_niveau(&mut self) {
         let exp_requise = self.niveau * 1000;
         if self.experience >= exp_requise {
@@ -243,12 +599,132 @@ impl Joueur {
             self.stats_base.precision = (self.stats_base.precision + 0.3).min(95.0);
             self.stats_base.endurance = (self.stats_base.endurance + 0.3).min(95.0);
             // Recalculer les stats effectives
-            self.stats_effectives = self.stats_base.appliquer_bonus_domaine(&self.domaine);
+            self.recalculer_stats_effectives();
+        }
+    }
+
+    /// Multiplicateur lié au poste occupé (1.0 = poste parfaitement maîtrisé)
+    pub fn multiplicateur_poste(&self) -> f32 {
+        let mut familiarite = self.familiarite.get(self.position_actuelle);
+        for effet in self.effets_traits_actifs() {
+            if let EffetTrait::PosteMaitrise(poste, minimum) = effet {
+                if poste == self.position_actuelle {
+                    familiarite = familiarite.max(minimum);
+                }
+            }
+        }
+        self.position_actuelle.get_bonus_multiplicateur(familiarite)
+    }
+
+    /// Effets des traits actifs dans le contexte de match actuel
+    pub fn effets_traits_actifs(&self) -> impl Iterator<Item = EffetTrait> + '_ {
+        self.traits.iter()
+            .flat_map(|t| t.effets.iter())
+            .filter(|(condition, _)| self.contexte.verifie(*condition))
+            .map(|(_, effet)| *effet)
+    }
+
+    /// Produit des multiplicateurs d'un type d'effet de trait
+    fn produit_effets(&self, extraire: impl Fn(EffetTrait) -> Option<f32>) -> f32 {
+        self.effets_traits_actifs().filter_map(extraire).product()
+    }
+
+    pub fn modificateur_finition(&self) -> f32 {
+        self.produit_effets(|e| match e { EffetTrait::Finition(m) => Some(m), _ => None })
+    }
+
+    pub fn modificateur_passes(&self) -> f32 {
+        self.produit_effets(|e| match e { EffetTrait::Passes(m) => Some(m), _ => None })
+    }
+
+    pub fn modificateur_stamina(&self) -> f32 {
+        self.produit_effets(|e| match e { EffetTrait::Stamina(m) => Some(m), _ => None })
+    }
+
+    pub fn modificateur_cartons(&self) -> f32 {
+        self.produit_effets(|e| match e { EffetTrait::Cartons(m) => Some(m), _ => None })
+    }
+
+    pub fn modificateur_recuperation(&self) -> f32 {
+        self.produit_effets(|e| match e { EffetTrait::Recuperation(m) => Some(m), _ => None })
+    }
+
+    pub fn modificateur_moral(&self) -> f32 {
+        self.produit_effets(|e| match e { EffetTrait::Moral(m) => Some(m), _ => None })
+    }
+
+    /// Multiplicateur infligé à l'intelligence des adversaires
+    pub fn modificateur_intelligence_adversaires(&self) -> f32 {
+        self.produit_effets(|e| match e { EffetTrait::IntelligenceAdversaires(m) => Some(m), _ => None })
+    }
+
+    /// Mettre à jour la situation de match (les stats ne sont recalculées qu'en cas de changement)
+    pub fn changer_contexte(&mut self, contexte: ContexteMatch) {
+        if self.contexte != contexte {
+            self.contexte = contexte;
+            self.recalculer_stats_effectives();
+        }
+    }
+
+    /// Stats effectives = stats de base + bonus domaine, réduites hors de son poste,
+    /// puis traits de personnalité, bonus temporaires en cours et provocations adverses
+    pub fn recalculer_stats_effectives(&mut self) {
+        let stats = self.stats_base.appliquer_bonus_domaine(&self.domaine);
+        let m = self.multiplicateur_poste();
+        let stats = PlayerStats {
+            vitesse: stats.vitesse * m,
+            force: stats.force * m,
+            precision: stats.precision * m,
+            endurance: stats.endurance * m,
+            intelligence: stats.intelligence * m,
+            creativite: stats.creativite * m,
+            defense: stats.defense * m,
+            attaque: stats.attaque * m,
+            jeu_de_tete: stats.jeu_de_tete * m,
+        };
+        let stats = self.effets_traits_actifs()
+            .filter_map(|e| match e { EffetTrait::Attributs(modificateurs) => Some(modificateurs), _ => None })
+            .fold(stats, |stats, modificateurs| modificateurs.appliquer(&stats));
+        self.stats_effectives = self.bonus_temporaires.iter()
+            .fold(stats, |stats, bonus| bonus.modificateurs.appliquer(&stats));
+        self.stats_effectives.intelligence *= self.contexte.intelligence_subie;
+    }
+
+    pub fn ajouter_bonus_temporaire(&mut self, source: &str, modificateurs: ModificateursPowerUp, duree: f32) {
+        self.bonus_temporaires.push(BonusTemporaire {
+            source: source.to_string(),
+            modificateurs,
+            temps_restant: duree,
+        });
+        self.recalculer_stats_effectives();
+    }
+
+    /// Faire avancer les bonus temporaires et retirer ceux qui ont expiré
+    pub fn mise_a_jour_bonus(&mut self, delta: f32) {
+        if self.bonus_temporaires.is_empty() { return; }
+        for bonus in &mut self.bonus_temporaires {
+            bonus.temps_restant -= delta;
+        }
+        let avant = self.bonus_temporaires.len();
+        self.bonus_temporaires.retain(|b| b.temps_restant > 0.0);
+        if self.bonus_temporaires.len() != avant {
+            self.recalculer_stats_effectives();
         }
     }
 
+    pub fn changer_position(&mut self, position: Position) {
+        self.position_actuelle = position;
+        self.recalculer_stats_effectives();
+    }
+
+    /// Le temps passé sur le terrain rend le joueur plus à l'aise à son poste actuel
+    pub fn jouer_minutes(&mut self, minutes: f32) {
+        self.familiarite.ajouter_minutes(self.position_actuelle, minutes);
+        self.recalculer_stats_effectives();
+    }
+
     pub fn est_disponible(&self) -> bool {
-        !self.blesse && !self.suspendu
+        !self.blesse && !self.suspendu && !self.expulse
     }
 
     fn get_capacite_pour_domaine(domaine: &ScientificDomain) -> CapaciteSpeciale {
@@ -257,66 +733,86 @@ impl Joueur {
                 "Anticipation Algorithmique",
                 "Prédit les mouvements adverses grâce aux algorithmes. +40% intelligence pendant 20s.",
                 40.0,
+                EffetCapacite::BonusJoueur { modificateurs: ModificateursPowerUp { intelligence: 1.4, ..Default::default() }, duree: 20.0 },
             ),
             ScientificDomain::PhysiqueMecanique => CapaciteSpeciale::new(
                 "Tir Balistique",
                 "Calcule la trajectoire parfaite pour un tir imparable. Puissance x2.",
                 35.0,
+                EffetCapacite::TirPuissant { multiplicateur: 2.0, duree: 30.0 },
             ),
             ScientificDomain::BiologieChimie => CapaciteSpeciale::new(
                 "Dribble Cellulaire",
                 "Réactions chimiques ultra-rapides permettant des dribbles fulgurants.",
                 25.0,
+                EffetCapacite::BonusJoueur { modificateurs: ModificateursPowerUp { vitesse: 1.3, creativite: 1.5, ..Default::default() }, duree: 10.0 },
             ),
             ScientificDomain::PhysiqueChimie => CapaciteSpeciale::new(
                 "Équilibre Parfait",
                 "Synthèse physico-chimique : tous les attributs boostés de 20% pendant 15s.",
                 45.0,
+                EffetCapacite::BonusJoueur {
+                    modificateurs: ModificateursPowerUp {
+                        vitesse: 1.2, force: 1.2, precision: 1.2, endurance: 1.2,
+                        intelligence: 1.2, creativite: 1.2, defense: 1.2,
+                        attaque: 1.2, jeu_de_tete: 1.2,
+                    },
+                    duree: 15.0,
+                },
             ),
             ScientificDomain::Mathematiques => CapaciteSpeciale::new(
                 "Géométrie du Jeu",
                 "Calcule l'angle parfait pour la passe ou le tir. Précision maximale pendant 25s.",
                 35.0,
+                EffetCapacite::BonusJoueur { modificateurs: ModificateursPowerUp { precision: 2.0, ..Default::default() }, duree: 25.0 },
             ),
             ScientificDomain::Electronique => CapaciteSpeciale::new(
                 "Circuit Intégré",
                 "Réactivité électronique : vitesse et coordination maximales pendant 20s.",
                 30.0,
+                EffetCapacite::BonusJoueur { modificateurs: ModificateursPowerUp { vitesse: 1.5, intelligence: 1.3, ..Default::default() }, duree: 20.0 },
             ),
             ScientificDomain::BiologieMedecine => CapaciteSpeciale::new(
                 "Adrénali-Shot",
                 "Injection d'adrénaline : force et endurance x1.8 pendant 15s.",
                 40.0,
+                EffetCapacite::BonusJoueur { modificateurs: ModificateursPowerUp { force: 1.8, endurance: 1.8, ..Default::default() }, duree: 15.0 },
             ),
             ScientificDomain::Chimie => CapaciteSpeciale::new(
                 "Catalyseur",
                 "Accélère les réactions de l'équipe. Tout l'équipe +15% vitesse pendant 30s.",
                 50.0,
+                EffetCapacite::BonusEquipe { modificateurs: ModificateursPowerUp { vitesse: 1.15, ..Default::default() }, duree: 30.0 },
             ),
             ScientificDomain::MathematiquesBancaire => CapaciteSpeciale::new(
                 "ROI Optimal",
                 "Calcul du retour sur investissement tactique. +30% efficacité offensive.",
-                40.0,
+                90.0,
+                EffetCapacite::EfficaciteOffensive { multiplicateur: 1.3, duree: 20.0 },
             ),
             ScientificDomain::AidesSubventions => CapaciteSpeciale::new(
                 "Volée Flamboyante",
                 "Reprise de volée spectaculaire, inattendue et imparable.",
                 30.0,
+                EffetCapacite::TirPuissant { multiplicateur: 1.8, duree: 30.0 },
             ),
             ScientificDomain::Cyberscurite => CapaciteSpeciale::new(
                 "Pare-feu Défensif",
                 "Défense impénétrable : toutes les attaques adverses bloquées pendant 20s.",
-                45.0,
+                150.0,
+                EffetCapacite::BlocageTirs { duree: 20.0 },
             ),
             ScientificDomain::ElectroniqueBancaire => CapaciteSpeciale::new(
                 "Sprint Overclocked",
                 "Overdrive électronique : vitesse x2.5 pendant 10s.",
                 35.0,
+                EffetCapacite::BonusJoueur { modificateurs: ModificateursPowerUp { vitesse: 2.5, ..Default::default() }, duree: 10.0 },
             ),
             ScientificDomain::AgroalimentaireGeologie => CapaciteSpeciale::new(
                 "Pressing Tellurique",
                 "Récupération de balle intensifiée, force de la nature. +50% récupération.",
                 30.0,
+                EffetCapacite::Recuperation { multiplicateur: 1.5, duree: 20.0 },
             ),
         }
     }
@@ -324,7 +820,7 @@ impl Joueur {
 
 /// Créer tous les joueurs de l'équipe avec leurs vraies caractéristiques
 pub fn creer_joueurs_reels() -> Vec<Joueur> {
-    vec![
+    let mut joueurs = vec![
         // Roland - Informatique, costaud, jeu physique, erreurs devant le but
         {
             let mut j = Joueur::new(
@@ -335,11 +831,17 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Jeu Physique".to_string(),
                 description: "Utilise sa stature pour dominer physiquement".to_string(),
                 effet: "Force +10%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { force: 1.1, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Frappe Approximative".to_string(),
                 description: "Peut rater des occasions devant le but".to_string(),
                 effet: "Précision tir -15%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Finition(0.85)),
+                ],
             });
             j
         },
@@ -354,11 +856,17 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Finisseur Net".to_string(),
                 description: "Excellente précision devant le gardien".to_string(),
                 effet: "Précision tir +20%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Finition(1.2)),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Droitier Dominant".to_string(),
                 description: "Pied droit exceptionnel".to_string(),
-                effet: "Tirs droite +15%".to_string(),
+                effet: "Précision tir +7,5%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Finition(1.075)),
+                ],
             });
             j
         },
@@ -373,11 +881,17 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Ailier Virtuose".to_string(),
                 description: "Maître du dribble et des petits ponts".to_string(),
                 effet: "Dribble +25%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { creativite: 1.25, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Cardio Limité".to_string(),
                 description: "S'essouffle rapidement après les efforts".to_string(),
                 effet: "Stamina -20%".to_string(),
+                effets: vec![
+                    (ConditionTrait::FinDeMatch, EffetTrait::Attributs(ModificateursPowerUp { vitesse: 0.9, ..Default::default() })),
+                ],
             });
             j.stamina_max *= 0.8;
             j.stamina = j.stamina_max;
@@ -394,6 +908,9 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Joueur Complet".to_string(),
                 description: "Excelle aussi bien en défense qu'en attaque".to_string(),
                 effet: "Équilibre +10%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { defense: 1.1, attaque: 1.1, ..Default::default() })),
+                ],
             });
             j
         },
@@ -408,16 +925,25 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Très Grand".to_string(),
                 description: "Stature imposante, domine les airs".to_string(),
                 effet: "Jeu de tête +20%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { jeu_de_tete: 1.2, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Présence Défensive".to_string(),
                 description: "Gêne physiquement les adversaires".to_string(),
                 effet: "Défense +15%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { defense: 1.15, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Frappe Imprécise".to_string(),
                 description: "Difficultés à viser juste".to_string(),
                 effet: "Précision tir -20%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Finition(0.8)),
+                ],
             });
             j
         },
@@ -432,11 +958,17 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Très Grand".to_string(),
                 description: "Grande stature, jeu de tête impressionnant".to_string(),
                 effet: "Jeu de tête +15%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { jeu_de_tete: 1.15, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Réactivité Électronique".to_string(),
                 description: "Réactions ultrarapides comme un circuit".to_string(),
                 effet: "Vitesse réaction +10%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { intelligence: 1.1, ..Default::default() })),
+                ],
             });
             j
         },
@@ -451,11 +983,18 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Runner Infatigable".to_string(),
                 description: "Court sans cesse, couvre tout le terrain".to_string(),
                 effet: "Vitesse +10%, Endurance +15%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { vitesse: 1.1, endurance: 1.15, ..Default::default() })),
+                    (ConditionTrait::Toujours, EffetTrait::Stamina(0.85)),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Charmeur d'Arbitre".to_string(),
                 description: "Tellement sympa que l'arbitre lui pardonne tout".to_string(),
                 effet: "Chances carton jaune -30%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Cartons(0.3)),
+                ],
             });
             j
         },
@@ -470,11 +1009,18 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Frappe Médicale".to_string(),
                 description: "Puissance et précision anatomiquement calculées".to_string(),
                 effet: "Puissance tir +20%, Précision +10%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Finition(1.2)),
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { precision: 1.1, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Manque de Vitesse".to_string(),
                 description: "La puissance prime sur la vitesse".to_string(),
                 effet: "Vitesse -15%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { vitesse: 0.85, ..Default::default() })),
+                ],
             });
             j
         },
@@ -489,11 +1035,17 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Provocateur Dosé".to_string(),
                 description: "Déstabilise les adversaires avec des provocations calculées".to_string(),
                 effet: "Intelligence adversaire -10%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::IntelligenceAdversaires(0.9)),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Appel en Profondeur".to_string(),
                 description: "Crée des espaces et exploite les ailes".to_string(),
                 effet: "Placement offensif +15%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { attaque: 1.15, ..Default::default() })),
+                ],
             });
             j
         },
@@ -508,11 +1060,17 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Passeur Distribué".to_string(),
                 description: "Expert en systèmes distribués de passes de qualité".to_string(),
                 effet: "Précision passes +20%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Passes(1.2)),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Défenseur Fiable".to_string(),
                 description: "Efficace en défense, évite les remontées risquées".to_string(),
                 effet: "Défense +15%, Retours défensifs -10%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { defense: 1.15, attaque: 0.9, ..Default::default() })),
+                ],
             });
             j
         },
@@ -527,11 +1085,18 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Créatif Stratégique".to_string(),
                 description: "Solutions inattendues et gestion optimale des ressources".to_string(),
                 effet: "Créativité +15%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { creativite: 1.15, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Léger Physiquement".to_string(),
                 description: "Difficultés à s'imposer face aux défenseurs costauds".to_string(),
                 effet: "Force -15% face défenseurs puissants".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { force: 0.85, ..Default::default() })),
+                    (ConditionTrait::Toujours, EffetTrait::Recuperation(0.9)),
+                ],
             });
             j
         },
@@ -546,11 +1111,17 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Joueur Flamboyant".to_string(),
                 description: "Spectaculaire et imprévisible, l'artiste du terrain".to_string(),
                 effet: "Reprises de volée +30%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Finition(1.1)),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Présence en Défense".to_string(),
                 description: "Gêne efficacement les adversaires même en défense".to_string(),
                 effet: "Défense -20% vs vitesse".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { defense: 0.9, ..Default::default() })),
+                ],
             });
             j
         },
@@ -565,16 +1136,25 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Mur Défensif".to_string(),
                 description: "Défenseur d'élite, tacle net et sûr".to_string(),
                 effet: "Défense +25%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { defense: 1.25, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Attaque Limitée".to_string(),
                 description: "Ne monte pas facilement en attaque".to_string(),
                 effet: "Attaque -20%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { attaque: 0.8, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Gardien de Sécurité".to_string(),
                 description: "Aussi efficace entre les poteaux".to_string(),
                 effet: "Peut jouer Gardien avec bonus".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::PosteMaitrise(Position::Gardien, 85.0)),
+                ],
             });
             j
         },
@@ -589,16 +1169,26 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Explosivité Pure".to_string(),
                 description: "Sprints foudroyants digne d'un court-circuit".to_string(),
                 effet: "Vitesse sprint +30%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { vitesse: 1.15, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Mental Fragile".to_string(),
                 description: "Peut perdre ses moyens sous pression".to_string(),
                 effet: "Moral peut baisser rapidement".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Moral(1.5)),
+                    (ConditionTrait::EquipeMenee, EffetTrait::Attributs(ModificateursPowerUp { precision: 0.9, intelligence: 0.9, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Frappe Imprécise".to_string(),
                 description: "Puissant mais imprecis sur les grosses frappes".to_string(),
                 effet: "Précision tir puissant -20%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Finition(0.85)),
+                ],
             });
             j
         },
@@ -613,20 +1203,35 @@ pub fn creer_joueurs_reels() -> Vec<Joueur> {
                 nom: "Machine à Courir".to_string(),
                 description: "Court sans relâche, couvre le terrain à la manière d'un tracteur".to_string(),
                 effet: "Endurance +15%, Récupération de balle +20%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { endurance: 1.15, ..Default::default() })),
+                    (ConditionTrait::Toujours, EffetTrait::Recuperation(1.2)),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Vision Limitée".to_string(),
                 description: "Manque de vision du jeu, perd parfois le fil".to_string(),
                 effet: "Intelligence de jeu -15%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Attributs(ModificateursPowerUp { intelligence: 0.85, ..Default::default() })),
+                ],
             });
             j.traits.push(TraitPersonnalite {
                 nom: "Imprécis Devant le But".to_string(),
                 description: "La finition n'est pas son fort".to_string(),
                 effet: "Précision tir -15%".to_string(),
+                effets: vec![
+                    (ConditionTrait::Toujours, EffetTrait::Finition(0.85)),
+                ],
             });
             j
         },
-    ]
+    ];
+    // Intégrer les traits aux stats effectives
+    for j in &mut joueurs {
+        j.recalculer_stats_effectives();
+    }
+    joueurs
 }
 
 #[cfg(test)]
@@ -678,4 +1283,141 @@ mod tests {
         let attaque = loic.stats_effectives.attaque;
         assert!(attaque > 80.0, "Loïc doit être un bon finisseur: {}", attaque);
     }
+
+    #[test]
+    fn test_malus_hors_poste() {
+        let joueurs = creer_joueurs_reels();
+        let mut joueur = joueurs.iter().find(|j| j.position_preferee == Position::Attaquant).unwrap().clone();
+        let attaque_a_son_poste = joueur.stats_effectives.attaque;
+
+        joueur.changer_position(Position::Milieu);
+        let attaque_milieu = joueur.stats_effectives.attaque;
+        assert!(attaque_milieu < attaque_a_son_poste);
+
+        // Un attaquant dans les buts est bien plus pénalisé qu'au milieu
+        joueur.changer_position(Position::Gardien);
+        assert!(joueur.stats_effectives.attaque < attaque_milieu * 0.8);
+
+        // Le temps de jeu dans les buts réduit le malus
+        let avant = joueur.stats_effectives.defense;
+        joueur.jouer_minutes(100.0);
+        assert!(joueur.stats_effectives.defense > avant);
+        assert!(joueur.familiarite.gardien <= 100.0);
+    }
+
+    #[test]
+    fn test_traits_effets_mecaniques() {
+        let joueurs = creer_joueurs_reels();
+        let trouver = |prenom: &str| joueurs.iter().find(|j| j.prenom == prenom).unwrap().clone();
+
+        // Roland rate plus souvent ses tirs
+        assert!(trouver("Roland").modificateur_finition() < 1.0);
+
+        // David s'épuise plus vite et ralentit en fin de match
+        let mut david = trouver("David");
+        assert!(david.stamina_max < 80.0 + david.stats_base.endurance * 0.2);
+        let vitesse = david.stats_effectives.vitesse;
+        david.changer_contexte(ContexteMatch { fin_de_match: true, ..Default::default() });
+        assert!(david.stats_effectives.vitesse < vitesse);
+
+        // Médéric perd ses moyens quand son équipe est menée
+        let mut mederic = trouver("Médéric");
+        let precision = mederic.stats_effectives.precision;
+        mederic.changer_contexte(ContexteMatch { equipe_menee: true, ..Default::default() });
+        assert!(mederic.stats_effectives.precision < precision);
+        mederic.changer_contexte(ContexteMatch::default());
+        assert_eq!(mederic.stats_effectives.precision, precision);
+
+        // Djilani tient les buts bien mieux qu'un défenseur ordinaire
+        let mut djilani = trouver("Djilani");
+        let mut sans_trait = djilani.clone();
+        sans_trait.traits.clear();
+        djilani.changer_position(Position::Gardien);
+        sans_trait.changer_position(Position::Gardien);
+        assert!(djilani.multiplicateur_poste() > sans_trait.multiplicateur_poste());
+    }
+
+    #[test]
+    fn test_second_jaune_et_cumul_d_avertissements() {
+        let mut joueur = creer_joueurs_reels().remove(0);
+        joueur.sur_le_terrain = true;
+
+        assert!(!joueur.recevoir_carton_jaune());
+        assert!(joueur.recevoir_carton_jaune());
+        assert!(joueur.expulse && !joueur.sur_le_terrain && !joueur.est_disponible());
+        assert_eq!(joueur.cartons_rouges, 1);
+
+        // Le match de l'expulsion ne compte pas : un match à purger
+        joueur.cloturer_match();
+        assert!(joueur.suspendu && !joueur.expulse);
+        joueur.cloturer_match();
+        assert!(!joueur.suspendu);
+
+        // Le cumul d'avertissements sur plusieurs matchs entraîne aussi une suspension
+        for _ in 0..CARTONS_JAUNES_AVANT_SUSPENSION - joueur.cartons_jaunes {
+            assert!(!joueur.recevoir_carton_jaune());
+            joueur.cloturer_match();
+        }
+        assert!(joueur.suspendu);
+    }
+
+    #[test]
+    fn test_blessure_risque_et_guerison() {
+        let joueurs = creer_joueurs_reels();
+        let mut joueur = joueurs[0].clone();
+        let risque_frais = joueur.risque_blessure(1.0);
+        joueur.consommer_stamina(60.0);
+        assert!(joueur.risque_blessure(1.0) > risque_frais);
+        assert!(joueur.risque_blessure(1.5) > joueur.risque_blessure(1.0));
+        let costaud = joueurs.iter().max_by(|a, b| a.stats_effectives.force.total_cmp(&b.stats_effectives.force)).unwrap();
+        let frele = joueurs.iter().min_by(|a, b| a.stats_effectives.force.total_cmp(&b.stats_effectives.force)).unwrap();
+        assert!(costaud.risque_blessure(1.0) < frele.risque_blessure(1.0));
+
+        joueur.sur_le_terrain = true;
+        joueur.blesser(Blessure {
+            nature: "Entorse".to_string(),
+            gravite: GraviteBlessure::Moyenne,
+            matchs_restants: 2,
+            survenue_en_match: true,
+        });
+        assert!(!joueur.est_disponible());
+
+        // Le match de la blessure, puis deux matchs manqués
+        joueur.cloturer_match();
+        assert!(joueur.blesse && !joueur.sur_le_terrain);
+        joueur.cloturer_match();
+        assert_eq!(joueur.blessure.as_ref().map(|b| b.matchs_restants), Some(1));
+        joueur.cloturer_match();
+        assert!(joueur.est_disponible() && joueur.blessure.is_none());
+    }
+
+    #[test]
+    fn test_recuperation_entre_matchs() {
+        // Même joueur (Franck), seul le domaine diffère
+        let mut medecin = creer_joueurs_reels().into_iter().find(|j| j.domaine == ScientificDomain::BiologieMedecine).unwrap();
+        let mut informaticien = medecin.clone();
+        informaticien.domaine = ScientificDomain::Informatique;
+        medecin.stats_effectives = medecin.stats_base;
+        informaticien.stats_effectives = informaticien.stats_base;
+        for j in [&mut medecin, &mut informaticien] {
+            j.stamina = 20.0;
+            j.recuperer_entre_matchs(2);
+        }
+        assert!(medecin.stamina > informaticien.stamina);
+
+        // La fatigue d'un match se reporte sur le suivant si le repos est court
+        let mut joueur = informaticien.clone();
+        joueur.stamina = joueur.stamina_max - 55.0;
+        joueur.recuperer_entre_matchs(2);
+        assert!(joueur.stamina < joueur.stamina_max);
+        let apres_court_repos = joueur.stamina;
+        joueur.recuperer_entre_matchs(5);
+        assert!(joueur.stamina > apres_court_repos);
+
+        // Plus d'endurance, meilleure récupération
+        let mut endurant = informaticien.clone();
+        endurant.stats_base.endurance = 95.0;
+        endurant.recalculer_stats_effectives();
+        assert!(endurant.capacite_recuperation() > informaticien.capacite_recuperation());
+    }
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::models::player::PlayerStats;
 
 /// Types de power-ups scientifiques disponibles
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -121,6 +122,7 @@ impl TypePowerUp {
             TypePowerUp::TheorieDuTout => ModificateursPowerUp {
                 vitesse: 2.0, force: 2.0, precision: 2.0,
                 endurance: 2.0, intelligence: 2.0, creativite: 2.0, defense: 2.0,
+                attaque: 2.0, jeu_de_tete: 2.0,
             },
             TypePowerUp::CircuitIntegre => ModificateursPowerUp { vitesse: 1.8, intelligence: 1.5, ..Default::default() },
             TypePowerUp::PareFeuDefensif => ModificateursPowerUp { defense: 5.0, ..Default::default() },
@@ -131,8 +133,11 @@ impl TypePowerUp {
     }
 
     pub fn generer_aleatoire() -> TypePowerUp {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+        Self::tirer(&mut rand::thread_rng())
+    }
+
+    /// Tirage selon la rareté avec un générateur fourni (reproductible à graine fixe)
+    pub fn tirer<R: rand::Rng + ?Sized>(rng: &mut R) -> TypePowerUp {
         let roll: f32 = rng.gen();
 
         // Distribution par rareté
@@ -193,7 +198,7 @@ impl Rarete {
 }
 
 /// Modificateurs appliqués par un power-up
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct ModificateursPowerUp {
     pub vitesse: f32,
     pub force: f32,
@@ -202,6 +207,8 @@ pub struct ModificateursPowerUp {
     pub intelligence: f32,
     pub creativite: f32,
     pub defense: f32,
+    pub attaque: f32,
+    pub jeu_de_tete: f32,
 }
 
 impl Default for ModificateursPowerUp {
@@ -214,6 +221,25 @@ impl Default for ModificateursPowerUp {
             intelligence: 1.0,
             creativite: 1.0,
             defense: 1.0,
+            attaque: 1.0,
+            jeu_de_tete: 1.0,
+        }
+    }
+}
+
+impl ModificateursPowerUp {
+    /// Multiplier les attributs concernés (plafonnés à 99 comme les bonus de domaine)
+    pub fn appliquer(&self, stats: &PlayerStats) -> PlayerStats {
+        PlayerStats {
+            vitesse: (stats.vitesse * self.vitesse).min(99.0),
+            force: (stats.force * self.force).min(99.0),
+            precision: (stats.precision * self.precision).min(99.0),
+            endurance: (stats.endurance * self.endurance).min(99.0),
+            intelligence: (stats.intelligence * self.intelligence).min(99.0),
+            creativite: (stats.creativite * self.creativite).min(99.0),
+            defense: (stats.defense * self.defense).min(99.0),
+            attaque: (stats.attaque * self.attaque).min(99.0),
+            jeu_de_tete: (stats.jeu_de_tete * self.jeu_de_tete).min(99.0),
         }
     }
 }
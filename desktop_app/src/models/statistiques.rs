@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+use crate::models::coups_de_pied_arretes::CoupDePiedArrete;
+use crate::models::match_engine::{EvenementMatch, MoteurMatch, ResultatTir};
+use crate::models::player::Position;
+
+/// Note de départ d'un joueur ayant disputé le match
+const NOTE_DE_BASE: f32 = 5.5;
+
+/// Statistiques d'une équipe pendant le match
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatistiquesEquipe {
+    pub temps_possession: f32,  // Secondes de jeu où l'équipe domine / a le ballon
+    pub tirs: u32,
+    pub tirs_cadres: u32,
+    pub arrets: u32,            // Arrêts de son gardien
+    pub fautes: u32,
+    pub corners: u32,
+    pub cartons_jaunes: u32,
+    pub cartons_rouges: u32,
+    pub decouvertes: u32,
+    pub power_ups: u32,
+}
+
+/// Temps de jeu, effort et actions d'un joueur
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatistiquesJoueur {
+    pub joueur_id: u32,
+    pub minutes: f32,
+    pub stamina_depensee: f32,
+    pub buts: u32,
+    pub passes_decisives: u32,
+    pub arrets: u32,
+    pub belles_actions: u32,
+    pub cartons_jaunes: u32,
+    pub expulse: bool,
+    pub note: Option<f32>,  // Sur 10, attribuée au coup de sifflet final
+}
+
+impl StatistiquesJoueur {
+    /// Note sur 10 : actions décisives, discipline, temps de jeu et résultat de l'équipe.
+    /// `part_jouee` va de 0 à 1, `resultat` vaut 1 (victoire), 0 (nul) ou -1 (défaite).
+    pub fn calculer_note(&self, poste: Position, part_jouee: f32, resultat: f32, buts_encaisses: u32) -> f32 {
+        let mut note = NOTE_DE_BASE + 0.5 * part_jouee.clamp(0.0, 1.0);
+        note += 0.8 * self.buts as f32 + 0.5 * self.passes_decisives as f32;
+        note += 0.2 * self.arrets as f32 + 0.2 * self.belles_actions as f32;
+        note -= 0.5 * self.cartons_jaunes as f32;
+        if self.expulse {
+            note -= 1.5;
+        }
+        note += 0.5 * resultat * part_jouee;
+
+        // Le travail défensif compte aussi : cage inviolée ou buts encaissés
+        if matches!(poste, Position::Gardien | Position::Defenseur) {
+            note += if buts_encaisses == 0 { 0.8 * part_jouee } else { -(0.2 * buts_encaisses as f32).min(1.0) };
+        }
+        note.clamp(1.0, 10.0)
+    }
+}
+
+/// Statistiques tenues à jour pendant le match, consultables à tout moment
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatistiquesMatch {
+    pub domicile: StatistiquesEquipe,
+    pub exterieur: StatistiquesEquipe,
+    pub joueurs: Vec<StatistiquesJoueur>,
+    pub homme_du_match: Option<u32>,
+}
+
+impl StatistiquesMatch {
+    pub fn equipe(&self, est_domicile: bool) -> &StatistiquesEquipe {
+        if est_domicile { &self.domicile } else { &self.exterieur }
+    }
+
+    fn equipe_mut(&mut self, est_domicile: bool) -> &mut StatistiquesEquipe {
+        if est_domicile { &mut self.domicile } else { &mut self.exterieur }
+    }
+
+    /// Part de possession (0 à 1) d'une équipe
+    pub fn possession(&self, est_domicile: bool) -> f32 {
+        let total = self.domicile.temps_possession + self.exterieur.temps_possession;
+        if total <= 0.0 { return 0.5; }
+        self.equipe(est_domicile).temps_possession / total
+    }
+
+    /// Répartir `delta` secondes de possession selon la domination de l'équipe à domicile (0 à 1)
+    pub fn ajouter_possession(&mut self, part_domicile: f32, delta: f32) {
+        self.domicile.temps_possession += part_domicile * delta;
+        self.exterieur.temps_possession += (1.0 - part_domicile) * delta;
+    }
+
+    pub fn joueur(&self, joueur_id: u32) -> Option<&StatistiquesJoueur> {
+        self.joueurs.iter().find(|j| j.joueur_id == joueur_id)
+    }
+
+    pub fn joueur_mut(&mut self, joueur_id: u32) -> &mut StatistiquesJoueur {
+        let idx = match self.joueurs.iter().position(|j| j.joueur_id == joueur_id) {
+            Some(idx) => idx,
+            None => {
+                self.joueurs.push(StatistiquesJoueur { joueur_id, ..Default::default() });
+                self.joueurs.len() - 1
+            }
+        };
+        &mut self.joueurs[idx]
+    }
+}
+
+impl MoteurMatch {
+    /// Ajouter un événement au fil du match en tenant les statistiques à jour
+    pub(crate) fn consigner(&mut self, evenement: EvenementMatch) {
+        let domicile_id = self.equipe_domicile.id;
+        let joue_a_domicile = |m: &MoteurMatch, joueur_id: u32| m.equipe_domicile.get_joueur(joueur_id).is_some();
+
+        match &evenement {
+            EvenementMatch::But { buteur_id, passeur_id, .. } => {
+                self.statistiques.joueur_mut(*buteur_id).buts += 1;
+                if let Some(passeur_id) = passeur_id {
+                    self.statistiques.joueur_mut(*passeur_id).passes_decisives += 1;
+                }
+            }
+            EvenementMatch::BelleAction { joueur_id, .. } => {
+                self.statistiques.joueur_mut(*joueur_id).belles_actions += 1;
+            }
+            EvenementMatch::Tir { equipe_id, resultat, .. } => {
+                let stats = self.statistiques.equipe_mut(*equipe_id == domicile_id);
+                stats.tirs += 1;
+                if matches!(resultat, ResultatTir::But | ResultatTir::Arrete) {
+                    stats.tirs_cadres += 1;
+                }
+            }
+            EvenementMatch::SauvetageGardien { gardien_id, .. } => {
+                let domicile = joue_a_domicile(self, *gardien_id);
+                self.statistiques.equipe_mut(domicile).arrets += 1;
+                self.statistiques.joueur_mut(*gardien_id).arrets += 1;
+            }
+            EvenementMatch::Faute { equipe_id, .. } => {
+                self.statistiques.equipe_mut(*equipe_id == domicile_id).fautes += 1;
+            }
+            EvenementMatch::CoupDePiedArrete { equipe_id, coup: CoupDePiedArrete::Corner, .. } => {
+                self.statistiques.equipe_mut(*equipe_id == domicile_id).corners += 1;
+            }
+            EvenementMatch::CartonJaune { joueur_id, .. } => {
+                let domicile = joue_a_domicile(self, *joueur_id);
+                self.statistiques.equipe_mut(domicile).cartons_jaunes += 1;
+                self.statistiques.joueur_mut(*joueur_id).cartons_jaunes += 1;
+            }
+            EvenementMatch::CartonRouge { equipe_id, joueur_id, .. } => {
+                self.statistiques.equipe_mut(*equipe_id == domicile_id).cartons_rouges += 1;
+                self.statistiques.joueur_mut(*joueur_id).expulse = true;
+            }
+            EvenementMatch::DecouverteScientifique { equipe_id, .. } => {
+                self.statistiques.equipe_mut(*equipe_id == domicile_id).decouvertes += 1;
+            }
+            EvenementMatch::PowerUpUtilise { joueur_id, .. } => {
+                let domicile = joue_a_domicile(self, *joueur_id);
+                self.statistiques.equipe_mut(domicile).power_ups += 1;
+            }
+            _ => {}
+        }
+        self.evenements.push(evenement);
+    }
+
+    /// Noter chaque joueur ayant participé, désigner l'homme du match et
+    /// reporter les notes dans l'historique des joueurs (moral, expérience)
+    pub(crate) fn attribuer_notes(&mut self) {
+        let vainqueur = self.get_vainqueur();
+        let duree_min = (self.temps_ecoule / 60.0).max(1.0);
+        let mut notes = Vec::new();
+
+        for (equipe, encaisses) in [(&self.equipe_domicile, self.score_exterieur), (&self.equipe_exterieur, self.score_domicile)] {
+            let resultat = match vainqueur {
+                Some(id) if id == equipe.id => 1.0,
+                Some(_) => -1.0,
+                None => 0.0,
+            };
+            for j in &equipe.joueurs {
+                let Some(stats) = self.statistiques.joueur(j.id) else { continue; };
+                if stats.minutes <= 0.0 { continue; }
+                let note = stats.calculer_note(j.position_actuelle, stats.minutes / duree_min, resultat, encaisses);
+                notes.push((j.id, note));
+            }
+        }
+
+        let homme_du_match = notes.iter()
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(id, _)| *id);
+        self.statistiques.homme_du_match = homme_du_match;
+
+        for (joueur_id, note) in notes {
+            self.statistiques.joueur_mut(joueur_id).note = Some(note);
+            let joueur = self.equipe_domicile.get_joueur_mut(joueur_id)
+                .or_else(|| self.equipe_exterieur.get_joueur_mut(joueur_id));
+            if let Some(j) = joueur {
+                j.enregistrer_note(note, homme_du_match == Some(joueur_id));
+            }
+        }
+    }
+}
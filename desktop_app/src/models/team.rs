@@ -1,7 +1,13 @@
 use serde::{Deserialize, Serialize};
+use crate::models::coups_de_pied_arretes::{CoupDePiedArrete, TireursDesignes};
+use crate::models::finances::OperationFinanciere;
 use crate::models::player::{Joueur, Position};
+use crate::models::power_up::InventairePowerUp;
 use crate::models::scientific_domain::ScientificDomain;
 
+/// Taille maximale d'un effectif
+pub const TAILLE_EFFECTIF_MAX: usize = 15;
+
 /// Formation tactique de l'équipe (5v5, donc 1 gardien + 4 joueurs de champ)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Formation {
@@ -50,10 +56,71 @@ impl Formation {
             Formation::F1111 => "Formation flexible adaptée à toutes les situations",
         }
     }
+
+    /// Propension à tirer selon le poste (1.0 = attaquant)
+    pub fn poids_tireur(&self, position: Position) -> f32 {
+        match (self, position) {
+            (_, Position::Gardien) => 0.0,
+            (_, Position::Attaquant) => 1.0,
+            (Formation::F112, Position::Milieu) => 0.5,
+            (Formation::F121, Position::Milieu) => 0.7,
+            (Formation::F1111, Position::Milieu) => 0.75,
+            (Formation::F211, Position::Milieu) => 0.8,
+            (Formation::F211, Position::Defenseur) => 0.2,
+            (Formation::F1111, Position::Defenseur) => 0.15,
+            (_, Position::Defenseur) => 0.1,
+        }
+    }
+
+    /// Exposition défensive : multiplicateur du volume et de la qualité des tirs concédés
+    pub fn couverture_defensive(&self) -> f32 {
+        match self {
+            Formation::F211 => 0.8,
+            Formation::F1111 => 0.95,
+            Formation::F121 => 1.0,
+            Formation::F112 => 1.2,
+        }
+    }
+
+    /// Avantage de cette formation face à `autre` : multiplicateur de la qualité des occasions
+    pub fn avantage_contre(&self, autre: &Formation) -> f32 {
+        match (self, autre) {
+            // Supériorité numérique au milieu face aux blocs à un seul milieu
+            (Formation::F121, Formation::F112) | (Formation::F121, Formation::F211) => 1.1,
+            // Deux attaquants face à un seul défenseur
+            (Formation::F112, Formation::F121) | (Formation::F112, Formation::F1111) => 1.1,
+            // ... mais qui butent sur deux défenseurs
+            (Formation::F112, Formation::F211) => 0.8,
+            // Le 2-1-1 relance vite dans le dos d'une équipe qui se projette
+            (Formation::F211, Formation::F112) => 1.1,
+            // Le losange brouille les repères d'un bloc à deux lignes
+            (Formation::F1111, Formation::F121) => 1.05,
+            _ => 1.0,
+        }
+    }
+
+    /// Position de référence d'un joueur (vue de l'équipe à domicile, qui attaque vers x = +50)
+    pub fn position_de_base(&self, position: Position, rang: usize, nb_meme_role: usize) -> (f32, f32) {
+        let z = (rang as f32 + 0.5) / nb_meme_role.max(1) as f32 * 30.0 - 15.0;
+        match (self, position) {
+            (_, Position::Gardien) => (-46.0, 0.0),
+            (Formation::F211, Position::Defenseur) => (-26.0, z * 0.8),
+            (Formation::F112, Position::Defenseur) => (-24.0, z),
+            (_, Position::Defenseur) => (-26.0, z),
+            // Losange : les deux milieux sont alignés dans l'axe, l'un derrière l'autre
+            (Formation::F1111, Position::Milieu) => (-14.0 + 14.0 * rang as f32, 0.0),
+            (Formation::F211, Position::Milieu) => (-10.0, z),
+            (Formation::F112, Position::Milieu) => (-6.0, z),
+            (_, Position::Milieu) => (-8.0, z),
+            // Deux pointes resserrées dans l'axe
+            (Formation::F112, Position::Attaquant) => (12.0, z * 0.5),
+            (_, Position::Attaquant) => (12.0, z),
+        }
+    }
 }
 
 /// Instructions tactiques de l'équipe
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InstructionsTactiques {
     pub intensite: f32,       // 0.5 à 1.5 - Intensité du pressing
     pub pressing_haut: bool,  // Pressing haut de terrain
@@ -76,6 +143,85 @@ impl Default for InstructionsTactiques {
     }
 }
 
+/// Effets chiffrés des instructions d'une équipe, compte tenu de celles de l'adversaire
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModificateursTactiques {
+    pub recuperation: f32,          // Multiplicateur des chances de récupérer le ballon
+    pub hauteur_bloc: f32,          // Avancée du bloc en mètres (négatif = bloc bas)
+    pub precision_passes: f32,      // Bonus de réussite des passes
+    pub portee_tir: f32,            // Distance maximale de tir en mètres
+    pub envie_tir: f32,             // Multiplicateur de la propension à tirer
+    pub qualite_tir: f32,           // Multiplicateur de la qualité des occasions
+    pub bonus_contre: f32,          // Qualité en plus juste après une récupération
+    pub consommation_stamina: f32,  // Multiplicateur de la fatigue
+    pub exposition: f32,            // Multiplicateur de la qualité des tirs concédés
+    pub pressing_haut: bool,        // Presser dès la perte du ballon, même loin de son but
+}
+
+impl InstructionsTactiques {
+    /// Traduire les consignes en modificateurs de simulation face à `adversaire`
+    pub fn modificateurs(&self, adversaire: &InstructionsTactiques) -> ModificateursTactiques {
+        let mut m = ModificateursTactiques {
+            recuperation: self.intensite,
+            hauteur_bloc: 0.0,
+            precision_passes: 0.0,
+            portee_tir: 22.0,
+            envie_tir: 1.0,
+            qualite_tir: 1.0,
+            bonus_contre: 0.0,
+            consommation_stamina: self.intensite,
+            exposition: 1.0,
+            pressing_haut: self.pressing_haut,
+        };
+
+        if self.pressing_haut {
+            m.recuperation *= 1.15;
+            m.hauteur_bloc += 0.75;
+            m.consommation_stamina *= 1.25;
+            m.exposition *= 1.15;
+            // La relance courte adverse est la cible idéale du pressing
+            if adversaire.possession {
+                m.recuperation *= 1.1;
+            }
+        }
+        if self.contre_attaque {
+            m.hauteur_bloc -= 0.5;
+            m.bonus_contre += 0.3;
+            m.exposition *= 0.75;
+            // Une ligne haute adverse laisse de l'espace dans son dos
+            if adversaire.ligne_haute {
+                m.bonus_contre += 0.2;
+            }
+        }
+        if self.possession {
+            m.precision_passes += 0.06;
+            m.envie_tir *= 0.85;
+            m.qualite_tir *= 1.1;
+            m.consommation_stamina *= 0.9;
+            if adversaire.pressing_haut {
+                m.precision_passes -= 0.04;
+            }
+        }
+        if self.ligne_haute {
+            m.hauteur_bloc += 0.5;
+            m.recuperation *= 1.05;
+            m.consommation_stamina *= 1.1;
+            m.exposition *= if adversaire.contre_attaque { 1.35 } else { 1.15 };
+        }
+        if self.tirs_a_distance {
+            m.portee_tir += 8.0;
+            m.envie_tir *= 1.4;
+        }
+        // Courir après le ballon fatigue, sauf pour un bloc bas qui attend son heure
+        if adversaire.possession && !self.possession && !self.contre_attaque {
+            m.consommation_stamina *= 1.1;
+            m.recuperation *= 0.9;
+        }
+
+        m
+    }
+}
+
 /// Équipe de football
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Equipe {
@@ -84,8 +230,9 @@ pub struct Equipe {
     pub joueurs: Vec<Joueur>,
     pub formation: Formation,
     pub instructions: InstructionsTactiques,
-    pub budget: u32,
+    pub budget: i64,  // Négatif = club dans le rouge
     pub reputation: f32,
+    pub finances: Vec<OperationFinanciere>,  // Grand livre du club
 
     // Statistiques de saison
     pub victoires: u32,
@@ -96,6 +243,12 @@ pub struct Equipe {
 
     // Chimie d'équipe
     pub chimie: f32,  // 0.0 à 1.0
+
+    // Power-ups emportés en match
+    pub inventaire_power_ups: InventairePowerUp,
+
+    // Tireurs de coups de pied arrêtés
+    pub tireurs: TireursDesignes,
 }
 
 impl Equipe {
@@ -108,18 +261,25 @@ impl Equipe {
             instructions: InstructionsTactiques::default(),
             budget: 100_000,
             reputation: 0.5,
+            finances: Vec::new(),
             victoires: 0,
             nuls: 0,
             defaites: 0,
             buts_marques: 0,
             buts_encaisses: 0,
             chimie: 0.5,
+            inventaire_power_ups: InventairePowerUp::new(3),
+            tireurs: TireursDesignes::default(),
         }
     }
 
     pub fn ajouter_joueur(&mut self, joueur: Joueur) -> Result<(), String> {
-        if self.joueurs.len() >= 15 {
-            return Err("L'effectif est au complet (15 joueurs max)".to_string());
+        if self.joueurs.len() >= TAILLE_EFFECTIF_MAX {
+            return Err(format!("L'effectif est au complet ({} joueurs max)", TAILLE_EFFECTIF_MAX));
+        }
+        // Le moteur de match repère les joueurs par leur seul id
+        if self.joueurs.iter().any(|j| j.id == joueur.id) {
+            return Err(format!("Un joueur d'id {} fait déjà partie de l'effectif", joueur.id));
         }
         self.joueurs.push(joueur);
         self.recalculer_chimie();
@@ -144,6 +304,37 @@ impl Equipe {
         self.joueurs.iter().filter(|j| !j.sur_le_terrain && j.est_disponible()).collect()
     }
 
+    /// Remplaçant disponible le plus performant au poste donné (hors `exclus`)
+    pub fn meilleur_remplacant(&self, poste: Position, exclus: &[u32]) -> Option<u32> {
+        let score = |j: &Joueur| j.note_globale() * poste.get_bonus_multiplicateur(j.familiarite.get(poste));
+        self.get_remplacants().into_iter()
+            .filter(|j| !exclus.contains(&j.id))
+            .max_by(|a, b| score(a).partial_cmp(&score(b)).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|j| j.id)
+    }
+
+    /// Désigner (ou retirer avec `None`) le tireur d'un type de coup de pied arrêté
+    pub fn designer_tireur(&mut self, coup: CoupDePiedArrete, joueur_id: Option<u32>) -> Result<(), String> {
+        if joueur_id.is_some_and(|id| self.get_joueur(id).is_none()) {
+            return Err("Joueur non trouvé".to_string());
+        }
+        self.tireurs.set(coup, joueur_id);
+        Ok(())
+    }
+
+    /// Tireur désigné s'il est sur le terrain, sinon le joueur de champ le plus adapté
+    pub fn choisir_tireur(&self, coup: CoupDePiedArrete) -> Option<u32> {
+        if let Some(id) = self.tireurs.get(coup) {
+            if self.get_joueur(id).is_some_and(|j| j.sur_le_terrain) {
+                return Some(id);
+            }
+        }
+        self.get_titulaires().into_iter()
+            .filter(|j| j.position_actuelle != Position::Gardien)
+            .max_by(|a, b| coup.note_tireur(a).partial_cmp(&coup.note_tireur(b)).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|j| j.id)
+    }
+
     pub fn get_joueur(&self, id: u32) -> Option<&Joueur> {
         self.joueurs.iter().find(|j| j.id == id)
     }
@@ -169,8 +360,9 @@ impl Equipe {
             return Err("Le joueur n'est pas sur le terrain".to_string());
         }
         sortant.sur_le_terrain = false;
+        let poste = sortant.position_actuelle;
 
-        // Mettre le joueur entrant sur le terrain
+        // Mettre le joueur entrant sur le terrain, au poste du sortant
         let entrant = self.joueurs.iter_mut().find(|j| j.id == entrant_id)
             .ok_or("Joueur entrant non trouvé")?;
         if entrant.sur_le_terrain {
@@ -180,6 +372,7 @@ impl Equipe {
             return Err("Le joueur n'est pas disponible (blessé/suspendu)".to_string());
         }
         entrant.sur_le_terrain = true;
+        entrant.changer_position(poste);
         Ok(())
     }
 
@@ -216,29 +409,30 @@ impl Equipe {
         // Désélectionner tout le monde et réinitialiser position_actuelle
         for j in &mut self.joueurs {
             j.sur_le_terrain = false;
-            j.position_actuelle = j.position_preferee;
+            j.changer_position(j.position_preferee);
         }
 
         let positions = self.formation.get_positions_requises();
         let mut positions_restantes = positions.clone();
 
-        // D'abord, assigner le gardien : choisir le meilleur joueur défensif
-        // (aucun joueur n'a Position::Gardien par défaut)
+        // D'abord, assigner le gardien : choisir le meilleur joueur défensif,
+        // en tenant compte de son expérience dans les buts
         if let Some(gk_slot) = positions_restantes.iter().position(|p| *p == Position::Gardien) {
             // Trouver le meilleur candidat gardien (meilleure défense + jeu de tête)
             let meilleur_gardien_idx = self.joueurs.iter()
                 .enumerate()
                 .filter(|(_, j)| j.est_disponible())
                 .max_by(|(_, a), (_, b)| {
-                    let score_a = a.stats_effectives.defense * 0.6 + a.stats_effectives.jeu_de_tete * 0.4;
-                    let score_b = b.stats_effectives.defense * 0.6 + b.stats_effectives.jeu_de_tete * 0.4;
+                    let score = |j: &Joueur| (j.stats_effectives.defense * 0.6 + j.stats_effectives.jeu_de_tete * 0.4)
+                        * Position::Gardien.get_bonus_multiplicateur(j.familiarite.gardien);
+                    let (score_a, score_b) = (score(a), score(b));
                     score_a.partial_cmp(&score_b).unwrap_or(std::cmp::Ordering::Equal)
                 })
                 .map(|(idx, _)| idx);
 
             if let Some(idx) = meilleur_gardien_idx {
                 self.joueurs[idx].sur_le_terrain = true;
-                self.joueurs[idx].position_actuelle = Position::Gardien;
+                self.joueurs[idx].changer_position(Position::Gardien);
                 positions_restantes.remove(gk_slot);
             }
         }
@@ -264,12 +458,16 @@ impl Equipe {
             {
                 let pos = positions_restantes.remove(pos_idx);
                 self.joueurs[idx].sur_le_terrain = true;
-                self.joueurs[idx].position_actuelle = pos;
-            } else if !positions_restantes.is_empty() {
-                // Prendre la première position disponible
-                let pos = positions_restantes.remove(0);
+                self.joueurs[idx].changer_position(pos);
+            } else if let Some(pos_idx) = (0..positions_restantes.len()).max_by(|&a, &b| {
+                // Sinon, le poste restant qu'il connaît le mieux
+                joueur.familiarite.get(positions_restantes[a])
+                    .partial_cmp(&joueur.familiarite.get(positions_restantes[b]))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }) {
+                let pos = positions_restantes.remove(pos_idx);
                 self.joueurs[idx].sur_le_terrain = true;
-                self.joueurs[idx].position_actuelle = pos;
+                self.joueurs[idx].changer_position(pos);
             }
 
             if positions_restantes.is_empty() { break; }
@@ -285,6 +483,13 @@ impl Equipe {
         }
     }
 
+    /// Jours de repos avant le prochain match
+    pub fn recuperer_entre_matchs(&mut self, jours_repos: u32) {
+        for j in &mut self.joueurs {
+            j.recuperer_entre_matchs(jours_repos);
+        }
+    }
+
     pub fn repos_equipe(&mut self) {
         for j in &mut self.joueurs {
             j.stamina = j.stamina_max;
@@ -299,4 +504,17 @@ impl Equipe {
     pub fn difference_buts(&self) -> i32 {
         self.buts_marques as i32 - self.buts_encaisses as i32
     }
+
+    /// Remettre à zéro les statistiques de saison
+    pub fn reinitialiser_saison(&mut self) {
+        self.victoires = 0;
+        self.nuls = 0;
+        self.defaites = 0;
+        self.buts_marques = 0;
+        self.buts_encaisses = 0;
+        // Une saison de plus pour tout l'effectif
+        for j in &mut self.joueurs {
+            j.age += 1;
+        }
+    }
 }
@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use rand::Rng;
+use crate::models::match_engine::{EvenementMatch, MoteurMatch, PeriodeMatch};
+use crate::models::player::{Joueur, Position};
+use crate::models::team::Equipe;
+
+/// Nombre de tirs par équipe avant la mort subite
+pub const TIRS_REGLEMENTAIRES: usize = 5;
+/// Délai (s de match) entre deux tirs au but
+const DELAI_ENTRE_TIRS: f32 = 3.0;
+
+/// Séance de tirs au but (score distinct du score du match)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SeanceTirsAuBut {
+    pub ordre_domicile: Vec<u32>,
+    pub ordre_exterieur: Vec<u32>,
+    pub tirs_domicile: Vec<bool>,   // Réussite de chaque tir, dans l'ordre
+    pub tirs_exterieur: Vec<bool>,
+}
+
+impl SeanceTirsAuBut {
+    pub fn score(&self) -> (u32, u32) {
+        let reussis = |tirs: &[bool]| tirs.iter().filter(|r| **r).count() as u32;
+        (reussis(&self.tirs_domicile), reussis(&self.tirs_exterieur))
+    }
+
+    /// Série réglementaire jouée (ou devenue sans suspense), puis mort subite à égalité de tirs
+    pub fn est_terminee(&self) -> bool {
+        let (nd, ne) = (self.tirs_domicile.len(), self.tirs_exterieur.len());
+        let (sd, se) = self.score();
+        if nd < TIRS_REGLEMENTAIRES || ne < TIRS_REGLEMENTAIRES {
+            // Une équipe ne peut plus rattraper l'autre
+            let restants_d = TIRS_REGLEMENTAIRES.saturating_sub(nd) as u32;
+            let restants_e = TIRS_REGLEMENTAIRES.saturating_sub(ne) as u32;
+            return sd + restants_d < se || se + restants_e < sd;
+        }
+        nd == ne && sd != se
+    }
+
+    /// L'équipe à domicile tire en premier à chaque tour
+    pub fn tour_domicile(&self) -> bool {
+        self.tirs_domicile.len() == self.tirs_exterieur.len()
+    }
+
+    /// Prochain tireur d'une équipe (l'ordre recommence une fois tout le monde passé)
+    pub fn prochain_tireur(&self, domicile: bool) -> Option<u32> {
+        let (ordre, tirs) = if domicile {
+            (&self.ordre_domicile, &self.tirs_domicile)
+        } else {
+            (&self.ordre_exterieur, &self.tirs_exterieur)
+        };
+        if ordre.is_empty() { return None; }
+        Some(ordre[tirs.len() % ordre.len()])
+    }
+}
+
+/// Ordre de passage : les tireurs choisis d'abord, puis les autres joueurs
+/// sur le terrain par précision décroissante
+pub fn ordre_tireurs(equipe: &Equipe, choisis: &[u32]) -> Vec<u32> {
+    let mut ordre: Vec<u32> = choisis.iter()
+        .copied()
+        .filter(|id| equipe.get_joueur(*id).is_some_and(|j| j.sur_le_terrain))
+        .collect();
+    let mut autres: Vec<&Joueur> = equipe.get_titulaires().into_iter()
+        .filter(|j| !ordre.contains(&j.id))
+        .collect();
+    autres.sort_by(|a, b| b.stats_effectives.precision.partial_cmp(&a.stats_effectives.precision).unwrap_or(std::cmp::Ordering::Equal));
+    ordre.extend(autres.iter().map(|j| j.id));
+    ordre
+}
+
+impl MoteurMatch {
+    /// Choisir l'ordre des tireurs d'une équipe (avant le début de la séance)
+    pub fn definir_ordre_tireurs(&mut self, equipe_id: u32, ordre: Vec<u32>) -> Result<(), String> {
+        if self.seance_tirs_au_but.is_some() {
+            return Err("La séance de tirs au but a déjà commencé".to_string());
+        }
+        let equipe = if equipe_id == self.equipe_domicile.id {
+            &self.equipe_domicile
+        } else if equipe_id == self.equipe_exterieur.id {
+            &self.equipe_exterieur
+        } else {
+            return Err("Équipe non trouvée".to_string());
+        };
+        if ordre.iter().any(|id| equipe.get_joueur(*id).is_none()) {
+            return Err("Joueur non trouvé".to_string());
+        }
+        if equipe_id == self.equipe_domicile.id {
+            self.tireurs_choisis_domicile = ordre;
+        } else {
+            self.tireurs_choisis_exterieur = ordre;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn demarrer_tirs_au_but(&mut self) {
+        self.periode = PeriodeMatch::TirsAuBut;
+        self.temps_avant_action = DELAI_ENTRE_TIRS;
+        let mut ordre_domicile = ordre_tireurs(&self.equipe_domicile, &self.tireurs_choisis_domicile);
+        let mut ordre_exterieur = ordre_tireurs(&self.equipe_exterieur, &self.tireurs_choisis_exterieur);
+        // Autant de tireurs de chaque côté : l'équipe la plus nombreuse écarte ses derniers
+        let nb_tireurs = ordre_domicile.len().min(ordre_exterieur.len());
+        ordre_domicile.truncate(nb_tireurs);
+        ordre_exterieur.truncate(nb_tireurs);
+        self.seance_tirs_au_but = Some(SeanceTirsAuBut {
+            ordre_domicile,
+            ordre_exterieur,
+            ..Default::default()
+        });
+        // Sans tireur d'un côté, la séance ne peut pas avoir lieu
+        if nb_tireurs == 0 {
+            self.terminer_match();
+        }
+    }
+
+    /// Un tir toutes les quelques secondes jusqu'au verdict
+    pub(crate) fn mise_a_jour_tirs_au_but(&mut self, delta: f32) {
+        self.temps_avant_action -= delta;
+        if self.temps_avant_action > 0.0 { return; }
+        self.temps_avant_action = DELAI_ENTRE_TIRS;
+
+        self.tirer_au_but();
+        let seance_en_cours = matches!(&self.seance_tirs_au_but, Some(s) if !s.est_terminee());
+        if !seance_en_cours {
+            self.terminer_match();
+        }
+    }
+
+    /// Tirer le prochain tir au but de la séance
+    pub(crate) fn tirer_au_but(&mut self) {
+        let Some(seance) = &self.seance_tirs_au_but else { return; };
+        let domicile = seance.tour_domicile();
+        let (equipe, gardiens) = if domicile {
+            (&self.equipe_domicile, &self.equipe_exterieur)
+        } else {
+            (&self.equipe_exterieur, &self.equipe_domicile)
+        };
+        let equipe_id = equipe.id;
+        let Some(tireur) = seance.prochain_tireur(domicile).and_then(|id| equipe.get_joueur(id)) else { return; };
+
+        // La précision et la puissance du tireur face au gardien
+        let frappe = (tireur.stats_effectives.precision * 0.7 + tireur.stats_effectives.force * 0.3) / 100.0;
+        let arret = gardiens.joueurs.iter()
+            .find(|j| j.sur_le_terrain && j.position_actuelle == Position::Gardien)
+            .map(|g| (g.stats_effectives.precision + g.stats_effectives.defense) / 200.0)
+            .unwrap_or(0.4);
+        let chance = ((0.75 + 0.4 * (frappe - arret)) * tireur.modificateur_finition()).clamp(0.4, 0.95);
+        let tireur_id = tireur.id;
+        let reussi = self.rng.gen::<f32>() < chance;
+
+        if let Some(seance) = &mut self.seance_tirs_au_but {
+            if domicile {
+                seance.tirs_domicile.push(reussi);
+            } else {
+                seance.tirs_exterieur.push(reussi);
+            }
+        }
+        self.consigner(EvenementMatch::TirAuBut {
+            minute: self.get_minute_actuelle(),
+            equipe_id,
+            tireur_id,
+            reussi,
+        });
+    }
+}
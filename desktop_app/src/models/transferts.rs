@@ -0,0 +1,348 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::models::championnat::Championnat;
+use crate::models::finances::{Contrat, NatureOperation};
+use crate::models::player::Joueur;
+use crate::models::team::{Equipe, TAILLE_EFFECTIF_MAX};
+
+/// Effectif minimal qu'un club garde avant d'accepter de vendre
+pub const TAILLE_EFFECTIF_MIN: usize = 7;
+
+/// Période de l'année où les transferts sont autorisés
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FenetreTransferts {
+    Ete,    // Avant la première journée
+    Hiver,  // À la trêve, entre les matchs aller et retour
+}
+
+impl FenetreTransferts {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            FenetreTransferts::Ete => "Mercato d'été",
+            FenetreTransferts::Hiver => "Mercato d'hiver",
+        }
+    }
+
+    /// Fenêtre ouverte au point où en est le championnat, s'il y en a une
+    pub fn en_cours(championnat: &Championnat) -> Option<Self> {
+        let joues = championnat.calendrier.iter().filter(|r| r.est_jouee()).count();
+        let total = championnat.calendrier.len();
+        if joues == 0 {
+            Some(FenetreTransferts::Ete)
+        } else if joues * 2 == total {
+            Some(FenetreTransferts::Hiver)
+        } else {
+            None
+        }
+    }
+}
+
+/// Offre d'un club pour un joueur d'un autre club
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffreTransfert {
+    pub joueur_id: u32,
+    pub vendeur_id: u32,
+    pub acheteur_id: u32,
+    pub montant: u32,
+}
+
+/// Réponse du club vendeur (ou du joueur) à une offre
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReponseOffre {
+    Acceptee,
+    Refusee(String),
+    ContreOffre(u32),
+}
+
+/// Transfert conclu, conservé dans l'historique du marché
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transfert {
+    pub saison: u32,
+    pub fenetre: FenetreTransferts,
+    pub joueur_id: u32,
+    pub nom_joueur: String,
+    pub vendeur_id: u32,
+    pub acheteur_id: u32,
+    pub montant: u32,
+}
+
+/// Prix que le vendeur demande : un titulaire se négocie plus cher, et un club
+/// réputé vend moins volontiers
+pub fn prix_demande(vendeur: &Equipe, joueur: &Joueur) -> u32 {
+    let valeur = joueur.valeur_marchande() as f32;
+    let titulaire = if joueur.sur_le_terrain { 1.3 } else { 1.0 };
+    let prestige = 1.0 + vendeur.reputation * 0.3;
+    ((valeur * titulaire * prestige / 5_000.0).round() as u32) * 5_000
+}
+
+/// Décision du club vendeur face à une offre
+pub fn evaluer_offre(vendeur: &Equipe, acheteur: &Equipe, joueur: &Joueur, montant: u32) -> ReponseOffre {
+    // Un joueur ne quitte pas un club pour un autre nettement moins prestigieux
+    if acheteur.reputation + 0.3 < vendeur.reputation {
+        return ReponseOffre::Refusee(format!("{} refuse de rejoindre un club moins prestigieux", joueur.prenom));
+    }
+    // Clause libératoire levée : le club vendeur ne peut pas s'y opposer
+    if joueur.contrat.clause_liberatoire.is_some_and(|clause| montant >= clause) {
+        return ReponseOffre::Acceptee;
+    }
+    if vendeur.joueurs.len() <= TAILLE_EFFECTIF_MIN {
+        return ReponseOffre::Refusee(format!("{} n'a plus assez de joueurs pour vendre", vendeur.nom));
+    }
+    let prix = prix_demande(vendeur, joueur);
+    if montant >= prix {
+        ReponseOffre::Acceptee
+    } else if montant as f32 >= prix as f32 * 0.75 {
+        ReponseOffre::ContreOffre(prix)
+    } else {
+        ReponseOffre::Refusee(format!("Offre jugée insuffisante ({} demandés)", prix))
+    }
+}
+
+/// Marché des transferts : négociations, mouvements d'argent et historique
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarcheTransferts {
+    pub historique: Vec<Transfert>,
+    /// Dernière fenêtre où les clubs IA ont déjà fait leur marché
+    pub derniere_session_ia: Option<(u32, FenetreTransferts)>,
+    /// Fenêtre restée ouverte depuis que le championnat l'a atteinte, jusqu'au prochain
+    /// match du club du joueur (les rencontres IA jouées d'office ne la referment pas)
+    pub fenetre_ouverte: Option<FenetreTransferts>,
+}
+
+impl MarcheTransferts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fenêtre dans laquelle on peut négocier, s'il y en a une
+    pub fn fenetre(&self, championnat: &Championnat) -> Option<FenetreTransferts> {
+        FenetreTransferts::en_cours(championnat).or(self.fenetre_ouverte)
+    }
+
+    /// À appeler après chaque résultat : une fenêtre atteinte reste ouverte jusqu'à `fermer_fenetre`
+    pub fn actualiser_fenetre(&mut self, championnat: &Championnat) {
+        if let Some(fenetre) = FenetreTransferts::en_cours(championnat) {
+            self.fenetre_ouverte = Some(fenetre);
+        }
+    }
+
+    pub fn fermer_fenetre(&mut self) {
+        self.fenetre_ouverte = None;
+    }
+
+    /// Soumettre une offre : le transfert est conclu immédiatement si elle est acceptée
+    pub fn proposer(&mut self, equipes: &mut [Equipe], championnat: &Championnat, offre: OffreTransfert) -> Result<ReponseOffre, String> {
+        let fenetre = self.fenetre(championnat)
+            .ok_or("Le marché des transferts est fermé")?;
+        if offre.vendeur_id == offre.acheteur_id {
+            return Err("Un club ne peut pas acheter son propre joueur".to_string());
+        }
+        let vendeur_idx = equipes.iter().position(|e| e.id == offre.vendeur_id).ok_or("Club vendeur introuvable")?;
+        let acheteur_idx = equipes.iter().position(|e| e.id == offre.acheteur_id).ok_or("Club acheteur introuvable")?;
+        let (vendeur, acheteur) = (&equipes[vendeur_idx], &equipes[acheteur_idx]);
+        let joueur = vendeur.get_joueur(offre.joueur_id).ok_or("Joueur introuvable dans le club vendeur")?;
+
+        if acheteur.joueurs.len() >= TAILLE_EFFECTIF_MAX {
+            return Err(format!("{} a déjà un effectif complet ({} joueurs)", acheteur.nom, TAILLE_EFFECTIF_MAX));
+        }
+        if acheteur.est_sous_embargo() {
+            return Err(format!("{} est interdit de recrutement (budget dans le rouge)", acheteur.nom));
+        }
+        if acheteur.budget < offre.montant as i64 {
+            return Err(format!("Budget insuffisant ({} disponibles)", acheteur.budget));
+        }
+
+        let reponse = evaluer_offre(vendeur, acheteur, joueur, offre.montant);
+        if reponse == ReponseOffre::Acceptee {
+            self.conclure(equipes, vendeur_idx, acheteur_idx, offre, championnat.saison, fenetre)?;
+        }
+        Ok(reponse)
+    }
+
+    /// Le joueur rejoint l'acheteur avant tout mouvement d'argent : si l'acheteur ne peut
+    /// pas l'accueillir, rien n'a changé
+    fn conclure(&mut self, equipes: &mut [Equipe], vendeur_idx: usize, acheteur_idx: usize, offre: OffreTransfert, saison: u32, fenetre: FenetreTransferts) -> Result<(), String> {
+        let mut joueur = equipes[vendeur_idx].get_joueur(offre.joueur_id)
+            .ok_or("Joueur introuvable dans le club vendeur")?
+            .clone();
+        let etait_titulaire = joueur.sur_le_terrain;
+        joueur.sur_le_terrain = false;
+        let nom_joueur = joueur.prenom.clone();
+        // Nouveau club, nouveau contrat
+        joueur.contrat = Contrat::pour_valeur(joueur.valeur_marchande());
+
+        let acheteur = &mut equipes[acheteur_idx];
+        acheteur.ajouter_joueur(joueur)?;
+        acheteur.enregistrer_operation(saison, NatureOperation::Transfert, format!("Achat de {}", nom_joueur), -(offre.montant as i64));
+        if acheteur.get_titulaires().len() < 5 {
+            acheteur.selectionner_titulaires_auto();
+        }
+
+        let vendeur = &mut equipes[vendeur_idx];
+        vendeur.retirer_joueur(offre.joueur_id);
+        vendeur.enregistrer_operation(saison, NatureOperation::Transfert, format!("Vente de {}", nom_joueur), offre.montant as i64);
+        if etait_titulaire {
+            vendeur.selectionner_titulaires_auto();
+        }
+
+        self.historique.push(Transfert {
+            saison,
+            fenetre,
+            joueur_id: offre.joueur_id,
+            nom_joueur,
+            vendeur_id: offre.vendeur_id,
+            acheteur_id: offre.acheteur_id,
+            montant: offre.montant,
+        });
+        Ok(())
+    }
+
+    /// Les clubs IA (hors `exclus`) renforcent leur titulaire le plus faible auprès des
+    /// autres clubs IA, une seule fois par fenêtre. Retourne le nombre de transferts conclus.
+    pub fn mercato_ia<R: Rng + ?Sized>(&mut self, equipes: &mut [Equipe], championnat: &Championnat, exclus: Option<u32>, rng: &mut R) -> usize {
+        let Some(fenetre) = self.fenetre(championnat) else { return 0 };
+        if self.derniere_session_ia == Some((championnat.saison, fenetre)) {
+            return 0;
+        }
+        self.derniere_session_ia = Some((championnat.saison, fenetre));
+
+        let acheteurs: Vec<u32> = equipes.iter().map(|e| e.id).filter(|id| Some(*id) != exclus).collect();
+        let mut conclus = 0;
+        for acheteur_id in acheteurs {
+            // Tous les clubs ne cherchent pas à recruter à chaque fenêtre
+            if !rng.gen_bool(0.5) { continue; }
+            let Some(acheteur) = equipes.iter().find(|e| e.id == acheteur_id) else { continue };
+            if acheteur.est_sous_embargo() { continue; }
+            let Some(maillon_faible) = acheteur.get_titulaires().into_iter()
+                .min_by(|a, b| a.stats_effectives.note_globale().total_cmp(&b.stats_effectives.note_globale()))
+            else { continue };
+            let (poste, note_a_battre) = (maillon_faible.position_actuelle, maillon_faible.stats_effectives.note_globale());
+
+            // Meilleure recrue abordable au même poste, chez un autre club IA
+            let cible = equipes.iter()
+                .filter(|e| e.id != acheteur_id && Some(e.id) != exclus)
+                .flat_map(|e| e.joueurs.iter().map(move |j| (e, j)))
+                .filter(|(_, j)| j.position_preferee == poste && j.stats_effectives.note_globale() > note_a_battre + 2.0)
+                .filter(|(e, j)| prix_demande(e, j) as i64 <= acheteur.budget)
+                .max_by(|(_, a), (_, b)| a.stats_effectives.note_globale().total_cmp(&b.stats_effectives.note_globale()))
+                .map(|(e, j)| (e.id, j.id, prix_demande(e, j)));
+            let Some((vendeur_id, joueur_id, prix)) = cible else { continue };
+
+            // Première offre un peu sous le prix, puis on s'aligne sur la contre-offre si on peut
+            let premiere = ((prix as f32 * rng.gen_range(0.8..1.05) / 5_000.0).round() as u32) * 5_000;
+            let mut offre = OffreTransfert { joueur_id, vendeur_id, acheteur_id, montant: premiere };
+            match self.proposer(equipes, championnat, offre) {
+                Ok(ReponseOffre::Acceptee) => conclus += 1,
+                Ok(ReponseOffre::ContreOffre(montant)) if rng.gen_bool(0.6) => {
+                    offre.montant = montant;
+                    if let Ok(ReponseOffre::Acceptee) = self.proposer(equipes, championnat, offre) {
+                        conclus += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+        conclus
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::aleatoire::GenerateurAleatoire;
+    use crate::models::generateur::GenerateurMonde;
+    use crate::models::player::creer_joueurs_reels;
+
+    fn deux_clubs() -> Vec<Equipe> {
+        let mut a = Equipe::new(1, "Acheteurs");
+        let mut b = Equipe::new(2, "Vendeurs");
+        for (i, j) in creer_joueurs_reels().into_iter().enumerate() {
+            let _ = if i < 7 { a.ajouter_joueur(j) } else { b.ajouter_joueur(j) };
+        }
+        a.selectionner_titulaires_auto();
+        b.selectionner_titulaires_auto();
+        a.budget = 2_000_000;
+        vec![a, b]
+    }
+
+    #[test]
+    fn test_negociation_et_mouvements_d_argent() {
+        let mut equipes = deux_clubs();
+        let mut championnat = Championnat::new(1, &equipes);
+        let mut marche = MarcheTransferts::new();
+
+        let joueur = equipes[1].joueurs.iter().find(|j| j.sur_le_terrain).unwrap().clone();
+        let prix = prix_demande(&equipes[1], &joueur);
+        assert!(prix > joueur.valeur_marchande(), "Un titulaire se vend au-dessus de sa valeur");
+        let offre = |montant| OffreTransfert { joueur_id: joueur.id, vendeur_id: 2, acheteur_id: 1, montant };
+
+        // Trop bas : refus ; proche du prix : contre-offre ; au prix : transfert conclu
+        assert!(matches!(marche.proposer(&mut equipes, &championnat, offre(prix / 2)), Ok(ReponseOffre::Refusee(_))));
+        assert_eq!(marche.proposer(&mut equipes, &championnat, offre(prix * 9 / 10)), Ok(ReponseOffre::ContreOffre(prix)));
+        assert_eq!(marche.proposer(&mut equipes, &championnat, offre(prix)), Ok(ReponseOffre::Acceptee));
+
+        assert_eq!(equipes[0].budget, 2_000_000 - prix as i64);
+        assert_eq!(equipes[1].budget, 100_000 + prix as i64);
+        assert!(equipes[0].get_joueur(joueur.id).is_some() && equipes[1].get_joueur(joueur.id).is_none());
+        assert_eq!(equipes[1].get_titulaires().len(), 5, "Le vendeur recompose son onze");
+        assert_eq!(marche.historique.len(), 1);
+
+        // Budget, effectif maximal et fenêtre fermée sont vérifiés avant de négocier
+        let autre = equipes[1].joueurs[0].id;
+        let trop_cher = OffreTransfert { joueur_id: autre, vendeur_id: 2, acheteur_id: 1, montant: 5_000_000 };
+        assert!(marche.proposer(&mut equipes, &championnat, trop_cher).is_err());
+        while equipes[0].joueurs.len() < TAILLE_EFFECTIF_MAX {
+            let j = GenerateurMonde::new(3).apres(&equipes).generer_joueur(60.0);
+            equipes[0].ajouter_joueur(j).unwrap();
+        }
+        assert!(equipes[0].ajouter_joueur(joueur.clone()).is_err());
+        assert!(marche.proposer(&mut equipes, &championnat, OffreTransfert { montant: 1_000_000, ..trop_cher }).is_err());
+
+        // Un acheteur qui ne peut pas accueillir le joueur ne paie rien et le vendeur le garde
+        let budgets: Vec<i64> = equipes.iter().map(|e| e.budget).collect();
+        let offre_acceptee = OffreTransfert { montant: 1_000_000, ..trop_cher };
+        assert!(marche.conclure(&mut equipes, 1, 0, offre_acceptee, 1, FenetreTransferts::Ete).is_err());
+        assert_eq!(equipes.iter().map(|e| e.budget).collect::<Vec<_>>(), budgets);
+        assert!(equipes[1].get_joueur(autre).is_some() && equipes[0].get_joueur(autre).is_none());
+        assert_eq!(marche.historique.len(), 1);
+
+        // À deux clubs, la trêve arrive après la première journée
+        championnat.enregistrer_resultat(1, 2, 1, 0).unwrap();
+        assert_eq!(FenetreTransferts::en_cours(&championnat), Some(FenetreTransferts::Hiver));
+        championnat.enregistrer_resultat(2, 1, 0, 0).unwrap();
+        assert_eq!(FenetreTransferts::en_cours(&championnat), None, "Saison terminée : marché fermé jusqu'à la suivante");
+        let vente = OffreTransfert { joueur_id: joueur.id, vendeur_id: 1, acheteur_id: 2, montant: 1_000 };
+        assert_eq!(marche.proposer(&mut equipes, &championnat, vente), Err("Le marché des transferts est fermé".to_string()));
+    }
+
+    #[test]
+    fn test_valeur_et_mercato_ia() {
+        let mut jeune = creer_joueurs_reels().remove(1);
+        let valeur_25_ans = jeune.valeur_marchande();
+        jeune.age = 20;
+        assert!(jeune.valeur_marchande() > valeur_25_ans);
+        jeune.age = 34;
+        assert!(jeune.valeur_marchande() < valeur_25_ans / 2);
+
+        let mut generateur = GenerateurMonde::new(11);
+        let mut equipes = generateur.generer_clubs(8, 70.0);
+        for e in &mut equipes {
+            e.budget = 3_000_000;
+            e.reputation = 0.5;
+        }
+        let championnat = Championnat::new(1, &equipes);
+        let budget_total: i64 = equipes.iter().map(|e| e.budget).sum();
+
+        let mut marche = MarcheTransferts::new();
+        let mut rng = GenerateurAleatoire::new(5);
+        let protege = equipes[0].id;
+        let avant: Vec<u32> = equipes[0].joueurs.iter().map(|j| j.id).collect();
+        let conclus = marche.mercato_ia(&mut equipes, &championnat, Some(protege), &mut rng);
+
+        assert!(conclus > 0, "Les clubs IA doivent commercer entre eux");
+        assert_eq!(marche.historique.len(), conclus);
+        assert_eq!(equipes.iter().map(|e| e.budget).sum::<i64>(), budget_total, "L'argent change seulement de main");
+        assert_eq!(equipes[0].joueurs.iter().map(|j| j.id).collect::<Vec<_>>(), avant, "Le club du joueur n'est pas touché");
+        assert_eq!(marche.mercato_ia(&mut equipes, &championnat, Some(protege), &mut rng), 0, "Une seule session par fenêtre");
+    }
+}
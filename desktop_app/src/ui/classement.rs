@@ -10,7 +10,10 @@ impl Plugin for ClassementPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(EcranJeu::Classement), afficher_classement)
            .add_systems(OnExit(EcranJeu::Classement), nettoyer_ecran::<EcranClassement>)
-           .add_systems(Update, gerer_boutons_classement.run_if(in_state(EcranJeu::Classement)));
+           .add_systems(Update, (
+               gerer_boutons_classement,
+               gerer_bouton_cloturer_saison,
+           ).run_if(in_state(EcranJeu::Classement)));
     }
 }
 
@@ -18,12 +21,17 @@ fn afficher_classement(
     mut commands: Commands,
     etat_jeu: Res<EtatJeu>,
 ) {
-    // Trier les équipes par points décroissants
-    let mut equipes_triees: Vec<_> = etat_jeu.equipes.iter().collect();
-    equipes_triees.sort_by(|a, b| {
-        b.points().cmp(&a.points())
-            .then(b.difference_buts().cmp(&a.difference_buts()))
-    });
+    construire_classement(&mut commands, &etat_jeu);
+}
+
+fn construire_classement(commands: &mut Commands, etat_jeu: &EtatJeu) {
+    // Classement du championnat, départagé selon le règlement
+    let championnat = &etat_jeu.championnat;
+    let lignes = championnat.classement();
+    let titre = match championnat.journee_en_cours() {
+        Some(journee) => format!("🏆 Classement — Saison {}, journée {}/{}", championnat.saison, journee, championnat.nb_journees()),
+        None => format!("🏆 Classement — Saison {} terminée", championnat.saison),
+    };
 
     commands.spawn((
         Node {
@@ -73,7 +81,7 @@ fn afficher_classement(
             });
 
             nav.spawn((
-                Text::new("🏆 Classement"),
+                Text::new(titre),
                 TextFont { font_size: 22.0, ..default() },
                 TextColor(COULEUR_ACCENT),
             ));
@@ -117,7 +125,7 @@ fn afficher_classement(
             });
 
             // Lignes du classement
-            for (rang, equipe) in equipes_triees.iter().enumerate() {
+            for (rang, ligne) in lignes.iter().enumerate() {
                 let couleur_rang = match rang {
                     0 => Color::srgb(1.0, 0.8, 0.0), // Or
                     1 => Color::srgb(0.7, 0.7, 0.7), // Argent
@@ -140,19 +148,18 @@ fn afficher_classement(
                 )).with_children(|row| {
                     let valeurs: Vec<(&str, f32, String)> = vec![
                         ("#", 30.0, format!("{}", rang + 1)),
-                        ("Équipe", 240.0, equipe.nom.clone()),
-                        ("J", 40.0, format!("{}", equipe.victoires + equipe.nuls + equipe.defaites)),
-                        ("V", 40.0, format!("{}", equipe.victoires)),
-                        ("N", 40.0, format!("{}", equipe.nuls)),
-                        ("D", 40.0, format!("{}", equipe.defaites)),
-                        ("BP", 50.0, format!("{}", equipe.buts_marques)),
-                        ("BC", 50.0, format!("{}", equipe.buts_encaisses)),
-                        ("+/-", 60.0, format!("{:+}", equipe.difference_buts())),
-                        ("Pts", 60.0, format!("{}", equipe.points())),
+                        ("Équipe", 240.0, ligne.nom.clone()),
+                        ("J", 40.0, format!("{}", ligne.joues())),
+                        ("V", 40.0, format!("{}", ligne.victoires)),
+                        ("N", 40.0, format!("{}", ligne.nuls)),
+                        ("D", 40.0, format!("{}", ligne.defaites)),
+                        ("BP", 50.0, format!("{}", ligne.buts_marques)),
+                        ("BC", 50.0, format!("{}", ligne.buts_encaisses)),
+                        ("+/-", 60.0, format!("{:+}", ligne.difference_buts())),
+                        ("Pts", 60.0, format!("{}", ligne.points())),
                     ];
 
                     for (_, largeur, valeur) in valeurs.iter() {
-                        let _is_pts = valeur == &format!("{}", equipe.points());
                         row.spawn((
                             Text::new(valeur),
                             TextFont { font_size: 13.0, ..default() },
@@ -163,27 +170,92 @@ fn afficher_classement(
                 });
             }
 
-            if equipes_triees.is_empty() {
+            if lignes.is_empty() {
                 content.spawn((
                     Text::new("Aucune équipe enregistrée. Initialisez les équipes depuis le menu principal."),
                     TextFont { font_size: 16.0, ..default() },
                     TextColor(COULEUR_TEXTE_SECONDAIRE),
                 ));
             }
+
+            // Fin de saison : archiver le classement et repartir pour une nouvelle saison
+            if championnat.est_terminee() {
+                content.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(280.0),
+                        height: Val::Px(44.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        margin: UiRect::top(Val::Px(16.0)),
+                        ..default()
+                    },
+                    BackgroundColor(COULEUR_BTN_NORMAL),
+                    BorderColor(COULEUR_SUCCES),
+                    BorderRadius::all(Val::Px(6.0)),
+                    BoutonCloturerSaison,
+                )).with_children(|btn| {
+                    btn.spawn((
+                        Text::new("🏁 Clôturer la saison"),
+                        TextFont { font_size: 15.0, ..default() },
+                        TextColor(COULEUR_SUCCES),
+                    ));
+                });
+            }
+
+            // Palmarès des saisons précédentes
+            for archive in championnat.archives.iter().rev() {
+                if let Some(champion) = archive.champion() {
+                    content.spawn((
+                        Text::new(format!("Saison {} : 🥇 {} ({} pts)", archive.saison, champion.nom, champion.points())),
+                        TextFont { font_size: 13.0, ..default() },
+                        TextColor(COULEUR_TEXTE_SECONDAIRE),
+                    ));
+                }
+            }
         });
     });
 }
 
+/// Boutons dont l'état d'interaction vient de changer
+type BoutonsModifies = (Changed<Interaction>, With<Button>);
+
 fn gerer_boutons_classement(
-    mut interactions: Query<(&Interaction, &mut BackgroundColor, &BoutonNavigation),
-        (Changed<Interaction>, With<Button>)>,
+    mut interactions: Query<(&Interaction, &mut BackgroundColor, Option<&BoutonNavigation>), BoutonsModifies>,
     mut prochaine_etat: ResMut<NextState<EcranJeu>>,
 ) {
     for (interaction, mut couleur, nav) in interactions.iter_mut() {
         match interaction {
-            Interaction::Pressed => prochaine_etat.set(nav.0),
+            Interaction::Pressed => {
+                if let Some(nav) = nav {
+                    prochaine_etat.set(nav.0);
+                }
+            }
             Interaction::Hovered => *couleur = BackgroundColor(COULEUR_BTN_SURVOL),
             Interaction::None => *couleur = BackgroundColor(COULEUR_BTN_NORMAL),
         }
     }
 }
+
+fn gerer_bouton_cloturer_saison(
+    mut commands: Commands,
+    interactions: Query<&Interaction, (Changed<Interaction>, With<BoutonCloturerSaison>)>,
+    ecran: Query<Entity, With<EcranClassement>>,
+    mut etat_jeu: ResMut<EtatJeu>,
+) {
+    // Le survol est géré par gerer_boutons_classement, commun à tous les boutons de l'écran
+    for interaction in interactions.iter() {
+        if *interaction != Interaction::Pressed { continue; }
+        match etat_jeu.cloturer_saison() {
+            Ok(()) => {
+                // Reconstruire l'écran avec le calendrier de la nouvelle saison
+                for entity in ecran.iter() {
+                    commands.entity(entity).despawn_recursive();
+                }
+                construire_classement(&mut commands, &etat_jeu);
+            }
+            Err(e) => warn!("Impossible de clôturer la saison: {}", e),
+        }
+    }
+}
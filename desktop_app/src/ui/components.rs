@@ -22,6 +22,12 @@ pub struct EcranClassement;
 #[derive(Component)]
 pub struct EcranFichesJoueurs;
 
+#[derive(Component)]
+pub struct EcranCoupe;
+
+#[derive(Component)]
+pub struct EcranTransferts;
+
 /// Composants de boutons
 #[derive(Component)]
 pub struct BoutonNavigation(pub crate::game_state::EcranJeu);
@@ -38,12 +44,33 @@ pub struct BoutonReprendreMatch;
 #[derive(Component)]
 pub struct BoutonMiTemps;
 
+#[derive(Component)]
+pub struct BoutonSimulerFin;
+
 #[derive(Component)]
 pub struct BoutonSubstitution {
     pub equipe_id: u32,
     pub sortant_id: u32,
 }
 
+#[derive(Component)]
+pub struct BoutonPowerUp {
+    pub equipe_id: u32,
+    pub type_power_up: crate::models::power_up::TypePowerUp,
+}
+
+#[derive(Component)]
+pub struct BoutonCapacite {
+    pub equipe_id: u32,
+    pub joueur_id: u32,
+}
+
+#[derive(Component)]
+pub struct BoutonTireurAuBut {
+    pub equipe_id: u32,
+    pub joueur_id: u32,
+}
+
 #[derive(Component)]
 pub struct BoutonAjouterJoueur {
     pub joueur_idx: usize,
@@ -70,6 +97,41 @@ pub struct BoutonChangerFormation(pub u32); // equipe_id
 #[derive(Component)]
 pub struct BoutonInitDemo;
 
+#[derive(Component)]
+pub struct BoutonGenererLigue;
+
+#[derive(Component)]
+pub struct BoutonDifficulteIA;
+
+#[derive(Component)]
+pub struct BoutonCloturerSaison;
+
+/// Actions de l'écran de la coupe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionCoupe {
+    ChangerCritere,
+    ChangerFormat,
+    Tirer,
+    JouerMatch,
+}
+
+#[derive(Component)]
+pub struct BoutonCoupe(pub ActionCoupe);
+
+/// Actions de l'écran des transferts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionTransfert {
+    ClubPrecedent,
+    ClubSuivant,
+    Offre { vendeur_id: u32, joueur_id: u32, montant: u32 },
+    AccepterContreOffre,
+    RefuserContreOffre,
+    Prolonger { joueur_id: u32, salaire: u32 },
+}
+
+#[derive(Component)]
+pub struct BoutonTransfert(pub ActionTransfert);
+
 /// Affichages dynamiques
 #[derive(Component)]
 pub struct AffichageScore;
@@ -80,6 +142,12 @@ pub struct AffichageTemps;
 #[derive(Component)]
 pub struct AffichagePeriode;
 
+#[derive(Component)]
+pub struct AffichageDifficulteIA;
+
+#[derive(Component)]
+pub struct AffichageErreurMatch;
+
 #[derive(Component)]
 pub struct AffichageEvenements;
 
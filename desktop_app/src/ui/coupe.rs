@@ -0,0 +1,284 @@
+use bevy::prelude::*;
+use crate::game_state::{EcranJeu, EtatJeu};
+use crate::models::coupe::{Confrontation, FormatConfrontation};
+use crate::ui::styles::*;
+use crate::ui::components::*;
+use crate::ui::menu_principal::nettoyer_ecran;
+
+pub struct CoupePlugin;
+
+impl Plugin for CoupePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(EcranJeu::Coupe), afficher_coupe)
+           .add_systems(OnExit(EcranJeu::Coupe), nettoyer_ecran::<EcranCoupe>)
+           .add_systems(Update, gerer_boutons_coupe.run_if(in_state(EcranJeu::Coupe)));
+    }
+}
+
+fn afficher_coupe(
+    mut commands: Commands,
+    etat_jeu: Res<EtatJeu>,
+) {
+    construire_coupe(&mut commands, &etat_jeu);
+}
+
+fn bouton(parent: &mut ChildBuilder, texte: String, action: ActionCoupe, couleur: Color) {
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(280.0),
+            height: Val::Px(40.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(COULEUR_BTN_NORMAL),
+        BorderColor(couleur),
+        BorderRadius::all(Val::Px(6.0)),
+        BoutonCoupe(action),
+    )).with_children(|btn| {
+        btn.spawn((
+            Text::new(texte),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(couleur),
+        ));
+    });
+}
+
+fn libelle_confrontation(etat_jeu: &EtatJeu, c: &Confrontation) -> String {
+    let nom = |id: Option<u32>| id
+        .and_then(|id| etat_jeu.get_equipe(id))
+        .map(|e| e.nom.clone())
+        .unwrap_or_else(|| "?".to_string());
+    if c.est_exemption() {
+        return format!("{} (exempté)", nom(c.equipe_a));
+    }
+    let score = if c.manches.is_empty() {
+        "-".to_string()
+    } else {
+        let (buts_a, buts_b) = c.cumul();
+        format!("{} - {}", buts_a, buts_b)
+    };
+    let qualifie = if c.vainqueur.is_some() { format!(" → {}", nom(c.vainqueur)) } else { String::new() };
+    format!("{} {} {}{}", nom(c.equipe_a), score, nom(c.equipe_b), qualifie)
+}
+
+fn construire_coupe(commands: &mut Commands, etat_jeu: &EtatJeu) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        BackgroundColor(COULEUR_FOND),
+        EcranCoupe,
+    )).with_children(|parent| {
+        // En-tête
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(56.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                padding: UiRect::horizontal(Val::Px(16.0)),
+                border: UiRect::bottom(Val::Px(1.0)),
+                column_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.06, 0.06, 0.14, 1.0)),
+            BorderColor(COULEUR_BORDURE),
+        )).with_children(|nav| {
+            nav.spawn((
+                Button,
+                Node {
+                    width: Val::Px(100.0),
+                    height: Val::Px(36.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(COULEUR_BTN_NORMAL),
+                BorderColor(COULEUR_BORDURE),
+                BorderRadius::all(Val::Px(4.0)),
+                BoutonNavigation(EcranJeu::MenuPrincipal),
+            )).with_children(|btn| {
+                btn.spawn((
+                    Text::new("← Retour"),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(COULEUR_TEXTE),
+                ));
+            });
+
+            let titre = match &etat_jeu.coupe {
+                Some(coupe) => format!("🥇 {} ({})", coupe.nom, coupe.format.get_nom()),
+                None => "🥇 Coupe".to_string(),
+            };
+            nav.spawn((
+                Text::new(titre),
+                TextFont { font_size: 22.0, ..default() },
+                TextColor(COULEUR_ACCENT),
+            ));
+        });
+
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_grow: 1.0,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(24.0)),
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+        )).with_children(|content| {
+            // Tableau : une colonne par tour, du premier tour à la finale
+            if let Some(coupe) = &etat_jeu.coupe {
+                content.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(24.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                )).with_children(|tableau| {
+                    for (tour, confrontations) in coupe.tours.iter().enumerate() {
+                        tableau.spawn((
+                            Node {
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(8.0),
+                                ..default()
+                            },
+                        )).with_children(|colonne| {
+                            colonne.spawn((
+                                Text::new(coupe.nom_tour(tour)),
+                                TextFont { font_size: 14.0, ..default() },
+                                TextColor(COULEUR_ACCENT),
+                            ));
+                            for c in confrontations {
+                                colonne.spawn((
+                                    Node {
+                                        width: Val::Px(260.0),
+                                        padding: UiRect::all(Val::Px(8.0)),
+                                        border: UiRect::all(Val::Px(1.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(COULEUR_PANNEAU),
+                                    BorderColor(if c.vainqueur.is_some() { COULEUR_SUCCES } else { COULEUR_BORDURE }),
+                                    BorderRadius::all(Val::Px(4.0)),
+                                )).with_children(|case| {
+                                    case.spawn((
+                                        Text::new(libelle_confrontation(etat_jeu, c)),
+                                        TextFont { font_size: 12.0, ..default() },
+                                        TextColor(COULEUR_TEXTE),
+                                    ));
+                                });
+                            }
+                        });
+                    }
+                });
+
+                match coupe.vainqueur.and_then(|id| etat_jeu.get_equipe(id)) {
+                    Some(vainqueur) => {
+                        content.spawn((
+                            Text::new(format!("🏆 {} remporte la coupe !", vainqueur.nom)),
+                            TextFont { font_size: 20.0, ..default() },
+                            TextColor(COULEUR_AVERTISSEMENT),
+                        ));
+                    }
+                    None => {
+                        if let Some(affiche) = coupe.prochaine_affiche() {
+                            let nom = |id: u32| etat_jeu.get_equipe(id).map(|e| e.nom.clone()).unwrap_or_default();
+                            let manche = match affiche.buts_aller {
+                                Some(_) => "match retour",
+                                None if affiche.decisive => "match sec",
+                                None => "match aller",
+                            };
+                            content.spawn((
+                                Text::new(format!("{} ({}) : {} vs {}",
+                                    coupe.nom_tour(affiche.tour), manche, nom(affiche.domicile_id), nom(affiche.exterieur_id))),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(COULEUR_TEXTE),
+                            ));
+                        }
+                        bouton(content, "⚽ Jouer le prochain match".to_string(), ActionCoupe::JouerMatch, COULEUR_SUCCES);
+                    }
+                }
+            } else {
+                content.spawn((
+                    Text::new("Aucune coupe en cours : choisissez les têtes de série et le format, puis tirez le tableau."),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(COULEUR_TEXTE_SECONDAIRE),
+                ));
+            }
+
+            // Nouvelle coupe (remplace le tableau terminé)
+            let coupe_en_cours = matches!(&etat_jeu.coupe, Some(c) if !c.est_terminee());
+            if !coupe_en_cours {
+                bouton(content, format!("🎯 Têtes de série : {}", etat_jeu.critere_coupe.get_nom()), ActionCoupe::ChangerCritere, COULEUR_TEXTE);
+                bouton(content, format!("🔁 Format : {}", etat_jeu.format_coupe.get_nom()), ActionCoupe::ChangerFormat, COULEUR_TEXTE);
+                bouton(content, "🎲 Tirer le tableau".to_string(), ActionCoupe::Tirer, COULEUR_SUCCES);
+            }
+        });
+    });
+}
+
+fn gerer_boutons_coupe(
+    mut commands: Commands,
+    mut survols: Query<(&Interaction, &mut BackgroundColor), Changed<Interaction>>,
+    actions: Query<(&Interaction, &BoutonCoupe), Changed<Interaction>>,
+    navigations: Query<(&Interaction, &BoutonNavigation), Changed<Interaction>>,
+    ecran: Query<Entity, With<EcranCoupe>>,
+    mut etat_jeu: ResMut<EtatJeu>,
+    mut prochaine_etat: ResMut<NextState<EcranJeu>>,
+) {
+    for (interaction, mut couleur) in survols.iter_mut() {
+        *couleur = BackgroundColor(match interaction {
+            Interaction::Pressed => COULEUR_BTN_PRESSE,
+            Interaction::Hovered => COULEUR_BTN_SURVOL,
+            Interaction::None => COULEUR_BTN_NORMAL,
+        });
+    }
+    for (interaction, nav) in navigations.iter() {
+        if *interaction == Interaction::Pressed {
+            prochaine_etat.set(nav.0);
+        }
+    }
+
+    for (interaction, BoutonCoupe(action)) in actions.iter() {
+        if *interaction != Interaction::Pressed { continue; }
+        match action {
+            ActionCoupe::ChangerCritere => etat_jeu.critere_coupe = etat_jeu.critere_coupe.suivant(),
+            ActionCoupe::ChangerFormat => {
+                etat_jeu.format_coupe = match etat_jeu.format_coupe {
+                    FormatConfrontation::MatchSec => FormatConfrontation::AllerRetour,
+                    FormatConfrontation::AllerRetour => FormatConfrontation::MatchSec,
+                };
+            }
+            ActionCoupe::Tirer => {
+                if let Err(e) = etat_jeu.lancer_coupe() {
+                    warn!("Impossible de tirer la coupe: {}", e);
+                }
+            }
+            ActionCoupe::JouerMatch => {
+                match etat_jeu.creer_match_coupe() {
+                    Ok(()) => {
+                        if let Some(ref mut m) = etat_jeu.match_actuel {
+                            m.demarrer();
+                        }
+                        prochaine_etat.set(EcranJeu::MatchEnCours);
+                    }
+                    Err(e) => warn!("Impossible de lancer le match de coupe: {}", e),
+                }
+                return;
+            }
+        }
+        // Reconstruire l'écran avec les nouveaux réglages
+        for entity in ecran.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        construire_coupe(&mut commands, &etat_jeu);
+    }
+}
@@ -181,6 +181,34 @@ fn afficher_fiche_joueur(parent: &mut ChildBuilder, joueur: &Joueur, equipe_nom:
             TextColor(COULEUR_TEXTE_SECONDAIRE),
         ));
 
+        // Familiarité avec chaque poste
+        let f = &joueur.familiarite;
+        card.spawn((
+            Text::new(format!("GK {:.0} | DEF {:.0} | MIL {:.0} | ATT {:.0}", f.gardien, f.defenseur, f.milieu, f.attaquant)),
+            TextFont { font_size: 9.0, ..default() },
+            TextColor(COULEUR_TEXTE_SECONDAIRE),
+        ));
+
+        // Malus si le joueur est aligné hors de son poste
+        let malus = (1.0 - joueur.multiplicateur_poste()) * 100.0;
+        if malus >= 1.0 {
+            card.spawn((
+                Text::new(format!("⚠ {} : -{:.0}% hors poste", joueur.position_actuelle.get_name(), malus)),
+                TextFont { font_size: 9.0, ..default() },
+                TextColor(COULEUR_AVERTISSEMENT),
+            ));
+        }
+
+        // Blessure en cours et date de retour
+        if let Some(ref blessure) = joueur.blessure {
+            card.spawn((
+                Text::new(format!("🤕 {} ({}) : retour dans {} match(s)",
+                    blessure.nature, blessure.gravite.get_nom(), blessure.matchs_restants)),
+                TextFont { font_size: 9.0, ..default() },
+                TextColor(COULEUR_ERREUR),
+            ));
+        }
+
         // Séparateur
         card.spawn((
             Node {
@@ -280,7 +308,10 @@ fn afficher_fiche_joueur(parent: &mut ChildBuilder, joueur: &Joueur, equipe_nom:
 
         // Stats de carrière
         card.spawn((
-            Text::new(format!("⚽{} 🅰{} 🎮{}", joueur.buts, joueur.passes_decisives, joueur.matchs_joues)),
+            Text::new(match joueur.note_moyenne() {
+                Some(note) => format!("⚽{} 🅰{} 🎮{} ⭐{:.1}", joueur.buts, joueur.passes_decisives, joueur.matchs_joues, note),
+                None => format!("⚽{} 🅰{} 🎮{}", joueur.buts, joueur.passes_decisives, joueur.matchs_joues),
+            }),
             TextFont { font_size: 10.0, ..default() },
             TextColor(COULEUR_TEXTE_SECONDAIRE),
         ));
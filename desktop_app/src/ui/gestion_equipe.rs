@@ -172,7 +172,7 @@ fn afficher_panneau_equipe(
                     COULEUR_TEXTE_SECONDAIRE
                 };
 
-                let statut = if joueur.sur_le_terrain { "★" } else if joueur.blesse { "🤕" } else { " " };
+                let statut = if joueur.sur_le_terrain { "★" } else if joueur.blesse { "🤕" } else if joueur.suspendu { "🟥" } else { " " };
                 let domaine_couleur = couleur_domaine(&joueur.domaine);
 
                 parent.spawn((
@@ -204,11 +204,34 @@ fn afficher_panneau_equipe(
                         Node { flex_grow: 1.0, ..default() },
                     ));
 
-                    // Position
+                    // Indisponibilité : matchs restant avant le retour
+                    if let Some(ref blessure) = joueur.blessure {
+                        row.spawn((
+                            Text::new(format!("{} match(s)", blessure.matchs_restants)),
+                            TextFont { font_size: 11.0, ..default() },
+                            TextColor(COULEUR_ERREUR),
+                        ));
+                    } else if joueur.suspendu {
+                        row.spawn((
+                            Text::new(format!("{} match(s)", joueur.matchs_suspension)),
+                            TextFont { font_size: 11.0, ..default() },
+                            TextColor(COULEUR_ERREUR),
+                        ));
+                    }
+
+                    // Position (poste occupé et malus éventuel pour les titulaires)
+                    let malus = (1.0 - joueur.multiplicateur_poste()) * 100.0;
+                    let (texte_poste, couleur_poste) = if joueur.sur_le_terrain && malus >= 1.0 {
+                        (format!("{} -{:.0}%", joueur.position_actuelle.get_name(), malus), COULEUR_AVERTISSEMENT)
+                    } else if joueur.sur_le_terrain {
+                        (joueur.position_actuelle.get_name().to_string(), COULEUR_TEXTE_SECONDAIRE)
+                    } else {
+                        (joueur.position_preferee.get_name().to_string(), COULEUR_TEXTE_SECONDAIRE)
+                    };
                     row.spawn((
-                        Text::new(joueur.position_preferee.get_name()),
+                        Text::new(texte_poste),
                         TextFont { font_size: 11.0, ..default() },
-                        TextColor(COULEUR_TEXTE_SECONDAIRE),
+                        TextColor(couleur_poste),
                     ));
 
                     // Note
@@ -15,6 +15,9 @@ impl Plugin for MatchEnCoursPlugin {
                mettre_a_jour_match,
                mettre_a_jour_ui_match,
                gerer_controles_match,
+               gerer_boutons_power_up,
+               gerer_boutons_capacite,
+               gerer_boutons_tireur_au_but,
                verifier_fin_match,
            ).run_if(in_state(EcranJeu::MatchEnCours)));
     }
@@ -83,7 +86,7 @@ fn afficher_match(
                     AffichageScore,
                 ));
                 centre.spawn((
-                    Text::new(format!("{} — {}", match_actuel.periode.get_nom(), match_actuel.get_temps_affichage())),
+                    Text::new(format!("{} — {}", match_actuel.get_nom_periode(), match_actuel.get_temps_affichage())),
                     TextFont { font_size: 14.0, ..default() },
                     TextColor(COULEUR_ACCENT),
                     AffichageTemps,
@@ -121,7 +124,7 @@ fn afficher_match(
             },
         )).with_children(|main| {
             // Panneau gauche - Équipe domicile
-            afficher_panneau_equipe_match(main, &match_actuel.equipe_domicile, true);
+            afficher_panneau_equipe_match(main, &match_actuel.equipe_domicile, true, match_actuel.regles.tirs_au_but);
 
             // Zone centrale - Terrain simplifié
             main.spawn((
@@ -194,12 +197,23 @@ fn afficher_match(
                     TextColor(COULEUR_TEXTE_SECONDAIRE),
                 ));
 
+                // Porteur du ballon (simulation spatiale)
+                if let Some(porteur) = match_actuel.get_porteur_ballon()
+                    .and_then(|e| match_actuel.get_joueur(e.joueur_id))
+                {
+                    terrain.spawn((
+                        Text::new(format!("Ballon : {}", porteur.prenom)),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(COULEUR_TEXTE_SECONDAIRE),
+                    ));
+                }
+
                 // Contrôles
                 parent_controles_match(terrain, match_actuel.en_jeu, match_actuel.periode.clone());
             });
 
             // Panneau droit - Équipe extérieur
-            afficher_panneau_equipe_match(main, &match_actuel.equipe_exterieur, false);
+            afficher_panneau_equipe_match(main, &match_actuel.equipe_exterieur, false, match_actuel.regles.tirs_au_but);
         });
 
         // ===== Log des événements =====
@@ -248,6 +262,7 @@ fn afficher_panneau_equipe_match(
     parent: &mut ChildBuilder,
     equipe: &crate::models::Equipe,
     est_domicile: bool,
+    tirs_au_but: bool,
 ) {
     let couleur = if est_domicile { Color::srgb(0.9, 0.3, 0.3) } else { Color::srgb(0.3, 0.3, 0.9) };
 
@@ -333,6 +348,81 @@ fn afficher_panneau_equipe_match(
                     TextFont { font_size: 9.0, ..default() },
                     TextColor(couleur_stamina),
                 ));
+
+                // Capacité spéciale (l'équipe extérieure est pilotée par l'IA)
+                if est_domicile {
+                    jrow.spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::all(Val::Px(2.0)),
+                            border: UiRect::all(Val::Px(1.0)),
+                            ..default()
+                        },
+                        BackgroundColor(COULEUR_BTN_NORMAL),
+                        BorderColor(COULEUR_ACCENT),
+                        BorderRadius::all(Val::Px(3.0)),
+                        BoutonCapacite { equipe_id: equipe.id, joueur_id: joueur.id },
+                    )).with_children(|btn| {
+                        btn.spawn((
+                            Text::new(format!("🔬 {}", joueur.capacite_speciale.nom)),
+                            TextFont { font_size: 9.0, ..default() },
+                            TextColor(COULEUR_ACCENT),
+                        ));
+                    });
+                }
+
+                // Match à élimination directe : désigner les tireurs au but, dans l'ordre des clics
+                if est_domicile && tirs_au_but {
+                    jrow.spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::all(Val::Px(2.0)),
+                            border: UiRect::all(Val::Px(1.0)),
+                            ..default()
+                        },
+                        BackgroundColor(COULEUR_BTN_NORMAL),
+                        BorderColor(COULEUR_BORDURE),
+                        BorderRadius::all(Val::Px(3.0)),
+                        BoutonTireurAuBut { equipe_id: equipe.id, joueur_id: joueur.id },
+                    )).with_children(|btn| {
+                        btn.spawn((
+                            Text::new("🎯 Tireur au but"),
+                            TextFont { font_size: 9.0, ..default() },
+                            TextColor(COULEUR_TEXTE_SECONDAIRE),
+                        ));
+                    });
+                }
+            });
+        }
+
+        // Power-ups de l'équipe
+        if !equipe.inventaire_power_ups.disponibles.is_empty() {
+            panel.spawn((
+                Text::new("Power-ups:"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(COULEUR_ACCENT),
+            ));
+        }
+        for type_power_up in &equipe.inventaire_power_ups.disponibles {
+            let [r, g, b, _] = type_power_up.get_couleur();
+            panel.spawn((
+                Button,
+                Node {
+                    width: Val::Percent(100.0),
+                    padding: UiRect::all(Val::Px(3.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(COULEUR_BTN_NORMAL),
+                BorderColor(Color::srgb(r, g, b)),
+                BorderRadius::all(Val::Px(3.0)),
+                BoutonPowerUp { equipe_id: equipe.id, type_power_up: *type_power_up },
+            )).with_children(|btn| {
+                btn.spawn((
+                    Text::new(format!("⚡ {} ({:.0}s)", type_power_up.get_nom(), type_power_up.get_duree())),
+                    TextFont { font_size: 10.0, ..default() },
+                    TextColor(Color::srgb(r, g, b)),
+                ));
             });
         }
     });
@@ -352,7 +442,8 @@ fn parent_controles_match(
         },
     )).with_children(|ctrl| {
         match periode {
-            PeriodeMatch::PremiereMitemps | PeriodeMatch::DeuxiemeMitemps => {
+            PeriodeMatch::PremiereMitemps | PeriodeMatch::DeuxiemeMitemps
+            | PeriodeMatch::Prolongation | PeriodeMatch::TirsAuBut => {
                 if en_jeu {
                     // Bouton pause
                     ctrl.spawn((
@@ -432,6 +523,31 @@ fn parent_controles_match(
                 ));
             }
         }
+
+        // Résoudre la suite du match sans l'afficher
+        if periode != PeriodeMatch::Termine {
+            ctrl.spawn((
+                Button,
+                Node {
+                    width: Val::Px(160.0),
+                    height: Val::Px(40.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(COULEUR_BTN_NORMAL),
+                BorderColor(COULEUR_BORDURE),
+                BorderRadius::all(Val::Px(4.0)),
+                BoutonSimulerFin,
+            )).with_children(|btn| {
+                btn.spawn((
+                    Text::new("⏩ Résultat final"),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(COULEUR_TEXTE),
+                ));
+            });
+        }
     });
 }
 
@@ -459,7 +575,7 @@ fn mettre_a_jour_ui_match(
 
         // Mettre à jour le temps
         for mut text in q_temps.iter_mut() {
-            *text = Text::new(format!("{} — {}", m.periode.get_nom(), m.get_temps_affichage()));
+            *text = Text::new(format!("{} — {}", m.get_nom_periode(), m.get_temps_affichage()));
         }
     }
 }
@@ -471,12 +587,13 @@ fn gerer_controles_match(
         Option<&BoutonPauseMatch>,
         Option<&BoutonReprendreMatch>,
         Option<&BoutonMiTemps>,
+        Option<&BoutonSimulerFin>,
         Option<&BoutonNavigation>,
     ), (Changed<Interaction>, With<Button>)>,
     mut etat_jeu: ResMut<EtatJeu>,
     mut prochaine_etat: ResMut<NextState<EcranJeu>>,
 ) {
-    for (interaction, mut couleur, pause, reprendre, mitemps, nav) in interactions.iter_mut() {
+    for (interaction, mut couleur, pause, reprendre, mitemps, simuler, nav) in interactions.iter_mut() {
         if pause.is_some() {
             match interaction {
                 Interaction::Pressed => {
@@ -507,6 +624,16 @@ fn gerer_controles_match(
                 Interaction::Hovered => *couleur = BackgroundColor(Color::srgb(0.15, 0.4, 0.15)),
                 Interaction::None => *couleur = BackgroundColor(Color::srgb(0.1, 0.3, 0.1)),
             }
+        } else if simuler.is_some() {
+            match interaction {
+                Interaction::Pressed => {
+                    if let Some(ref mut m) = etat_jeu.match_actuel {
+                        m.simuler_jusqu_a_la_fin();
+                    }
+                }
+                Interaction::Hovered => *couleur = BackgroundColor(COULEUR_BTN_SURVOL),
+                Interaction::None => *couleur = BackgroundColor(COULEUR_BTN_NORMAL),
+            }
         } else if let Some(nav) = nav {
             match interaction {
                 Interaction::Pressed => prochaine_etat.set(nav.0),
@@ -517,6 +644,79 @@ fn gerer_controles_match(
     }
 }
 
+/// Activer un power-up sur le porteur du ballon, ou à défaut sur le meilleur joueur du terrain
+fn gerer_boutons_power_up(
+    mut commands: Commands,
+    mut interactions: Query<(Entity, &Interaction, &mut BackgroundColor, &BoutonPowerUp), Changed<Interaction>>,
+    mut etat_jeu: ResMut<EtatJeu>,
+) {
+    for (entite, interaction, mut couleur, bouton) in interactions.iter_mut() {
+        match interaction {
+            Interaction::Pressed => {
+                let Some(ref mut m) = etat_jeu.match_actuel else { continue; };
+                let equipe = if bouton.equipe_id == m.equipe_domicile.id { &m.equipe_domicile } else { &m.equipe_exterieur };
+                let Some(index) = equipe.inventaire_power_ups.disponibles.iter().position(|p| *p == bouton.type_power_up) else { continue; };
+                let cible = m.get_porteur_ballon()
+                    .filter(|e| e.equipe_id == bouton.equipe_id)
+                    .map(|e| e.joueur_id)
+                    .or_else(|| equipe.get_titulaires().iter()
+                        .max_by(|a, b| a.note_globale().partial_cmp(&b.note_globale()).unwrap_or(std::cmp::Ordering::Equal))
+                        .map(|j| j.id));
+                if let Some(joueur_id) = cible {
+                    if m.activer_power_up(bouton.equipe_id, joueur_id, index).is_ok() {
+                        commands.entity(entite).despawn_recursive();
+                    }
+                }
+            }
+            Interaction::Hovered => *couleur = BackgroundColor(COULEUR_BTN_SURVOL),
+            Interaction::None => *couleur = BackgroundColor(COULEUR_BTN_NORMAL),
+        }
+    }
+}
+
+fn gerer_boutons_capacite(
+    mut interactions: Query<(&Interaction, &mut BackgroundColor, &BoutonCapacite), Changed<Interaction>>,
+    mut etat_jeu: ResMut<EtatJeu>,
+) {
+    for (interaction, mut couleur, bouton) in interactions.iter_mut() {
+        match interaction {
+            Interaction::Pressed => {
+                if let Some(ref mut m) = etat_jeu.match_actuel {
+                    if let Err(e) = m.utiliser_capacite(bouton.equipe_id, bouton.joueur_id) {
+                        info!("Capacité indisponible : {}", e);
+                    }
+                }
+            }
+            Interaction::Hovered => *couleur = BackgroundColor(COULEUR_BTN_SURVOL),
+            Interaction::None => *couleur = BackgroundColor(COULEUR_BTN_NORMAL),
+        }
+    }
+}
+
+fn gerer_boutons_tireur_au_but(
+    mut interactions: Query<(&Interaction, &mut BackgroundColor, &BoutonTireurAuBut), Changed<Interaction>>,
+    mut etat_jeu: ResMut<EtatJeu>,
+) {
+    for (interaction, mut couleur, bouton) in interactions.iter_mut() {
+        match interaction {
+            Interaction::Pressed => {
+                if let Some(ref mut m) = etat_jeu.match_actuel {
+                    let mut ordre = m.tireurs_choisis_domicile.clone();
+                    if !ordre.contains(&bouton.joueur_id) {
+                        ordre.push(bouton.joueur_id);
+                    }
+                    match m.definir_ordre_tireurs(bouton.equipe_id, ordre) {
+                        Ok(()) => *couleur = BackgroundColor(COULEUR_BTN_PRESSE),
+                        Err(e) => info!("Ordre des tireurs inchangé : {}", e),
+                    }
+                }
+            }
+            Interaction::Hovered => *couleur = BackgroundColor(COULEUR_BTN_SURVOL),
+            Interaction::None => {}
+        }
+    }
+}
+
 fn verifier_fin_match(
     etat_jeu: Res<EtatJeu>,
     mut prochaine_etat: ResMut<NextState<EcranJeu>>,
@@ -532,9 +732,14 @@ fn couleur_evenement(evt: &EvenementMatch) -> Color {
     match evt {
         EvenementMatch::But { .. } => Color::srgb(1.0, 0.9, 0.0),
         EvenementMatch::CartonJaune { .. } => COULEUR_AVERTISSEMENT,
+        EvenementMatch::CartonRouge { .. } | EvenementMatch::Blessure { .. } => COULEUR_ERREUR,
         EvenementMatch::DecouverteScientifique { .. } => COULEUR_ACCENT,
         EvenementMatch::SauvetageGardien { .. } => Color::srgb(0.5, 0.8, 0.9),
         EvenementMatch::BelleAction { .. } => COULEUR_SUCCES,
+        EvenementMatch::CapaciteUtilisee { .. } => COULEUR_ACCENT,
+        EvenementMatch::TirAuBut { reussi: true, .. } => COULEUR_SUCCES,
+        EvenementMatch::TirAuBut { reussi: false, .. } => COULEUR_ERREUR,
+        EvenementMatch::Faute { .. } | EvenementMatch::Tir { .. } => COULEUR_TEXTE_SECONDAIRE,
         _ => COULEUR_TEXTE,
     }
 }
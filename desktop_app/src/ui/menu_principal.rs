@@ -1,8 +1,12 @@
 use bevy::prelude::*;
 use crate::game_state::{EcranJeu, EtatJeu};
+use crate::models::GenerateurAleatoire;
 use crate::ui::styles::*;
 use crate::ui::components::*;
 
+/// Nombre de clubs de la ligue générée depuis le menu
+const TAILLE_LIGUE_DEFAUT: usize = 10;
+
 /// Plugin du menu principal
 pub struct MenuPrincipalPlugin;
 
@@ -10,7 +14,7 @@ impl Plugin for MenuPrincipalPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(EcranJeu::MenuPrincipal), afficher_menu_principal)
            .add_systems(OnExit(EcranJeu::MenuPrincipal), nettoyer_ecran::<EcranMenuPrincipal>)
-           .add_systems(Update, gerer_boutons_menu.run_if(in_state(EcranJeu::MenuPrincipal)));
+           .add_systems(Update, (gerer_boutons_menu, gerer_bouton_generer_ligue).run_if(in_state(EcranJeu::MenuPrincipal)));
     }
 }
 
@@ -70,6 +74,8 @@ fn afficher_menu_principal(mut commands: Commands) {
             ("🏟️  Jouer", EcranJeu::SelectionEquipe),
             ("👥  Gestion des Équipes", EcranJeu::GestionEquipe),
             ("🏆  Classement", EcranJeu::Classement),
+            ("🥇  Coupe", EcranJeu::Coupe),
+            ("💼  Transferts", EcranJeu::Transferts),
             ("📋  Fiches Joueurs", EcranJeu::FichesJoueurs),
         ];
 
@@ -125,6 +131,30 @@ fn afficher_menu_principal(mut commands: Commands) {
             ));
         });
 
+        // Bouton ligue générée
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(320.0),
+                height: Val::Px(45.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(6.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(COULEUR_BTN_NORMAL),
+            BorderColor(COULEUR_ACCENT),
+            BorderRadius::all(Val::Px(6.0)),
+            BoutonGenererLigue,
+        )).with_children(|btn| {
+            btn.spawn((
+                Text::new(format!("🌍 Générer une ligue de {} clubs", TAILLE_LIGUE_DEFAUT)),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(COULEUR_ACCENT),
+            ));
+        });
+
         // Version
         parent.spawn((
             Text::new("v0.1.0 — Rust + Bevy"),
@@ -168,6 +198,22 @@ fn gerer_boutons_menu(
     }
 }
 
+fn gerer_bouton_generer_ligue(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<BoutonGenererLigue>)>,
+    mut etat_jeu: ResMut<EtatJeu>,
+) {
+    // Le survol est géré par gerer_boutons_menu
+    for interaction in interactions.iter() {
+        if *interaction == Interaction::Pressed {
+            let graine = GenerateurAleatoire::graine_aleatoire();
+            match etat_jeu.generer_monde(graine, TAILLE_LIGUE_DEFAUT) {
+                Ok(()) => info!("Ligue de {} clubs générée (graine {})", TAILLE_LIGUE_DEFAUT, graine),
+                Err(e) => warn!("Impossible de générer la ligue: {}", e),
+            }
+        }
+    }
+}
+
 pub fn nettoyer_ecran<T: Component>(
     mut commands: Commands,
     query: Query<Entity, With<T>>,
@@ -7,6 +7,8 @@ pub mod match_en_cours;
 pub mod resultat_match;
 pub mod classement;
 pub mod fiches_joueurs;
+pub mod coupe;
+pub mod transferts;
 
 use bevy::prelude::*;
 use menu_principal::MenuPrincipalPlugin;
@@ -16,6 +18,8 @@ use match_en_cours::MatchEnCoursPlugin;
 use resultat_match::ResultatMatchPlugin;
 use classement::ClassementPlugin;
 use fiches_joueurs::FichesJoueursPlugin;
+use coupe::CoupePlugin;
+use transferts::TransfertsPlugin;
 
 /// Plugin regroupant toute l'interface utilisateur
 pub struct UIPlugin;
@@ -30,6 +34,8 @@ impl Plugin for UIPlugin {
             ResultatMatchPlugin,
             ClassementPlugin,
             FichesJoueursPlugin,
+            CoupePlugin,
+            TransfertsPlugin,
         ));
     }
 }
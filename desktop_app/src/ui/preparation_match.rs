@@ -15,6 +15,7 @@ impl Plugin for PreparationMatchPlugin {
            .add_systems(Update, (
                gerer_selection_equipe,
                gerer_bouton_demarrer,
+               gerer_bouton_difficulte_ia,
            ).run_if(in_state(EcranJeu::SelectionEquipe).or(in_state(EcranJeu::PreparationMatch))));
     }
 }
@@ -64,7 +65,10 @@ fn afficher_selection_equipe(
                 parent.spawn((
                     Node {
                         flex_direction: FlexDirection::Row,
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::Center,
                         column_gap: Val::Px(32.0),
+                        row_gap: Val::Px(16.0),
                         ..default()
                     },
                 )).with_children(|parent| {
@@ -110,6 +114,46 @@ fn afficher_selection_equipe(
                     }
                 });
 
+                // Affiche de la journée de championnat
+                let championnat = &etat_jeu.championnat;
+                let affiche = match championnat.prochaine_rencontre() {
+                    Some(r) => {
+                        let nom = |id: u32| etat_jeu.get_equipe(id).map(|e| e.nom.clone()).unwrap_or_default();
+                        format!("🗓️ Saison {} — Journée {}/{} : {} vs {}",
+                            championnat.saison, r.journee, championnat.nb_journees(), nom(r.domicile_id), nom(r.exterieur_id))
+                    }
+                    None => format!("🗓️ Saison {} terminée — match amical", championnat.saison),
+                };
+                parent.spawn((
+                    Text::new(affiche),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(COULEUR_ACCENT),
+                ));
+
+                // Niveau de l'entraîneur adverse (cliquer pour changer)
+                parent.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(280.0),
+                        height: Val::Px(40.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(COULEUR_BTN_NORMAL),
+                    BorderColor(COULEUR_BORDURE),
+                    BorderRadius::all(Val::Px(6.0)),
+                    BoutonDifficulteIA,
+                )).with_children(|btn| {
+                    btn.spawn((
+                        Text::new(format!("🤖 Entraîneur adverse : {}", etat_jeu.difficulte_ia.get_nom())),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(COULEUR_TEXTE),
+                        AffichageDifficulteIA,
+                    ));
+                });
+
                 // Bouton lancer le match
                 parent.spawn((
                     Button,
@@ -133,6 +177,15 @@ fn afficher_selection_equipe(
                         TextColor(COULEUR_SUCCES),
                     ));
                 });
+
+                // Raison pour laquelle le match n'a pas pu être lancé
+                parent.spawn((
+                    Text::new(""),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(COULEUR_ERREUR),
+                    Node { margin: UiRect::top(Val::Px(8.0)), ..default() },
+                    AffichageErreurMatch,
+                ));
             }
         });
     });
@@ -166,6 +219,7 @@ fn gerer_selection_equipe(
 
 fn gerer_bouton_demarrer(
     mut interactions: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<BoutonDemarrerMatch>)>,
+    mut erreurs: Query<&mut Text, With<AffichageErreurMatch>>,
     mut etat_jeu: ResMut<EtatJeu>,
     mut prochaine_etat: ResMut<NextState<EcranJeu>>,
 ) {
@@ -174,20 +228,17 @@ fn gerer_bouton_demarrer(
             Interaction::Pressed => {
                 *couleur = BackgroundColor(COULEUR_BTN_PRESSE);
 
-                // Vérifier qu'on a 2 équipes
-                if etat_jeu.equipes.len() >= 2 {
-                    let equipe1_id = etat_jeu.equipes[0].id;
-                    let equipe2_id = etat_jeu.equipes[1].id;
-
-                    match etat_jeu.creer_match(equipe1_id, equipe2_id) {
-                        Ok(_) => {
-                            if let Some(ref mut m) = etat_jeu.match_actuel {
-                                m.demarrer();
-                            }
-                            prochaine_etat.set(EcranJeu::MatchEnCours);
+                // Prochaine rencontre du club (les matchs IA d'avant sont simulés), sinon un amical
+                match etat_jeu.creer_prochain_match() {
+                    Ok(_) => {
+                        if let Some(ref mut m) = etat_jeu.match_actuel {
+                            m.demarrer();
                         }
-                        Err(e) => {
-                            warn!("Impossible de démarrer le match: {}", e);
+                        prochaine_etat.set(EcranJeu::MatchEnCours);
+                    }
+                    Err(e) => {
+                        for mut texte in erreurs.iter_mut() {
+                            texte.0 = format!("⚠️ Impossible de lancer le match : {}", e);
                         }
                     }
                 }
@@ -198,6 +249,22 @@ fn gerer_bouton_demarrer(
     }
 }
 
+fn gerer_bouton_difficulte_ia(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<BoutonDifficulteIA>)>,
+    mut textes: Query<&mut Text, With<AffichageDifficulteIA>>,
+    mut etat_jeu: ResMut<EtatJeu>,
+) {
+    // Le survol est géré par gerer_selection_equipe, commun à tous les boutons de l'écran
+    for interaction in interactions.iter() {
+        if *interaction == Interaction::Pressed {
+            etat_jeu.difficulte_ia = etat_jeu.difficulte_ia.suivant();
+            for mut texte in textes.iter_mut() {
+                texte.0 = format!("🤖 Entraîneur adverse : {}", etat_jeu.difficulte_ia.get_nom());
+            }
+        }
+    }
+}
+
 fn barre_navigation_simple(parent: &mut ChildBuilder, titre: &str, ecran_retour: EcranJeu) {
     parent.spawn((
         Node {
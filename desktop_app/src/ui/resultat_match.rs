@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::game_state::{EcranJeu, EtatJeu};
+use crate::models::match_engine::Competition;
 use crate::ui::styles::*;
 use crate::ui::components::*;
 use crate::ui::menu_principal::nettoyer_ecran;
@@ -28,10 +29,14 @@ fn afficher_resultat(
 
     let titre_resultat = if est_nul {
         "⚽ Match Nul !".to_string()
-    } else if vainqueur == Some(m.equipe_domicile.id) {
-        format!("🏆 {} Gagne !", m.equipe_domicile.nom)
     } else {
-        format!("🏆 {} Gagne !", m.equipe_exterieur.nom)
+        let nom = if vainqueur == Some(m.equipe_domicile.id) { &m.equipe_domicile.nom } else { &m.equipe_exterieur.nom };
+        // Au match retour, c'est la qualification qui se joue
+        if m.buts_aller.is_some() { format!("🏆 {} se qualifie !", nom) } else { format!("🏆 {} Gagne !", nom) }
+    };
+    let (ecran_suite, libelle_suite) = match m.competition {
+        Competition::Championnat => (EcranJeu::Classement, "🏆 Classement"),
+        Competition::Coupe => (EcranJeu::Coupe, "🥇 Tableau de la coupe"),
     };
 
     commands.spawn((
@@ -87,6 +92,11 @@ fn afficher_resultat(
                     TextFont { font_size: 13.0, ..default() },
                     TextColor(COULEUR_TEXTE_SECONDAIRE),
                 ));
+                eq.spawn((
+                    Text::new(format!("xG {:.2}", m.xg_equipe(m.equipe_domicile.id))),
+                    TextFont { font_size: 13.0, ..default() },
+                    TextColor(COULEUR_ACCENT),
+                ));
             });
 
             // Score
@@ -115,6 +125,11 @@ fn afficher_resultat(
                     TextFont { font_size: 13.0, ..default() },
                     TextColor(COULEUR_TEXTE_SECONDAIRE),
                 ));
+                eq.spawn((
+                    Text::new(format!("xG {:.2}", m.xg_equipe(m.equipe_exterieur.id))),
+                    TextFont { font_size: 13.0, ..default() },
+                    TextColor(COULEUR_ACCENT),
+                ));
             });
         });
 
@@ -159,7 +174,7 @@ fn afficher_resultat(
                 }
 
                 for evt in &buts_events {
-                    if let crate::models::EvenementMatch::But { minute, buteur_id, equipe_id, .. } = evt {
+                    if let crate::models::EvenementMatch::But { minute, buteur_id, equipe_id, coup_de_pied_arrete, .. } = evt {
                         // Trouver le nom du buteur
                         let nom_buteur = trouver_nom_joueur(&m, *buteur_id).unwrap_or_else(|| "Inconnu".to_string());
                         let equipe_nom = if *equipe_id == m.equipe_domicile.id {
@@ -168,8 +183,12 @@ fn afficher_resultat(
                             &m.equipe_exterieur.nom
                         };
 
+                        // Préciser les buts marqués sur phase arrêtée
+                        let origine = coup_de_pied_arrete
+                            .map(|c| format!(" [{}]", c.get_nom().to_lowercase()))
+                            .unwrap_or_default();
                         buts.spawn((
-                            Text::new(format!("{}' {} ({})", minute, nom_buteur, equipe_nom)),
+                            Text::new(format!("{}' {} ({}){}", minute, nom_buteur, equipe_nom, origine)),
                             TextFont { font_size: 11.0, ..default() },
                             TextColor(COULEUR_AVERTISSEMENT),
                         ));
@@ -214,8 +233,67 @@ fn afficher_resultat(
                     }
                 }
             });
+
+            // Statistiques du match
+            stats.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    row_gap: Val::Px(4.0),
+                    min_width: Val::Px(240.0),
+                    ..default()
+                },
+                BackgroundColor(COULEUR_PANNEAU),
+                BorderColor(COULEUR_BORDURE),
+                BorderRadius::all(Val::Px(6.0)),
+            )).with_children(|panneau| {
+                panneau.spawn((
+                    Text::new("📊 Statistiques"),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(COULEUR_ACCENT),
+                ));
+
+                let st = &m.statistiques;
+                let (d, e) = (&st.domicile, &st.exterieur);
+                let lignes = [
+                    ("Possession", format!("{:.0}%", st.possession(true) * 100.0), format!("{:.0}%", st.possession(false) * 100.0)),
+                    ("Tirs (cadrés)", format!("{} ({})", d.tirs, d.tirs_cadres), format!("{} ({})", e.tirs, e.tirs_cadres)),
+                    ("Arrêts", d.arrets.to_string(), e.arrets.to_string()),
+                    ("Fautes", d.fautes.to_string(), e.fautes.to_string()),
+                    ("Corners", d.corners.to_string(), e.corners.to_string()),
+                    ("Cartons", format!("🟨{} 🟥{}", d.cartons_jaunes, d.cartons_rouges), format!("🟨{} 🟥{}", e.cartons_jaunes, e.cartons_rouges)),
+                    ("Découvertes", d.decouvertes.to_string(), e.decouvertes.to_string()),
+                    ("Power-ups", d.power_ups.to_string(), e.power_ups.to_string()),
+                ];
+                for (libelle, domicile, exterieur) in lignes {
+                    panneau.spawn((
+                        Text::new(format!("{}  {}  {}", domicile, libelle, exterieur)),
+                        TextFont { font_size: 11.0, ..default() },
+                        TextColor(COULEUR_TEXTE),
+                    ));
+                }
+            });
         });
 
+        // Homme du match, d'après les notes d'après-match
+        if let Some(joueur_id) = m.statistiques.homme_du_match {
+            let nom = trouver_nom_joueur(m, joueur_id).unwrap_or_else(|| "Inconnu".to_string());
+            let note = m.statistiques.joueur(joueur_id).and_then(|s| s.note).unwrap_or(0.0);
+            parent.spawn((
+                Text::new(format!("⭐ Homme du match : {} ({:.1}/10)", nom, note)),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(COULEUR_AVERTISSEMENT),
+            ));
+        }
+
+        // Graine du match (permet de rejouer exactement la même rencontre)
+        parent.spawn((
+            Text::new(format!("🎲 Graine du match : {}", m.graine)),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(COULEUR_TEXTE_SECONDAIRE),
+        ));
+
         // Boutons de navigation
         parent.spawn((
             Node {
@@ -271,7 +349,7 @@ fn afficher_resultat(
                 ));
             });
 
-            // Classement
+            // Classement ou tableau de la coupe
             btns.spawn((
                 Button,
                 Node {
@@ -285,10 +363,10 @@ fn afficher_resultat(
                 BackgroundColor(COULEUR_BTN_NORMAL),
                 BorderColor(COULEUR_BORDURE),
                 BorderRadius::all(Val::Px(6.0)),
-                BoutonNavigation(EcranJeu::Classement),
+                BoutonNavigation(ecran_suite),
             )).with_children(|btn| {
                 btn.spawn((
-                    Text::new("🏆 Classement"),
+                    Text::new(libelle_suite),
                     TextFont { font_size: 16.0, ..default() },
                     TextColor(COULEUR_TEXTE),
                 ));
@@ -0,0 +1,367 @@
+use bevy::prelude::*;
+use crate::game_state::{EcranJeu, EtatJeu};
+use crate::models::{prix_demande, Equipe, FenetreTransferts, ReponseOffre};
+use crate::ui::styles::*;
+use crate::ui::components::*;
+use crate::ui::menu_principal::nettoyer_ecran;
+
+pub struct TransfertsPlugin;
+
+impl Plugin for TransfertsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(EcranJeu::Transferts), afficher_transferts)
+           .add_systems(OnExit(EcranJeu::Transferts), nettoyer_ecran::<EcranTransferts>)
+           .add_systems(Update, gerer_boutons_transferts.run_if(in_state(EcranJeu::Transferts)));
+    }
+}
+
+fn afficher_transferts(
+    mut commands: Commands,
+    mut etat_jeu: ResMut<EtatJeu>,
+) {
+    // Les clubs IA font leur marché dès l'ouverture de la fenêtre
+    etat_jeu.lancer_mercato_ia();
+    construire_transferts(&mut commands, &etat_jeu);
+}
+
+/// Clubs dont on peut consulter l'effectif (tous sauf celui du joueur)
+fn clubs_consultables(etat_jeu: &EtatJeu) -> Vec<&Equipe> {
+    let equipe_joueur = etat_jeu.equipe_joueur_id();
+    etat_jeu.equipes.iter().filter(|e| Some(e.id) != equipe_joueur).collect()
+}
+
+fn bouton(parent: &mut ChildBuilder, texte: String, action: ActionTransfert, couleur: Color, largeur: f32) {
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(largeur),
+            height: Val::Px(30.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(COULEUR_BTN_NORMAL),
+        BorderColor(couleur),
+        BorderRadius::all(Val::Px(4.0)),
+        BoutonTransfert(action),
+    )).with_children(|btn| {
+        btn.spawn((
+            Text::new(texte),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(couleur),
+        ));
+    });
+}
+
+fn construire_transferts(commands: &mut Commands, etat_jeu: &EtatJeu) {
+    let fenetre = etat_jeu.marche.fenetre(&etat_jeu.championnat);
+    let equipe_joueur = etat_jeu.equipe_joueur_id().and_then(|id| etat_jeu.get_equipe(id));
+    let clubs = clubs_consultables(etat_jeu);
+    let club = clubs.get(etat_jeu.club_consulte_idx % clubs.len().max(1)).copied();
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        BackgroundColor(COULEUR_FOND),
+        EcranTransferts,
+    )).with_children(|parent| {
+        // En-tête
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(56.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                padding: UiRect::horizontal(Val::Px(16.0)),
+                border: UiRect::bottom(Val::Px(1.0)),
+                column_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.06, 0.06, 0.14, 1.0)),
+            BorderColor(COULEUR_BORDURE),
+        )).with_children(|nav| {
+            nav.spawn((
+                Button,
+                Node {
+                    width: Val::Px(100.0),
+                    height: Val::Px(36.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(COULEUR_BTN_NORMAL),
+                BorderColor(COULEUR_BORDURE),
+                BorderRadius::all(Val::Px(4.0)),
+                BoutonNavigation(EcranJeu::MenuPrincipal),
+            )).with_children(|btn| {
+                btn.spawn((
+                    Text::new("← Retour"),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(COULEUR_TEXTE),
+                ));
+            });
+
+            let statut = match fenetre {
+                Some(f) => format!("💼 Transferts — {} ouvert", f.get_nom()),
+                None => "💼 Transferts — marché fermé".to_string(),
+            };
+            nav.spawn((
+                Text::new(statut),
+                TextFont { font_size: 22.0, ..default() },
+                TextColor(COULEUR_ACCENT),
+            ));
+
+            if let Some(equipe) = equipe_joueur {
+                nav.spawn((
+                    Text::new(format!("{} — budget {} — salaires {} / match — {} joueurs",
+                        equipe.nom, equipe.budget, equipe.masse_salariale(), equipe.joueurs.len())),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(if equipe.est_sous_embargo() { COULEUR_ERREUR } else { COULEUR_TEXTE_SECONDAIRE }),
+                ));
+            }
+        });
+
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_grow: 1.0,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(16.0)),
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+        )).with_children(|content| {
+            if fenetre.is_none() {
+                content.spawn((
+                    Text::new("Le marché ouvre avant la première journée et à la trêve, entre les matchs aller et retour."),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(COULEUR_TEXTE_SECONDAIRE),
+                ));
+            }
+
+            // Réponse à la dernière offre, et contre-offre à trancher
+            if let Some(message) = &etat_jeu.message_transfert {
+                content.spawn((
+                    Text::new(message.clone()),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(COULEUR_AVERTISSEMENT),
+                ));
+            }
+            if let Some(contre) = etat_jeu.contre_offre {
+                content.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(12.0),
+                        ..default()
+                    },
+                )).with_children(|ligne| {
+                    bouton(ligne, format!("✅ Accepter {}", contre.montant), ActionTransfert::AccepterContreOffre, COULEUR_SUCCES, 200.0);
+                    bouton(ligne, "❌ Refuser".to_string(), ActionTransfert::RefuserContreOffre, COULEUR_ERREUR, 120.0);
+                });
+            }
+
+            // Finances du club : bilan de la saison et contrats à renouveler
+            if let Some(equipe) = equipe_joueur {
+                if equipe.est_sous_embargo() {
+                    content.spawn((
+                        Text::new("⛔ Budget dans le rouge : recrutement interdit jusqu'au retour à l'équilibre"),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(COULEUR_ERREUR),
+                    ));
+                }
+                let bilan: Vec<String> = equipe.bilan_saison(etat_jeu.championnat.saison).iter()
+                    .map(|(nature, montant)| format!("{} {:+}", nature.get_nom(), montant))
+                    .collect();
+                if !bilan.is_empty() {
+                    content.spawn((
+                        Text::new(format!("Saison {} : {}", etat_jeu.championnat.saison, bilan.join(" · "))),
+                        TextFont { font_size: 13.0, ..default() },
+                        TextColor(COULEUR_TEXTE_SECONDAIRE),
+                    ));
+                }
+                for joueur in equipe.joueurs.iter().filter(|j| j.contrat.expire()) {
+                    content.spawn((
+                        Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(12.0),
+                            ..default()
+                        },
+                    )).with_children(|ligne| {
+                        let demande = joueur.exigences_renouvellement();
+                        ligne.spawn((
+                            Text::new(match demande {
+                                Some(salaire) => format!("📝 {} termine son contrat ({} / match) et demande {}",
+                                    joueur.prenom, joueur.contrat.salaire, salaire),
+                                None => format!("📝 {} termine son contrat et ne veut pas prolonger", joueur.prenom),
+                            }),
+                            TextFont { font_size: 13.0, ..default() },
+                            TextColor(COULEUR_TEXTE),
+                        ));
+                        if let Some(salaire) = demande {
+                            let action = ActionTransfert::Prolonger { joueur_id: joueur.id, salaire };
+                            bouton(ligne, "Prolonger 2 saisons".to_string(), action, COULEUR_SUCCES, 180.0);
+                        }
+                    });
+                }
+            }
+
+            // Sélection du club consulté
+            let Some(club) = club else { return };
+            content.spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(16.0),
+                    ..default()
+                },
+            )).with_children(|ligne| {
+                bouton(ligne, "◀".to_string(), ActionTransfert::ClubPrecedent, COULEUR_TEXTE, 40.0);
+                ligne.spawn((
+                    Text::new(format!("{} (réputation {:.0}%)", club.nom, club.reputation * 100.0)),
+                    TextFont { font_size: 18.0, ..default() },
+                    TextColor(COULEUR_ACCENT),
+                ));
+                bouton(ligne, "▶".to_string(), ActionTransfert::ClubSuivant, COULEUR_TEXTE, 40.0);
+            });
+
+            // Effectif du club : valeur, prix demandé et offres possibles
+            for joueur in &club.joueurs {
+                let prix = prix_demande(club, joueur);
+                content.spawn((
+                    Node {
+                        width: Val::Px(900.0),
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::SpaceBetween,
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(COULEUR_PANNEAU),
+                    BorderRadius::all(Val::Px(4.0)),
+                )).with_children(|ligne| {
+                    ligne.spawn((
+                        Text::new(format!("{}{} — {} — {} — {} ans — note {:.0} — valeur {} — contrat {} saison(s)",
+                            if joueur.sur_le_terrain { "★ " } else { "" },
+                            joueur.prenom, joueur.domaine.get_name(), joueur.position_preferee.get_name(),
+                            joueur.age, joueur.stats_effectives.note_globale(), joueur.valeur_marchande(),
+                            joueur.contrat.saisons_restantes)),
+                        TextFont { font_size: 13.0, ..default() },
+                        TextColor(COULEUR_TEXTE),
+                    ));
+                    if fenetre.is_some() {
+                        ligne.spawn(Node { column_gap: Val::Px(8.0), ..default() }).with_children(|offres| {
+                            let offre = |montant| ActionTransfert::Offre { vendeur_id: club.id, joueur_id: joueur.id, montant };
+                            let basse = prix / 5_000 * 4_000;
+                            bouton(offres, format!("Offrir {}", basse), offre(basse), COULEUR_TEXTE, 130.0);
+                            bouton(offres, format!("Offrir {}", prix), offre(prix), COULEUR_SUCCES, 130.0);
+                            if let Some(clause) = joueur.contrat.clause_liberatoire {
+                                bouton(offres, format!("Clause {}", clause), offre(clause), COULEUR_AVERTISSEMENT, 150.0);
+                            }
+                        });
+                    }
+                });
+            }
+
+            // Derniers transferts conclus sur le marché
+            let derniers: Vec<_> = etat_jeu.marche.historique.iter().rev().take(5).collect();
+            if !derniers.is_empty() {
+                content.spawn((
+                    Text::new("Derniers transferts"),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(COULEUR_ACCENT),
+                    Node { margin: UiRect::top(Val::Px(12.0)), ..default() },
+                ));
+                let nom = |id: u32| etat_jeu.get_equipe(id).map(|e| e.nom.clone()).unwrap_or_default();
+                for t in derniers {
+                    content.spawn((
+                        Text::new(format!("Saison {} ({}) : {} — {} → {} pour {}",
+                            t.saison, t.fenetre.get_nom(), t.nom_joueur, nom(t.vendeur_id), nom(t.acheteur_id), t.montant)),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(COULEUR_TEXTE_SECONDAIRE),
+                    ));
+                }
+            }
+        });
+    });
+}
+
+fn gerer_boutons_transferts(
+    mut commands: Commands,
+    mut survols: Query<(&Interaction, &mut BackgroundColor), Changed<Interaction>>,
+    actions: Query<(&Interaction, &BoutonTransfert), Changed<Interaction>>,
+    navigations: Query<(&Interaction, &BoutonNavigation), Changed<Interaction>>,
+    ecran: Query<Entity, With<EcranTransferts>>,
+    mut etat_jeu: ResMut<EtatJeu>,
+    mut prochaine_etat: ResMut<NextState<EcranJeu>>,
+) {
+    for (interaction, mut couleur) in survols.iter_mut() {
+        *couleur = BackgroundColor(match interaction {
+            Interaction::Pressed => COULEUR_BTN_PRESSE,
+            Interaction::Hovered => COULEUR_BTN_SURVOL,
+            Interaction::None => COULEUR_BTN_NORMAL,
+        });
+    }
+    for (interaction, nav) in navigations.iter() {
+        if *interaction == Interaction::Pressed {
+            prochaine_etat.set(nav.0);
+        }
+    }
+
+    for (interaction, BoutonTransfert(action)) in actions.iter() {
+        if *interaction != Interaction::Pressed { continue; }
+        let nb_clubs = clubs_consultables(&etat_jeu).len().max(1);
+        let offre = match *action {
+            ActionTransfert::ClubPrecedent => {
+                etat_jeu.club_consulte_idx = (etat_jeu.club_consulte_idx + nb_clubs - 1) % nb_clubs;
+                None
+            }
+            ActionTransfert::ClubSuivant => {
+                etat_jeu.club_consulte_idx = (etat_jeu.club_consulte_idx + 1) % nb_clubs;
+                None
+            }
+            ActionTransfert::Offre { vendeur_id, joueur_id, montant } => Some((vendeur_id, joueur_id, montant)),
+            ActionTransfert::AccepterContreOffre => etat_jeu.contre_offre.map(|o| (o.vendeur_id, o.joueur_id, o.montant)),
+            ActionTransfert::RefuserContreOffre => {
+                etat_jeu.contre_offre = None;
+                etat_jeu.message_transfert = Some("Négociation abandonnée".to_string());
+                None
+            }
+            ActionTransfert::Prolonger { joueur_id, salaire } => {
+                etat_jeu.message_transfert = Some(match etat_jeu.prolonger_contrat(joueur_id, salaire) {
+                    Ok(ReponseOffre::Acceptee) => format!("✅ Contrat prolongé à {} par match", salaire),
+                    Ok(ReponseOffre::ContreOffre(demande)) => format!("🤝 Le joueur demande {} par match", demande),
+                    Ok(ReponseOffre::Refusee(raison)) => format!("❌ {}", raison),
+                    Err(e) => format!("⚠️ {}", e),
+                });
+                None
+            }
+        };
+
+        if let Some((vendeur_id, joueur_id, montant)) = offre {
+            let nom = etat_jeu.get_equipe(vendeur_id)
+                .and_then(|e| e.get_joueur(joueur_id))
+                .map(|j| j.prenom.clone())
+                .unwrap_or_default();
+            etat_jeu.message_transfert = Some(match etat_jeu.faire_offre(vendeur_id, joueur_id, montant) {
+                Ok(ReponseOffre::Acceptee) => format!("✅ {} rejoint votre club pour {}", nom, montant),
+                Ok(ReponseOffre::ContreOffre(prix)) => format!("🤝 Contre-offre pour {} : {}", nom, prix),
+                Ok(ReponseOffre::Refusee(raison)) => format!("❌ Offre refusée : {}", raison),
+                Err(e) => format!("⚠️ {}", e),
+            });
+        }
+
+        // Reconstruire l'écran avec l'état à jour
+        for entity in ecran.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        construire_transferts(&mut commands, &etat_jeu);
+    }
+}
@@ -15,12 +15,40 @@ pub mod models {
     pub use team::*;
     pub use match_engine::*;
     pub use power_up::*;
+    pub use aleatoire::*;
+    pub use simulation_spatiale::*;
+    pub use capacites::*;
+    pub use regles::*;
+    pub use tirs_au_but::*;
+    pub use coups_de_pied_arretes::*;
+    pub use statistiques::*;
+    pub use entraineur_ia::*;
+    pub use decouvertes::*;
+    pub use championnat::*;
+    pub use coupe::*;
+    pub use generateur::*;
+    pub use transferts::*;
+    pub use finances::*;
 
     pub mod scientific_domain;
     pub mod player;
     pub mod team;
     pub mod match_engine;
     pub mod power_up;
+    pub mod aleatoire;
+    pub mod simulation_spatiale;
+    pub mod capacites;
+    pub mod regles;
+    pub mod tirs_au_but;
+    pub mod coups_de_pied_arretes;
+    pub mod statistiques;
+    pub mod entraineur_ia;
+    pub mod decouvertes;
+    pub mod championnat;
+    pub mod coupe;
+    pub mod generateur;
+    pub mod transferts;
+    pub mod finances;
 }
 
 /// Interface C pour l'intégration FFI (Android NDK, iOS)
@@ -48,7 +76,13 @@ pub mod ffi {
     /// Simuler un match rapidement et retourner le résultat en JSON
     #[no_mangle]
     pub extern "C" fn sfm_simuler_match_rapide() -> *mut c_char {
-        use crate::models::{Equipe, MoteurMatch, creer_joueurs_reels};
+        sfm_simuler_match_graine(GenerateurAleatoire::graine_aleatoire())
+    }
+
+    /// Simuler un match reproductible à partir d'une graine (rapports de bug)
+    #[no_mangle]
+    pub extern "C" fn sfm_simuler_match_graine(graine: u64) -> *mut c_char {
+        use crate::models::{Equipe, MoteurMatch, RegleMatch, creer_joueurs_reels};
 
         let tous_joueurs = creer_joueurs_reels();
         let mut equipe1 = Equipe::new(1, "Équipe Rouge");
@@ -68,23 +102,11 @@ pub mod ffi {
             idx += 1;
         }
 
-        let mut moteur = MoteurMatch::nouveau(1, equipe1, equipe2);
+        let mut moteur = MoteurMatch::avec_graine(1, equipe1, equipe2, RegleMatch::default(), graine);
         moteur.demarrer();
 
-        // Simuler tout le match rapidement
-        let dt = 0.1_f32;
-        let duree = moteur.duree_match;
-        let mut temps = 0.0_f32;
-
-        while temps < duree {
-            moteur.mise_a_jour(dt);
-            temps += dt;
-
-            // Gestion de la mi-temps
-            if moteur.periode == crate::models::PeriodeMatch::MiTemps {
-                moteur.reprendre();
-            }
-        }
+        // Simuler tout le match d'un coup (prolongation comprise)
+        moteur.simuler_jusqu_a_la_fin();
 
         let resultat = serde_json::json!({
             "score_domicile": moteur.score_domicile,
@@ -92,6 +114,8 @@ pub mod ffi {
             "equipe_domicile": moteur.equipe_domicile.nom,
             "equipe_exterieur": moteur.equipe_exterieur.nom,
             "nombre_evenements": moteur.evenements.len(),
+            "statistiques": moteur.statistiques,
+            "graine": moteur.graine,
         });
 
         let json = serde_json::to_string(&resultat).unwrap_or_default();
@@ -136,7 +160,7 @@ mod tests {
             }
         }
 
-        let mut moteur = MoteurMatch::nouveau(1, equipe1, equipe2);
+        let mut moteur = MoteurMatch::nouveau(1, equipe1, equipe2, RegleMatch::default());
         moteur.demarrer();
         moteur.mise_a_jour(1.0);
         assert!(moteur.en_jeu, "Le match doit être en cours");
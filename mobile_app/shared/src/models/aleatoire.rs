@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use rand::{Error, RngCore, SeedableRng};
+
+/// Générateur pseudo-aléatoire déterministe et sérialisable (SplitMix64)
+///
+/// Contrairement à `rand::thread_rng()`, son état complet est sauvegardé avec
+/// le match : une même graine rejoue toujours exactement la même séquence.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenerateurAleatoire {
+    pub graine: u64,
+    etat: u64,
+}
+
+impl GenerateurAleatoire {
+    pub fn new(graine: u64) -> Self {
+        Self { graine, etat: graine }
+    }
+
+    /// Graine tirée au hasard (match non reproductible a priori, mais rejouable ensuite)
+    pub fn graine_aleatoire() -> u64 {
+        rand::random::<u64>()
+    }
+}
+
+impl RngCore for GenerateurAleatoire {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.etat = self.etat.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.etat;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for bloc in dest.chunks_mut(8) {
+            let octets = self.next_u64().to_le_bytes();
+            bloc.copy_from_slice(&octets[..bloc.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for GenerateurAleatoire {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(graine: u64) -> Self {
+        Self::new(graine)
+    }
+}
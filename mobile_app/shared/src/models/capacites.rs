@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use crate::models::match_engine::{EvenementMatch, MoteurMatch};
+use crate::models::player::EffetCapacite;
+use crate::models::simulation_spatiale::DEMI_LONGUEUR_TERRAIN;
+use crate::models::team::ModificateursTactiques;
+
+/// Effet de capacité spéciale en cours pendant le match
+/// (les bonus d'attributs sont portés directement par les joueurs)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EffetActif {
+    pub equipe_id: u32,
+    pub joueur_id: u32,
+    pub effet: EffetCapacite,
+    pub temps_restant: f32,
+}
+
+impl MoteurMatch {
+    /// Faire avancer les recharges et les effets, puis laisser l'IA déclencher ses capacités
+    pub(crate) fn mise_a_jour_capacites(&mut self, delta: f32) {
+        for j in self.equipe_domicile.joueurs.iter_mut().chain(self.equipe_exterieur.joueurs.iter_mut()) {
+            j.capacite_speciale.mise_a_jour(delta);
+        }
+        for e in &mut self.effets_actifs {
+            e.temps_restant -= delta;
+        }
+        self.effets_actifs.retain(|e| e.temps_restant > 0.0);
+
+        if self.capacites_auto_domicile {
+            self.declencher_capacites_ia(true);
+        }
+        if self.capacites_auto_exterieur {
+            self.declencher_capacites_ia(false);
+        }
+    }
+
+    /// Déclencher la capacité spéciale d'un joueur sur le terrain
+    pub fn utiliser_capacite(&mut self, equipe_id: u32, joueur_id: u32) -> Result<(), String> {
+        let minute = self.get_minute_actuelle();
+        let equipe = if equipe_id == self.equipe_domicile.id {
+            &mut self.equipe_domicile
+        } else if equipe_id == self.equipe_exterieur.id {
+            &mut self.equipe_exterieur
+        } else {
+            return Err("Équipe non trouvée".to_string());
+        };
+
+        let joueur = equipe.get_joueur_mut(joueur_id).ok_or("Joueur non trouvé")?;
+        if !joueur.sur_le_terrain {
+            return Err("Le joueur n'est pas sur le terrain".to_string());
+        }
+        if !joueur.capacite_speciale.utiliser() {
+            return Err("Capacité en recharge".to_string());
+        }
+        let effet = joueur.capacite_speciale.effet;
+        let nom = joueur.capacite_speciale.nom.clone();
+
+        match effet {
+            EffetCapacite::BonusJoueur { modificateurs, duree } => {
+                joueur.ajouter_bonus_temporaire(&nom, modificateurs, duree);
+            }
+            EffetCapacite::BonusEquipe { modificateurs, duree } => {
+                for j in equipe.joueurs.iter_mut().filter(|j| j.sur_le_terrain) {
+                    j.ajouter_bonus_temporaire(&nom, modificateurs, duree);
+                }
+            }
+            _ => self.effets_actifs.push(EffetActif {
+                equipe_id,
+                joueur_id,
+                effet,
+                temps_restant: effet.get_duree(),
+            }),
+        }
+
+        self.consigner(EvenementMatch::CapaciteUtilisee {
+            minute,
+            equipe_id,
+            joueur_id,
+            nom,
+        });
+        Ok(())
+    }
+
+    /// Appliquer les effets collectifs en cours d'une équipe à ses modificateurs
+    pub(crate) fn appliquer_effets_capacites(&self, equipe_id: u32, m: &mut ModificateursTactiques) {
+        for e in self.effets_actifs.iter().filter(|e| e.equipe_id == equipe_id) {
+            match e.effet {
+                EffetCapacite::EfficaciteOffensive { multiplicateur, .. } => m.qualite_tir *= multiplicateur,
+                EffetCapacite::Recuperation { multiplicateur, .. } => m.recuperation *= multiplicateur,
+                _ => {}
+            }
+        }
+    }
+
+    /// Un pare-feu de l'équipe qui défend bloque-t-il les tirs ?
+    pub fn tirs_bloques(&self, equipe_defense_id: u32) -> bool {
+        self.effets_actifs.iter()
+            .any(|e| e.equipe_id == equipe_defense_id && matches!(e.effet, EffetCapacite::BlocageTirs { .. }))
+    }
+
+    /// Multiplicateur de puissance du tir (le tir puissant est consommé)
+    pub(crate) fn consommer_tir_puissant(&mut self, tireur_id: u32) -> f32 {
+        let idx = self.effets_actifs.iter()
+            .position(|e| e.joueur_id == tireur_id && matches!(e.effet, EffetCapacite::TirPuissant { .. }));
+        match idx.map(|i| self.effets_actifs.remove(i).effet) {
+            Some(EffetCapacite::TirPuissant { multiplicateur, .. }) => multiplicateur,
+            _ => 1.0,
+        }
+    }
+
+    /// L'IA lance une capacité disponible quand la situation de jeu s'y prête
+    fn declencher_capacites_ia(&mut self, est_domicile: bool) {
+        let Some(porteur) = self.get_porteur_ballon().cloned() else { return; };
+        let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
+        let equipe_id = equipe.id;
+        let sens = if est_domicile { 1.0 } else { -1.0 };
+        let avance = sens * porteur.position_x; // > 0 : dans le camp adverse
+        let en_possession = porteur.equipe_id == equipe_id;
+
+        let candidats: Vec<(u32, EffetCapacite)> = equipe.joueurs.iter()
+            .filter(|j| j.sur_le_terrain && j.capacite_speciale.est_disponible())
+            .map(|j| (j.id, j.capacite_speciale.effet))
+            .collect();
+
+        for (joueur_id, effet) in candidats {
+            let opportun = match effet {
+                EffetCapacite::TirPuissant { .. } => porteur.joueur_id == joueur_id && avance > DEMI_LONGUEUR_TERRAIN - 25.0,
+                EffetCapacite::BonusJoueur { .. } => porteur.joueur_id == joueur_id,
+                EffetCapacite::BonusEquipe { .. } | EffetCapacite::EfficaciteOffensive { .. } => en_possession && avance > 0.0,
+                EffetCapacite::Recuperation { .. } | EffetCapacite::BlocageTirs { .. } => !en_possession && avance < 0.0,
+            };
+            if opportun {
+                let _ = self.utiliser_capacite(equipe_id, joueur_id);
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+use crate::models::team::Equipe;
+use crate::models::finances::{prime_classement, NatureOperation};
+
+/// Rencontre du calendrier, avec son score une fois jouée
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rencontre {
+    pub journee: u32,
+    pub domicile_id: u32,
+    pub exterieur_id: u32,
+    pub score: Option<(u32, u32)>,  // (domicile, extérieur)
+}
+
+impl Rencontre {
+    fn new(journee: u32, domicile_id: u32, exterieur_id: u32) -> Self {
+        Self { journee, domicile_id, exterieur_id, score: None }
+    }
+
+    pub fn est_jouee(&self) -> bool {
+        self.score.is_some()
+    }
+
+    pub fn concerne(&self, equipe_id: u32) -> bool {
+        self.domicile_id == equipe_id || self.exterieur_id == equipe_id
+    }
+}
+
+/// Ligne du classement, calculée à partir des résultats enregistrés
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LigneClassement {
+    pub equipe_id: u32,
+    pub nom: String,
+    pub victoires: u32,
+    pub nuls: u32,
+    pub defaites: u32,
+    pub buts_marques: u32,
+    pub buts_encaisses: u32,
+}
+
+impl LigneClassement {
+    fn new(equipe_id: u32, nom: &str) -> Self {
+        Self { equipe_id, nom: nom.to_string(), victoires: 0, nuls: 0, defaites: 0, buts_marques: 0, buts_encaisses: 0 }
+    }
+
+    fn ajouter_resultat(&mut self, marques: u32, encaisses: u32) {
+        self.buts_marques += marques;
+        self.buts_encaisses += encaisses;
+        match marques.cmp(&encaisses) {
+            std::cmp::Ordering::Greater => self.victoires += 1,
+            std::cmp::Ordering::Equal => self.nuls += 1,
+            std::cmp::Ordering::Less => self.defaites += 1,
+        }
+    }
+
+    pub fn joues(&self) -> u32 {
+        self.victoires + self.nuls + self.defaites
+    }
+
+    pub fn points(&self) -> u32 {
+        self.victoires * 3 + self.nuls
+    }
+
+    pub fn difference_buts(&self) -> i32 {
+        self.buts_marques as i32 - self.buts_encaisses as i32
+    }
+
+    /// Critères de départage dans l'ordre : points, différence de buts, buts marqués
+    fn criteres(&self) -> (u32, i32, u32) {
+        (self.points(), self.difference_buts(), self.buts_marques)
+    }
+}
+
+/// Classement final d'une saison terminée
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaisonArchivee {
+    pub saison: u32,
+    pub classement: Vec<LigneClassement>,
+}
+
+impl SaisonArchivee {
+    pub fn champion(&self) -> Option<&LigneClassement> {
+        self.classement.first()
+    }
+}
+
+/// Championnat en matchs aller-retour : calendrier, journées et classement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Championnat {
+    pub saison: u32,
+    pub equipes: Vec<(u32, String)>,  // (id, nom) des participants
+    pub calendrier: Vec<Rencontre>,
+    pub archives: Vec<SaisonArchivee>,
+}
+
+impl Championnat {
+    pub fn new(saison: u32, equipes: &[Equipe]) -> Self {
+        let equipes: Vec<(u32, String)> = equipes.iter().map(|e| (e.id, e.nom.clone())).collect();
+        let ids: Vec<u32> = equipes.iter().map(|(id, _)| *id).collect();
+        Self {
+            saison,
+            equipes,
+            calendrier: generer_calendrier(&ids),
+            archives: Vec::new(),
+        }
+    }
+
+    pub fn nb_journees(&self) -> u32 {
+        self.calendrier.iter().map(|r| r.journee).max().unwrap_or(0)
+    }
+
+    /// Première journée comportant encore un match à jouer
+    pub fn journee_en_cours(&self) -> Option<u32> {
+        self.prochaine_rencontre().map(|r| r.journee)
+    }
+
+    pub fn rencontres_journee(&self, journee: u32) -> Vec<&Rencontre> {
+        self.calendrier.iter().filter(|r| r.journee == journee).collect()
+    }
+
+    pub fn prochaine_rencontre(&self) -> Option<&Rencontre> {
+        self.calendrier.iter().find(|r| !r.est_jouee())
+    }
+
+    pub fn prochaine_rencontre_de(&self, equipe_id: u32) -> Option<&Rencontre> {
+        self.calendrier.iter().find(|r| !r.est_jouee() && r.concerne(equipe_id))
+    }
+
+    pub fn est_terminee(&self) -> bool {
+        !self.calendrier.is_empty() && self.calendrier.iter().all(|r| r.est_jouee())
+    }
+
+    /// Reporter le score d'un match dans la première rencontre non jouée entre ces deux équipes
+    pub fn enregistrer_resultat(&mut self, domicile_id: u32, exterieur_id: u32, buts_domicile: u32, buts_exterieur: u32) -> Result<u32, String> {
+        let rencontre = self.calendrier.iter_mut()
+            .find(|r| !r.est_jouee() && r.domicile_id == domicile_id && r.exterieur_id == exterieur_id)
+            .ok_or("Aucune rencontre à jouer entre ces deux équipes")?;
+        rencontre.score = Some((buts_domicile, buts_exterieur));
+        Ok(rencontre.journee)
+    }
+
+    /// Tableau des équipes `ids`, limité aux rencontres qu'elles ont jouées entre elles
+    fn tableau(&self, ids: &[u32]) -> Vec<LigneClassement> {
+        let mut lignes: Vec<LigneClassement> = self.equipes.iter()
+            .filter(|(id, _)| ids.contains(id))
+            .map(|(id, nom)| LigneClassement::new(*id, nom))
+            .collect();
+        for r in &self.calendrier {
+            let Some((buts_dom, buts_ext)) = r.score else { continue; };
+            if !ids.contains(&r.domicile_id) || !ids.contains(&r.exterieur_id) { continue; }
+            for ligne in lignes.iter_mut() {
+                if ligne.equipe_id == r.domicile_id {
+                    ligne.ajouter_resultat(buts_dom, buts_ext);
+                } else if ligne.equipe_id == r.exterieur_id {
+                    ligne.ajouter_resultat(buts_ext, buts_dom);
+                }
+            }
+        }
+        lignes
+    }
+
+    /// Classement : points, différence de buts, buts marqués, puis confrontations directes
+    pub fn classement(&self) -> Vec<LigneClassement> {
+        let ids: Vec<u32> = self.equipes.iter().map(|(id, _)| *id).collect();
+        let mut lignes = self.tableau(&ids);
+        lignes.sort_by_key(|l| std::cmp::Reverse(l.criteres()));
+
+        // Départager les égalités par le mini-classement des matchs entre équipes ex aequo
+        let mut debut = 0;
+        while debut < lignes.len() {
+            let criteres = lignes[debut].criteres();
+            let fin = debut + lignes[debut..].iter().take_while(|l| l.criteres() == criteres).count();
+            if fin - debut > 1 {
+                let ex_aequo: Vec<u32> = lignes[debut..fin].iter().map(|l| l.equipe_id).collect();
+                let confrontations = self.tableau(&ex_aequo);
+                let critere_direct = |id: u32| confrontations.iter()
+                    .find(|l| l.equipe_id == id)
+                    .map(|l| l.criteres())
+                    .unwrap_or_default();
+                lignes[debut..fin].sort_by(|a, b| {
+                    critere_direct(b.equipe_id).cmp(&critere_direct(a.equipe_id))
+                        .then_with(|| a.nom.cmp(&b.nom))
+                });
+            }
+            debut = fin;
+        }
+        lignes
+    }
+
+    /// Fin de saison : archiver le classement final, remettre les compteurs des équipes
+    /// à zéro et établir le calendrier de la saison suivante
+    pub fn cloturer_saison(&mut self, equipes: &mut [Equipe]) -> Result<(), String> {
+        if !self.est_terminee() {
+            return Err("La saison n'est pas terminée".to_string());
+        }
+        let classement = self.classement();
+        // Primes de fin de saison selon le rang final
+        for (rang, ligne) in classement.iter().enumerate() {
+            if let Some(equipe) = equipes.iter_mut().find(|e| e.id == ligne.equipe_id) {
+                let prime = prime_classement(classement.len(), rang + 1);
+                equipe.enregistrer_operation(self.saison, NatureOperation::Primes,
+                    format!("Prime de championnat ({}e)", rang + 1), prime as i64);
+            }
+        }
+        self.archives.push(SaisonArchivee { saison: self.saison, classement });
+
+        for equipe in equipes.iter_mut() {
+            equipe.reinitialiser_saison();
+        }
+        let archives = std::mem::take(&mut self.archives);
+        *self = Championnat::new(self.saison + 1, equipes);
+        self.archives = archives;
+        Ok(())
+    }
+}
+
+/// Calendrier aller-retour (méthode du cercle) : chaque équipe joue une fois par journée,
+/// sauf l'équipe exemptée quand le nombre d'équipes est impair
+pub fn generer_calendrier(equipes_ids: &[u32]) -> Vec<Rencontre> {
+    let mut ids: Vec<Option<u32>> = equipes_ids.iter().map(|id| Some(*id)).collect();
+    if ids.len() < 2 {
+        return Vec::new();
+    }
+    if ids.len() % 2 == 1 {
+        ids.push(None);
+    }
+    let n = ids.len();
+    let nb_tours = (n - 1) as u32;
+
+    let mut calendrier = Vec::new();
+    for tour in 0..nb_tours {
+        for i in 0..n / 2 {
+            if let (Some(a), Some(b)) = (ids[i], ids[n - 1 - i]) {
+                // Alterner domicile et extérieur d'une journée à l'autre
+                let (domicile, exterieur) = match (tour as usize + i) % 2 {
+                    0 => (a, b),
+                    _ => (b, a),
+                };
+                calendrier.push(Rencontre::new(tour + 1, domicile, exterieur));
+            }
+        }
+        ids[1..].rotate_right(1);
+    }
+
+    // Phase retour : mêmes affiches, terrains inversés
+    let retour: Vec<Rencontre> = calendrier.iter()
+        .map(|r| Rencontre::new(r.journee + nb_tours, r.exterieur_id, r.domicile_id))
+        .collect();
+    calendrier.extend(retour);
+    calendrier
+}
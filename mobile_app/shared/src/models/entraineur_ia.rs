@@ -0,0 +1,426 @@
+use serde::{Deserialize, Serialize};
+use crate::models::match_engine::{EvenementMatch, ModeSimulation, MoteurMatch};
+use crate::models::player::{Joueur, Position};
+use crate::models::team::{Equipe, Formation, InstructionsTactiques};
+
+/// Niveau de l'entraîneur IA : rapidité de réaction et finesse des choix
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NiveauDifficulte {
+    Facile,
+    #[default]
+    Normal,
+    Difficile,
+}
+
+impl NiveauDifficulte {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            NiveauDifficulte::Facile => "Facile",
+            NiveauDifficulte::Normal => "Normal",
+            NiveauDifficulte::Difficile => "Difficile",
+        }
+    }
+
+    pub fn suivant(&self) -> Self {
+        match self {
+            NiveauDifficulte::Facile => NiveauDifficulte::Normal,
+            NiveauDifficulte::Normal => NiveauDifficulte::Difficile,
+            NiveauDifficulte::Difficile => NiveauDifficulte::Facile,
+        }
+    }
+
+    /// Secondes de jeu entre deux lectures du match
+    pub fn intervalle_decision(&self) -> f32 {
+        match self {
+            NiveauDifficulte::Facile => 120.0,
+            NiveauDifficulte::Normal => 60.0,
+            NiveauDifficulte::Difficile => 20.0,
+        }
+    }
+
+    /// Part d'endurance restante sous laquelle un joueur est remplacé
+    pub fn seuil_fatigue(&self) -> f32 {
+        match self {
+            NiveauDifficulte::Facile => 0.2,
+            NiveauDifficulte::Normal => 0.35,
+            NiveauDifficulte::Difficile => 0.45,
+        }
+    }
+
+    /// Part du temps réglementaire à partir de laquelle le score dicte la tactique
+    pub fn debut_reaction(&self) -> f32 {
+        match self {
+            NiveauDifficulte::Facile => 0.85,
+            NiveauDifficulte::Normal => 0.6,
+            NiveauDifficulte::Difficile => 0.4,
+        }
+    }
+
+    /// Au-delà du niveau facile, l'IA compare les joueurs et garde ses power-ups pour le bon moment
+    pub fn reflechi(&self) -> bool {
+        *self != NiveauDifficulte::Facile
+    }
+
+    /// Seul le niveau difficile répond aux consignes de l'adversaire
+    pub fn lit_l_adversaire(&self) -> bool {
+        *self == NiveauDifficulte::Difficile
+    }
+}
+
+/// Décision d'un entraîneur pendant le match
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecisionEntraineur {
+    Remplacement { sortant_id: u32, entrant_id: u32 },
+    Formation(Formation),
+    Instructions(InstructionsTactiques),
+    PowerUp { joueur_id: u32, index: usize },
+}
+
+/// Manière de diriger une équipe en match (interchangeable)
+pub trait StrategieEntraineur {
+    /// Décisions à prendre pour l'équipe `est_domicile` à cet instant du match
+    fn decider(&self, m: &MoteurMatch, est_domicile: bool, entraineur: &EntraineurIA) -> Vec<DecisionEntraineur>;
+}
+
+/// Protège un avantage et ne prend des risques que mené au score
+#[derive(Debug, Clone, Copy)]
+pub struct EntraineurPragmatique;
+
+/// Cherche la victoire : attaque dès l'égalité et ne recule jamais
+#[derive(Debug, Clone, Copy)]
+pub struct EntraineurAudacieux;
+
+/// Stratégies fournies avec le jeu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StyleEntraineur {
+    #[default]
+    Pragmatique,
+    Audacieux,
+}
+
+impl StyleEntraineur {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            StyleEntraineur::Pragmatique => "Pragmatique",
+            StyleEntraineur::Audacieux => "Audacieux",
+        }
+    }
+
+    pub fn strategie(&self) -> &'static dyn StrategieEntraineur {
+        match self {
+            StyleEntraineur::Pragmatique => &EntraineurPragmatique,
+            StyleEntraineur::Audacieux => &EntraineurAudacieux,
+        }
+    }
+}
+
+/// Entraîneur qui dirige une équipe dans la boucle du match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntraineurIA {
+    pub niveau: NiveauDifficulte,
+    pub style: StyleEntraineur,
+    pub formation_de_base: Formation,          // Plan de jeu d'avant-match
+    pub instructions_de_base: InstructionsTactiques,
+    pub temps_avant_decision: f32,
+}
+
+impl EntraineurIA {
+    pub fn new(equipe: &Equipe, niveau: NiveauDifficulte, style: StyleEntraineur) -> Self {
+        Self {
+            niveau,
+            style,
+            formation_de_base: equipe.formation,
+            instructions_de_base: equipe.instructions.clone(),
+            temps_avant_decision: niveau.intervalle_decision(),
+        }
+    }
+}
+
+/// Situation du match vue d'un banc
+struct LectureMatch<'a> {
+    equipe: &'a Equipe,
+    adversaire: &'a Equipe,
+    ecart: i32,        // Buts d'avance (négatif si mené)
+    avancement: f32,   // Part du temps réglementaire écoulée
+}
+
+impl<'a> LectureMatch<'a> {
+    fn new(m: &'a MoteurMatch, est_domicile: bool) -> Self {
+        // Au match retour, c'est le score cumulé qui compte
+        let (cumul_d, cumul_e) = m.score_cumule();
+        let (equipe, adversaire, pour, contre) = if est_domicile {
+            (&m.equipe_domicile, &m.equipe_exterieur, cumul_d, cumul_e)
+        } else {
+            (&m.equipe_exterieur, &m.equipe_domicile, cumul_e, cumul_d)
+        };
+        Self { equipe, adversaire, ecart: pour as i32 - contre as i32, avancement: m.temps_ecoule / m.duree_match.max(1.0) }
+    }
+}
+
+fn part_stamina(j: &Joueur) -> f32 {
+    j.stamina / j.stamina_max.max(1.0)
+}
+
+/// Sortir le joueur de champ le plus fatigué s'il passe sous le seuil du niveau
+fn remplacement_fatigue(m: &MoteurMatch, est_domicile: bool, niveau: NiveauDifficulte) -> Option<DecisionEntraineur> {
+    let equipe = if est_domicile { &m.equipe_domicile } else { &m.equipe_exterieur };
+    let effectues = if est_domicile { m.remplacements_domicile } else { m.remplacements_exterieur };
+    if !m.regles.remplacement_possible(effectues) { return None; }
+
+    let fatigue = equipe.get_titulaires().into_iter()
+        .filter(|j| j.position_actuelle != Position::Gardien && part_stamina(j) < niveau.seuil_fatigue())
+        .min_by(|a, b| part_stamina(a).partial_cmp(&part_stamina(b)).unwrap_or(std::cmp::Ordering::Equal))?;
+
+    // Avec les changements volants, un joueur sorti récupère sur le banc : il doit être reposé pour revenir
+    let exclus: Vec<u32> = equipe.get_remplacants().iter()
+        .filter(|j| part_stamina(j) < niveau.seuil_fatigue() + 0.2 || (!m.regles.retour_autorise && m.joueurs_remplaces.contains(&j.id)))
+        .map(|j| j.id)
+        .collect();
+    let entrant_id = equipe.meilleur_remplacant(fatigue.position_actuelle, &exclus)?;
+    if niveau.reflechi() {
+        let entrant = equipe.get_joueur(entrant_id)?;
+        if entrant.note_globale() <= fatigue.note_globale() { return None; }
+    }
+    Some(DecisionEntraineur::Remplacement { sortant_id: fatigue.id, entrant_id })
+}
+
+/// Consignes offensives pour revenir au score : récupérer haut et frapper de loin
+fn consignes_offensives() -> InstructionsTactiques {
+    InstructionsTactiques {
+        intensite: 1.2,
+        pressing_haut: true,
+        contre_attaque: false,
+        possession: true,
+        ligne_haute: false,
+        tirs_a_distance: true,
+    }
+}
+
+/// Conserver un avantage : garder le ballon, ligne basse et contres
+fn consignes_defensives() -> InstructionsTactiques {
+    InstructionsTactiques {
+        intensite: 1.0,
+        pressing_haut: false,
+        contre_attaque: true,
+        possession: true,
+        ligne_haute: false,
+        tirs_a_distance: false,
+    }
+}
+
+/// Retoucher les consignes pour exploiter celles de l'adversaire
+fn ajuster_a_l_adversaire(instructions: &mut InstructionsTactiques, adversaire: &InstructionsTactiques) {
+    // Une ligne haute laisse de l'espace dans son dos
+    if adversaire.ligne_haute {
+        instructions.contre_attaque = true;
+        instructions.ligne_haute = false;
+    }
+    // Une équipe qui fait tourner le ballon s'expose au pressing
+    if adversaire.possession {
+        instructions.pressing_haut = true;
+    }
+    // Les contres adverses punissent une ligne haute
+    if adversaire.contre_attaque {
+        instructions.ligne_haute = false;
+    }
+}
+
+/// Changements de formation et de consignes nécessaires pour atteindre le plan voulu
+fn changements_tactiques(lecture: &LectureMatch, niveau: NiveauDifficulte, formation: Formation, mut instructions: InstructionsTactiques) -> Vec<DecisionEntraineur> {
+    if niveau.lit_l_adversaire() {
+        ajuster_a_l_adversaire(&mut instructions, &lecture.adversaire.instructions);
+    }
+    let mut decisions = Vec::new();
+    if lecture.equipe.formation != formation {
+        decisions.push(DecisionEntraineur::Formation(formation));
+    }
+    if lecture.equipe.instructions != instructions {
+        decisions.push(DecisionEntraineur::Instructions(instructions));
+    }
+    decisions
+}
+
+/// Utiliser un power-up de l'inventaire quand la situation s'y prête, sur le joueur qui en profitera le plus
+fn power_up_opportun(lecture: &LectureMatch, niveau: NiveauDifficulte) -> Option<DecisionEntraineur> {
+    let titulaires = lecture.equipe.get_titulaires();
+    let meilleur = |note: &dyn Fn(&Joueur) -> f32| titulaires.iter()
+        .filter(|j| j.bonus_temporaires.is_empty())
+        .max_by(|a, b| note(a).partial_cmp(&note(b)).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|j| j.id);
+
+    if !niveau.reflechi() {
+        // Sans calcul : le premier power-up sur le premier joueur de champ
+        let joueur_id = titulaires.iter().find(|j| j.position_actuelle != Position::Gardien)?.id;
+        return (!lecture.equipe.inventaire_power_ups.disponibles.is_empty())
+            .then_some(DecisionEntraineur::PowerUp { joueur_id, index: 0 });
+    }
+
+    let pousser = lecture.ecart < 0 || (lecture.ecart == 0 && lecture.avancement >= 0.5);
+    let tenir = lecture.ecart > 0 && lecture.avancement >= niveau.debut_reaction();
+    for (index, power_up) in lecture.equipe.inventaire_power_ups.disponibles.iter().enumerate() {
+        let modif = power_up.get_modificateurs();
+        let joueur_id = if modif.endurance > 1.0 {
+            // Recharger le plus fatigué, seulement s'il en a besoin
+            titulaires.iter()
+                .filter(|j| j.bonus_temporaires.is_empty() && part_stamina(j) < 0.5)
+                .min_by(|a, b| part_stamina(a).partial_cmp(&part_stamina(b)).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|j| j.id)
+        } else if modif.defense > 1.0 && tenir {
+            meilleur(&|j| j.stats_effectives.defense)
+        } else if modif.defense <= 1.0 && pousser {
+            meilleur(&|j| j.stats_effectives.attaque * if j.position_actuelle == Position::Gardien { 0.0 } else { 1.0 })
+        } else {
+            None
+        };
+        if let Some(joueur_id) = joueur_id {
+            return Some(DecisionEntraineur::PowerUp { joueur_id, index });
+        }
+    }
+    None
+}
+
+impl StrategieEntraineur for EntraineurPragmatique {
+    fn decider(&self, m: &MoteurMatch, est_domicile: bool, entraineur: &EntraineurIA) -> Vec<DecisionEntraineur> {
+        let niveau = entraineur.niveau;
+        let lecture = LectureMatch::new(m, est_domicile);
+        let mut decisions: Vec<DecisionEntraineur> = remplacement_fatigue(m, est_domicile, niveau).into_iter().collect();
+
+        let (formation, instructions) = if lecture.avancement < niveau.debut_reaction() || lecture.ecart == 0 {
+            (entraineur.formation_de_base, entraineur.instructions_de_base.clone())
+        } else if lecture.ecart < 0 {
+            (Formation::F112, consignes_offensives())
+        } else {
+            // Le système est conservé : un défenseur improvisé fragiliserait l'équipe
+            (entraineur.formation_de_base, consignes_defensives())
+        };
+        decisions.extend(changements_tactiques(&lecture, niveau, formation, instructions));
+        decisions.extend(power_up_opportun(&lecture, niveau));
+        decisions
+    }
+}
+
+impl StrategieEntraineur for EntraineurAudacieux {
+    fn decider(&self, m: &MoteurMatch, est_domicile: bool, entraineur: &EntraineurIA) -> Vec<DecisionEntraineur> {
+        let niveau = entraineur.niveau;
+        let lecture = LectureMatch::new(m, est_domicile);
+        let mut decisions: Vec<DecisionEntraineur> = remplacement_fatigue(m, est_domicile, niveau).into_iter().collect();
+
+        let (formation, instructions) = if lecture.avancement >= niveau.debut_reaction() && lecture.ecart <= 0 {
+            (Formation::F112, consignes_offensives())
+        } else {
+            // Même devant, on continue de presser
+            let mut instructions = entraineur.instructions_de_base.clone();
+            instructions.pressing_haut = true;
+            (entraineur.formation_de_base, instructions)
+        };
+        decisions.extend(changements_tactiques(&lecture, niveau, formation, instructions));
+        decisions.extend(power_up_opportun(&lecture, niveau));
+        decisions
+    }
+}
+
+/// Résumé des consignes pour le fil du match
+fn resume_consignes(instructions: &InstructionsTactiques) -> String {
+    let consignes: Vec<&str> = [
+        (instructions.pressing_haut, "pressing haut"),
+        (instructions.contre_attaque, "contre-attaque"),
+        (instructions.possession, "possession"),
+        (instructions.ligne_haute, "ligne haute"),
+        (instructions.tirs_a_distance, "tirs à distance"),
+    ].into_iter().filter(|(actif, _)| *actif).map(|(_, nom)| nom).collect();
+    if consignes.is_empty() {
+        format!("Consignes : bloc neutre (intensité {:.0}%)", instructions.intensite * 100.0)
+    } else {
+        format!("Consignes : {} (intensité {:.0}%)", consignes.join(", "), instructions.intensite * 100.0)
+    }
+}
+
+impl MoteurMatch {
+    /// Confier la direction d'une équipe à l'IA (changements, tactique, power-ups, capacités)
+    pub fn confier_a_l_ia(&mut self, est_domicile: bool, niveau: NiveauDifficulte, style: StyleEntraineur) {
+        let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
+        let entraineur = Some(EntraineurIA::new(equipe, niveau, style));
+        if est_domicile {
+            self.entraineur_domicile = entraineur;
+            self.capacites_auto_domicile = true;
+        } else {
+            self.entraineur_exterieur = entraineur;
+            self.capacites_auto_exterieur = true;
+        }
+    }
+
+    /// Laisser chaque entraîneur IA relire le match à son rythme
+    pub(crate) fn mise_a_jour_entraineurs(&mut self, delta: f32) {
+        for est_domicile in [true, false] {
+            let entraineur = if est_domicile { &mut self.entraineur_domicile } else { &mut self.entraineur_exterieur };
+            let Some(entraineur) = entraineur else { continue; };
+            entraineur.temps_avant_decision -= delta;
+            if entraineur.temps_avant_decision > 0.0 { continue; }
+            entraineur.temps_avant_decision += entraineur.niveau.intervalle_decision();
+
+            let entraineur = entraineur.clone();
+            let decisions = entraineur.style.strategie().decider(self, est_domicile, &entraineur);
+            self.appliquer_decisions(est_domicile, decisions);
+        }
+    }
+
+    /// Exécuter les décisions d'un entraîneur ; celles devenues impossibles sont ignorées
+    pub fn appliquer_decisions(&mut self, est_domicile: bool, decisions: Vec<DecisionEntraineur>) {
+        let equipe_id = if est_domicile { self.equipe_domicile.id } else { self.equipe_exterieur.id };
+        for decision in decisions {
+            match decision {
+                DecisionEntraineur::Remplacement { sortant_id, entrant_id } => {
+                    let _ = self.faire_substitution(equipe_id, sortant_id, entrant_id);
+                }
+                DecisionEntraineur::Formation(formation) => {
+                    self.changer_formation(est_domicile, formation);
+                }
+                DecisionEntraineur::Instructions(instructions) => {
+                    let description = resume_consignes(&instructions);
+                    let equipe = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+                    equipe.instructions = instructions;
+                    self.consigner(EvenementMatch::ChangementTactique { minute: self.get_minute_actuelle(), equipe_id, description });
+                }
+                DecisionEntraineur::PowerUp { joueur_id, index } => {
+                    let _ = self.activer_power_up(equipe_id, joueur_id, index);
+                }
+            }
+        }
+    }
+
+    /// Changer de formation en cours de match : les joueurs de champ se répartissent
+    /// les postes du nouveau système selon leur aisance
+    pub fn changer_formation(&mut self, est_domicile: bool, formation: Formation) {
+        let equipe = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+        if equipe.formation == formation { return; }
+        equipe.set_formation(formation);
+        let equipe_id = equipe.id;
+
+        // Ceux dont le poste existe toujours le conservent
+        let mut postes = formation.get_positions_requises();
+        let mut a_replacer = Vec::new();
+        for j in equipe.joueurs.iter().filter(|j| j.sur_le_terrain) {
+            match postes.iter().position(|p| *p == j.position_actuelle) {
+                Some(i) => { postes.remove(i); }
+                None => a_replacer.push(j.id),
+            }
+        }
+        postes.retain(|p| *p != Position::Gardien);
+        for joueur_id in a_replacer {
+            let Some(j) = equipe.get_joueur_mut(joueur_id) else { continue; };
+            let Some(i) = (0..postes.len())
+                .max_by(|a, b| j.familiarite.get(postes[*a]).partial_cmp(&j.familiarite.get(postes[*b])).unwrap_or(std::cmp::Ordering::Equal))
+            else { break; };
+            j.changer_position(postes.remove(i));
+        }
+
+        if self.mode_simulation == ModeSimulation::Spatiale {
+            self.replacer_bloc(est_domicile);
+        }
+        self.consigner(EvenementMatch::ChangementTactique {
+            minute: self.get_minute_actuelle(),
+            equipe_id,
+            description: format!("Passage en {}", formation.get_nom()),
+        });
+    }
+}
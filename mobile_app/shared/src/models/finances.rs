@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+use crate::models::player::Joueur;
+use crate::models::team::Equipe;
+use crate::models::transferts::{ReponseOffre, TAILLE_EFFECTIF_MIN};
+
+/// Prime versée au vainqueur de la coupe
+pub const PRIME_COUPE: u32 = 100_000;
+
+/// Contrat d'un joueur avec son club
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contrat {
+    pub salaire: u32,            // Par match officiel disputé par le club
+    pub saisons_restantes: u32,  // Saison en cours comprise
+    pub clause_liberatoire: Option<u32>,
+}
+
+impl Contrat {
+    /// Contrat de 3 saisons, salaire et clause indexés sur la valeur du joueur
+    pub fn pour_valeur(valeur: u32) -> Self {
+        Self {
+            salaire: salaire_pour_valeur(valeur),
+            saisons_restantes: 3,
+            clause_liberatoire: Some(valeur * 2),
+        }
+    }
+
+    /// Le contrat se termine à la fin de la saison en cours
+    pub fn expire(&self) -> bool {
+        self.saisons_restantes <= 1
+    }
+}
+
+/// Salaire par match correspondant à une valeur marchande (arrondi à la centaine)
+pub fn salaire_pour_valeur(valeur: u32) -> u32 {
+    (valeur / 60 / 100).max(5) * 100
+}
+
+/// Nature d'une ligne du grand livre
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NatureOperation {
+    Salaires,
+    Billetterie,
+    Primes,
+    Transfert,
+}
+
+impl NatureOperation {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            NatureOperation::Salaires => "Salaires",
+            NatureOperation::Billetterie => "Billetterie",
+            NatureOperation::Primes => "Primes",
+            NatureOperation::Transfert => "Transferts",
+        }
+    }
+}
+
+/// Ligne du grand livre d'un club (montant positif = recette)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationFinanciere {
+    pub saison: u32,
+    pub nature: NatureOperation,
+    pub libelle: String,
+    pub montant: i64,
+}
+
+/// Prime de fin de championnat selon le rang (1 = champion)
+pub fn prime_classement(nb_equipes: usize, rang: usize) -> u32 {
+    let ecart = nb_equipes.saturating_sub(rang) as u32;
+    20_000 + 180_000 * ecart / (nb_equipes.max(2) - 1) as u32
+}
+
+impl Joueur {
+    /// Salaire demandé pour prolonger, ou `None` si le joueur veut partir.
+    /// Un joueur heureux demande moins, un joueur performant davantage.
+    pub fn exigences_renouvellement(&self) -> Option<u32> {
+        if self.moral < 0.6 {
+            return None;
+        }
+        let performance = self.note_moyenne().map(|n| 1.0 + (n - 6.0) * 0.1).unwrap_or(1.0).clamp(0.8, 1.4);
+        let humeur = 1.6 - self.moral * 0.6;
+        let base = salaire_pour_valeur(self.valeur_marchande()).max(self.contrat.salaire) as f32;
+        Some(((base * performance * humeur / 100.0).round() as u32).max(5) * 100)
+    }
+}
+
+impl Equipe {
+    /// Inscrire une opération au grand livre et l'appliquer au budget
+    pub fn enregistrer_operation(&mut self, saison: u32, nature: NatureOperation, libelle: String, montant: i64) {
+        self.budget += montant;
+        self.finances.push(OperationFinanciere { saison, nature, libelle, montant });
+    }
+
+    pub fn masse_salariale(&self) -> u32 {
+        self.joueurs.iter().map(|j| j.contrat.salaire).sum()
+    }
+
+    /// Club dans le rouge : plus aucun recrutement
+    pub fn est_sous_embargo(&self) -> bool {
+        self.budget < 0
+    }
+
+    /// Total de la saison par nature d'opération
+    pub fn bilan_saison(&self, saison: u32) -> Vec<(NatureOperation, i64)> {
+        let mut bilan: Vec<(NatureOperation, i64)> = Vec::new();
+        for op in self.finances.iter().filter(|op| op.saison == saison) {
+            match bilan.iter_mut().find(|(n, _)| *n == op.nature) {
+                Some((_, total)) => *total += op.montant,
+                None => bilan.push((op.nature, op.montant)),
+            }
+        }
+        bilan
+    }
+
+    /// Salaires du match, et recette au guichet pour le club qui reçoit
+    pub fn regler_match(&mut self, saison: u32, a_domicile: bool, adversaire: &str) {
+        let salaires = self.masse_salariale() as i64;
+        self.enregistrer_operation(saison, NatureOperation::Salaires, format!("Salaires (vs {})", adversaire), -salaires);
+        if a_domicile {
+            let recette = 10_000 + (self.reputation * 30_000.0) as i64;
+            self.enregistrer_operation(saison, NatureOperation::Billetterie, format!("Recette (vs {})", adversaire), recette);
+        }
+    }
+
+    /// Négocier la prolongation d'un contrat qui arrive à échéance
+    pub fn prolonger_contrat(&mut self, joueur_id: u32, saisons: u32, salaire: u32) -> Result<ReponseOffre, String> {
+        let joueur = self.get_joueur_mut(joueur_id).ok_or("Joueur introuvable")?;
+        if !joueur.contrat.expire() {
+            return Err(format!("Le contrat de {} court encore {} saisons", joueur.prenom, joueur.contrat.saisons_restantes));
+        }
+        let Some(demande) = joueur.exigences_renouvellement() else {
+            return Ok(ReponseOffre::Refusee(format!("{} ne souhaite pas prolonger", joueur.prenom)));
+        };
+        if salaire >= demande {
+            // Saison en cours + saisons ajoutées
+            joueur.contrat = Contrat {
+                salaire,
+                saisons_restantes: joueur.contrat.saisons_restantes + saisons,
+                clause_liberatoire: Some(joueur.valeur_marchande() * 2),
+            };
+            Ok(ReponseOffre::Acceptee)
+        } else if salaire as f32 >= demande as f32 * 0.85 {
+            Ok(ReponseOffre::ContreOffre(demande))
+        } else {
+            Ok(ReponseOffre::Refusee(format!("{} demande {} par match", joueur.prenom, demande)))
+        }
+    }
+
+    /// Fin de saison : les contrats avancent d'une saison et les joueurs en fin de contrat
+    /// partent. Un club IA prolonge ses titulaires qui le veulent bien ; un effectif trop
+    /// court garde ses joueurs une saison de plus, à leurs conditions. Retourne les partants.
+    pub fn echeance_contrats(&mut self, gestion_ia: bool) -> Vec<Joueur> {
+        let mut partants = Vec::new();
+        let ids: Vec<u32> = self.joueurs.iter().map(|j| j.id).collect();
+        for id in ids {
+            let Some(joueur) = self.get_joueur(id) else { continue };
+            if !joueur.contrat.expire() {
+                if let Some(j) = self.get_joueur_mut(id) { j.contrat.saisons_restantes -= 1; }
+                continue;
+            }
+            let demande = joueur.exigences_renouvellement();
+            let prolongation = match demande {
+                Some(salaire) if gestion_ia && joueur.sur_le_terrain && !self.est_sous_embargo() => Some((salaire, 2)),
+                _ if self.joueurs.len() <= TAILLE_EFFECTIF_MIN => Some((demande.unwrap_or(joueur.contrat.salaire), 1)),
+                _ => None,
+            };
+            match prolongation {
+                Some((salaire, saisons)) => {
+                    if let Some(j) = self.get_joueur_mut(id) {
+                        let clause = Some(j.valeur_marchande() * 2);
+                        j.contrat = Contrat { salaire, saisons_restantes: saisons, clause_liberatoire: clause };
+                    }
+                }
+                None => {
+                    if let Some(mut j) = self.retirer_joueur(id) {
+                        j.sur_le_terrain = false;
+                        partants.push(j);
+                    }
+                }
+            }
+        }
+        if self.get_titulaires().len() < 5 {
+            self.selectionner_titulaires_auto();
+        }
+        partants
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::HashSet;
+use rand::Rng;
+use rand::distributions::{Distribution, WeightedIndex};
+use crate::models::aleatoire::GenerateurAleatoire;
+use crate::models::player::{ConditionTrait, EffetTrait, Joueur, PlayerStats, Position, TraitPersonnalite};
+use crate::models::power_up::{ModificateursPowerUp, TypePowerUp};
+use crate::models::scientific_domain::{DomainBonus, ScientificDomain};
+use crate::models::team::{Equipe, Formation};
+
+/// Nombre de clubs d'une ligue générée
+pub const TAILLE_LIGUE_MIN: usize = 8;
+pub const TAILLE_LIGUE_MAX: usize = 16;
+
+const DOMAINES: [ScientificDomain; 13] = [
+    ScientificDomain::Informatique,
+    ScientificDomain::PhysiqueMecanique,
+    ScientificDomain::BiologieChimie,
+    ScientificDomain::PhysiqueChimie,
+    ScientificDomain::Mathematiques,
+    ScientificDomain::Electronique,
+    ScientificDomain::BiologieMedecine,
+    ScientificDomain::Chimie,
+    ScientificDomain::MathematiquesBancaire,
+    ScientificDomain::AidesSubventions,
+    ScientificDomain::Cyberscurite,
+    ScientificDomain::ElectroniqueBancaire,
+    ScientificDomain::AgroalimentaireGeologie,
+];
+
+const POSTES: [Position; 4] = [Position::Gardien, Position::Defenseur, Position::Milieu, Position::Attaquant];
+
+const FORMATIONS: [Formation; 4] = [Formation::F121, Formation::F112, Formation::F211, Formation::F1111];
+
+const PRENOMS: [&str; 40] = [
+    "Camille", "Léa", "Hugo", "Manon", "Nathan", "Chloé", "Lucas", "Inès", "Jules", "Sarah",
+    "Maxime", "Jade", "Antoine", "Zoé", "Bastien", "Clara", "Quentin", "Alice", "Mathis", "Emma",
+    "Adrien", "Louise", "Baptiste", "Lina", "Victor", "Anaïs", "Simon", "Margaux", "Rémi", "Elsa",
+    "Karim", "Yasmine", "Tristan", "Nora", "Gaspard", "Salomé", "Olivier", "Agathe", "Samuel", "Ambre",
+];
+
+const NOMS: [&str; 40] = [
+    "Curie", "Pasteur", "Lavoisier", "Fourier", "Laplace", "Carnot", "Ampère", "Fresnel", "Cuvier", "Poincaré",
+    "Galois", "Fermat", "Pascal", "Becquerel", "Langevin", "Perrin", "Monod", "Lamarck", "Germain", "Coulomb",
+    "Lovelace", "Turing", "Noether", "Hopper", "Franklin", "Meitner", "Darwin", "Mendel", "Tesla", "Faraday",
+    "Maxwell", "Bohr", "Planck", "Kepler", "Hubble", "Euler", "Gauss", "Leibniz", "Volta", "Ohm",
+];
+
+const NOMS_CLUBS: [&str; 16] = [
+    "Les Quarks", "Les Neutrinos", "Les Photons", "Les Enzymes", "Les Algorithmes", "Les Isotopes",
+    "Les Catalyseurs", "Les Fractales", "Les Électrons", "Les Tectoniques", "Les Chromosomes", "Les Qubits",
+    "Les Pulsars", "Les Polymères", "Les Vecteurs", "Les Mitochondries",
+];
+
+const VILLES: [&str; 12] = [
+    "de Saclay", "de Grenoble", "de Lyon", "de Strasbourg", "de Toulouse", "de Marseille",
+    "de Lille", "de Rennes", "de Bordeaux", "de Nancy", "de Montpellier", "de Nantes",
+];
+
+/// Profil de poste : poids de chaque attribut, dans l'ordre de `PlayerStats::new`
+fn profil_poste(poste: Position) -> [f32; 9] {
+    //                       vit   for   pre   end   int   cre   def   att   tete
+    match poste {
+        Position::Gardien   => [0.90, 1.00, 0.95, 0.95, 1.05, 0.85, 1.20, 0.75, 1.15],
+        Position::Defenseur => [0.95, 1.10, 0.90, 1.00, 1.00, 0.85, 1.20, 0.80, 1.10],
+        Position::Milieu    => [1.00, 0.95, 1.05, 1.10, 1.10, 1.05, 0.95, 0.95, 0.90],
+        Position::Attaquant => [1.05, 0.95, 1.10, 0.95, 0.95, 1.05, 0.80, 1.20, 0.95],
+    }
+}
+
+fn multiplicateurs(bonus: &DomainBonus) -> [f32; 9] {
+    [bonus.speed, bonus.strength, bonus.precision, bonus.endurance, bonus.intelligence,
+     bonus.creativity, bonus.defense, bonus.attack, bonus.heading]
+}
+
+/// Adéquation d'un domaine à un poste (moyenne des bonus pondérée par le profil)
+fn affinite(domaine: ScientificDomain, poste: Position) -> f32 {
+    let bonus = multiplicateurs(&domaine.get_domain_bonus());
+    profil_poste(poste).iter().zip(bonus).map(|(p, b)| p * b).sum::<f32>() / 9.0
+}
+
+fn trait_genere(nom: &str, description: &str, effet: &str, effets: Vec<(ConditionTrait, EffetTrait)>) -> TraitPersonnalite {
+    TraitPersonnalite { nom: nom.to_string(), description: description.to_string(), effet: effet.to_string(), effets }
+}
+
+fn attributs(modificateurs: ModificateursPowerUp) -> Vec<(ConditionTrait, EffetTrait)> {
+    vec![(ConditionTrait::Toujours, EffetTrait::Attributs(modificateurs))]
+}
+
+/// Trait de point fort associé à l'attribut `idx`
+fn trait_point_fort(idx: usize) -> TraitPersonnalite {
+    let m = ModificateursPowerUp::default();
+    match idx {
+        0 => trait_genere("Sprinteur", "Démarre plus vite que son ombre", "Vitesse +10%",
+            attributs(ModificateursPowerUp { vitesse: 1.1, ..m })),
+        1 => trait_genere("Colosse", "Gagne la plupart des duels physiques", "Force +10%",
+            attributs(ModificateursPowerUp { force: 1.1, ..m })),
+        2 => trait_genere("Frappe Chirurgicale", "Place le ballon au millimètre", "Précision tir +15%",
+            vec![(ConditionTrait::Toujours, EffetTrait::Finition(1.15))]),
+        3 => trait_genere("Poumons d'Acier", "Ne semble jamais fatiguer", "Stamina +15%",
+            vec![(ConditionTrait::Toujours, EffetTrait::Stamina(0.85))]),
+        4 => trait_genere("Lecture du Jeu", "Trouve toujours le partenaire libre", "Passes +10%",
+            vec![(ConditionTrait::Toujours, EffetTrait::Passes(1.1))]),
+        5 => trait_genere("Improvisateur", "Invente des gestes que personne n'attend", "Créativité +15%",
+            attributs(ModificateursPowerUp { creativite: 1.15, ..m })),
+        6 => trait_genere("Sentinelle", "Coupe les lignes de passe avec méthode", "Récupération +15%",
+            vec![(ConditionTrait::Toujours, EffetTrait::Recuperation(1.15))]),
+        7 => trait_genere("Renard des Surfaces", "Se transcende quand il faut revenir au score", "Attaque +15% si mené",
+            vec![(ConditionTrait::EquipeMenee, EffetTrait::Attributs(ModificateursPowerUp { attaque: 1.15, ..m }))]),
+        _ => trait_genere("Tour de Contrôle", "Domine les airs dans les deux surfaces", "Jeu de tête +15%",
+            attributs(ModificateursPowerUp { jeu_de_tete: 1.15, ..m })),
+    }
+}
+
+/// Trait de point faible associé à l'attribut `idx`
+fn trait_point_faible(idx: usize) -> TraitPersonnalite {
+    let m = ModificateursPowerUp::default();
+    match idx {
+        0 => trait_genere("Diesel", "Long à se mettre en route", "Vitesse -10%",
+            attributs(ModificateursPowerUp { vitesse: 0.9, ..m })),
+        1 => trait_genere("Gabarit Léger", "Perd souvent les contacts", "Force -10%",
+            attributs(ModificateursPowerUp { force: 0.9, ..m })),
+        2 => trait_genere("Pied Carré", "Ses frappes partent rarement où il le voulait", "Précision tir -15%",
+            vec![(ConditionTrait::Toujours, EffetTrait::Finition(0.85))]),
+        3 => trait_genere("Souffle Court", "Se fatigue vite quand le rythme monte", "Stamina -20%",
+            vec![(ConditionTrait::Toujours, EffetTrait::Stamina(1.2))]),
+        4 => trait_genere("Distrait", "Perd le fil en fin de rencontre", "Intelligence -10% en fin de match",
+            vec![(ConditionTrait::FinDeMatch, EffetTrait::Attributs(ModificateursPowerUp { intelligence: 0.9, ..m }))]),
+        5 => trait_genere("Scolaire", "Applique les consignes sans jamais surprendre", "Créativité -10%",
+            attributs(ModificateursPowerUp { creativite: 0.9, ..m })),
+        6 => trait_genere("Défense Passoire", "Oublie souvent de revenir", "Défense -10%",
+            attributs(ModificateursPowerUp { defense: 0.9, ..m })),
+        7 => trait_genere("Timide Devant le But", "Hésite au moment de conclure", "Précision tir -10%",
+            vec![(ConditionTrait::Toujours, EffetTrait::Finition(0.9))]),
+        _ => trait_genere("Petit Gabarit", "Ne gagne presque aucun duel aérien", "Jeu de tête -15%",
+            attributs(ModificateursPowerUp { jeu_de_tete: 0.85, ..m })),
+    }
+}
+
+/// Générateur de joueurs et de clubs : une même graine recrée exactement le même monde
+#[derive(Debug, Clone)]
+pub struct GenerateurMonde {
+    pub graine: u64,
+    rng: GenerateurAleatoire,
+    pub prochain_id_joueur: u32,
+    pub prochain_id_equipe: u32,
+    noms_pris: HashSet<String>,
+}
+
+impl GenerateurMonde {
+    pub fn new(graine: u64) -> Self {
+        Self {
+            graine,
+            rng: GenerateurAleatoire::new(graine),
+            prochain_id_joueur: 1,
+            prochain_id_equipe: 1,
+            noms_pris: HashSet::new(),
+        }
+    }
+
+    /// Démarrer les identifiants après les équipes existantes et ne pas réutiliser leurs noms
+    pub fn apres(mut self, equipes: &[Equipe]) -> Self {
+        for e in equipes {
+            self.prochain_id_equipe = self.prochain_id_equipe.max(e.id + 1);
+            self.noms_pris.insert(e.nom.clone());
+            for j in &e.joueurs {
+                self.prochain_id_joueur = self.prochain_id_joueur.max(j.id + 1);
+                self.noms_pris.insert(j.prenom.clone());
+            }
+        }
+        self
+    }
+
+    fn nom_unique(&mut self, premiers: &[&str], seconds: &[&str]) -> String {
+        for _ in 0..50 {
+            let nom = format!("{} {}",
+                premiers[self.rng.gen_range(0..premiers.len())], seconds[self.rng.gen_range(0..seconds.len())]);
+            if self.noms_pris.insert(nom.clone()) {
+                return nom;
+            }
+        }
+        // Toutes les combinaisons tentées sont prises : numéroter
+        let nom = format!("{} {}", premiers[0], self.noms_pris.len());
+        self.noms_pris.insert(nom.clone());
+        nom
+    }
+
+    /// Statistiques de base orientées par le domaine et le poste, calibrées pour
+    /// que la note effective (bonus de domaine compris) soit proche de `note_cible`
+    fn generer_stats(&mut self, domaine: ScientificDomain, poste: Position, note_cible: f32) -> [f32; 9] {
+        let bonus = multiplicateurs(&domaine.get_domain_bonus());
+        let profil = profil_poste(poste);
+        let mut brut = [0.0f32; 9];
+        for i in 0..9 {
+            // Accentuer les forces du domaine sans les doubler (le bonus s'applique ensuite)
+            brut[i] = profil[i] * (1.0 + 0.5 * (bonus[i] - 1.0)) * self.rng.gen_range(0.92..1.08);
+        }
+        let effective: f32 = brut.iter().zip(bonus).map(|(s, b)| s * b).sum::<f32>() / 9.0;
+        let echelle = note_cible / effective;
+        brut.map(|s| (s * echelle).clamp(30.0, 95.0))
+    }
+
+    /// Joueur au poste donné, de domaine tiré selon son adéquation au poste
+    pub fn generer_joueur_au_poste(&mut self, poste: Position, note_cible: f32) -> Joueur {
+        let poids: Vec<f32> = DOMAINES.iter().map(|d| affinite(*d, poste).powi(8)).collect();
+        let domaine = DOMAINES[WeightedIndex::new(&poids).map(|w| w.sample(&mut self.rng)).unwrap_or(0)];
+        self.creer_joueur(domaine, poste, note_cible)
+    }
+
+    /// Joueur entièrement aléatoire : domaine, puis poste préféré selon ce domaine
+    pub fn generer_joueur(&mut self, note_cible: f32) -> Joueur {
+        let domaine = DOMAINES[self.rng.gen_range(0..DOMAINES.len())];
+        let poids: Vec<f32> = POSTES.iter().map(|p| affinite(domaine, *p).powi(8)).collect();
+        let poste = POSTES[WeightedIndex::new(&poids).map(|w| w.sample(&mut self.rng)).unwrap_or(0)];
+        self.creer_joueur(domaine, poste, note_cible)
+    }
+
+    fn creer_joueur(&mut self, domaine: ScientificDomain, poste: Position, note_cible: f32) -> Joueur {
+        let s = self.generer_stats(domaine, poste, note_cible);
+        let nom = self.nom_unique(&PRENOMS, &NOMS);
+        let id = self.prochain_id_joueur;
+        self.prochain_id_joueur += 1;
+
+        // La capacité spéciale découle du domaine
+        let mut j = Joueur::new(id, &nom, domaine, poste, PlayerStats::new(s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7], s[8]));
+        j.age = self.rng.gen_range(19..=34);
+
+        // Traits assortis au profil : l'attribut le plus au-dessus de ce qu'on attend
+        // au poste (effet du domaine et de l'aléa), parfois le plus en dessous
+        let profil = profil_poste(poste);
+        let ecart = |i: usize| s[i] / profil[i];
+        let fort = (0..9).max_by(|&a, &b| ecart(a).total_cmp(&ecart(b))).unwrap_or(0);
+        let faible = (0..9).min_by(|&a, &b| ecart(a).total_cmp(&ecart(b))).unwrap_or(0);
+        j.traits.push(trait_point_fort(fort));
+        if self.rng.gen_bool(0.6) {
+            j.traits.push(trait_point_faible(faible));
+        }
+        if self.rng.gen_bool(0.25) {
+            j.traits.push(if self.rng.gen_bool(0.5) {
+                trait_genere("Sang-Froid", "Rien ne l'atteint, ni le score ni le public", "Baisses de moral -30%",
+                    vec![(ConditionTrait::Toujours, EffetTrait::Moral(0.7))])
+            } else {
+                trait_genere("Tête Brûlée", "Va au contact sans réfléchir", "Chances carton +30%",
+                    vec![(ConditionTrait::Toujours, EffetTrait::Cartons(1.3))])
+            });
+        }
+        j.recalculer_stats_effectives();
+        j
+    }
+
+    /// Club complet (5 titulaires + 3 remplaçants) autour d'une note moyenne visée
+    pub fn generer_club(&mut self, note_cible: f32) -> Equipe {
+        let nom = self.nom_unique(&NOMS_CLUBS, &VILLES);
+        let mut equipe = Equipe::new(self.prochain_id_equipe, &nom);
+        self.prochain_id_equipe += 1;
+        equipe.formation = FORMATIONS[self.rng.gen_range(0..FORMATIONS.len())];
+
+        let mut postes = equipe.formation.get_positions_requises();
+        postes.extend([Position::Defenseur, Position::Milieu, Position::Attaquant]);
+        for (i, poste) in postes.into_iter().enumerate() {
+            // Les remplaçants sont un cran en dessous des titulaires
+            let note = note_cible + self.rng.gen_range(-5.0..5.0) - if i >= 5 { 4.0 } else { 0.0 };
+            let joueur = self.generer_joueur_au_poste(poste, note);
+            let _ = equipe.ajouter_joueur(joueur);
+        }
+        equipe.selectionner_titulaires_auto();
+
+        equipe.reputation = ((note_cible - 50.0) / 40.0).clamp(0.1, 0.95);
+        equipe.budget = (note_cible.max(0.0) as i64 / 5) * 25_000;
+        while !equipe.inventaire_power_ups.est_plein() {
+            equipe.inventaire_power_ups.ajouter(TypePowerUp::tirer(&mut self.rng));
+        }
+        equipe
+    }
+
+    /// Clubs de niveaux étalés autour de `note_moyenne` (du plus faible au plus fort ± aléa)
+    pub fn generer_clubs(&mut self, nb: usize, note_moyenne: f32) -> Vec<Equipe> {
+        (0..nb).map(|i| {
+            let rang = if nb > 1 { i as f32 / (nb - 1) as f32 - 0.5 } else { 0.0 };
+            let note = note_moyenne + rang * 16.0 + self.rng.gen_range(-2.0..2.0);
+            self.generer_club(note)
+        }).collect()
+    }
+}
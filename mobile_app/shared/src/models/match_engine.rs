@@ -1,15 +1,36 @@
 use serde::{Deserialize, Serialize};
 use rand::Rng;
-use crate::models::team::Equipe;
-use crate::models::player::Position;
+use crate::models::team::{Equipe, ModificateursTactiques};
+use crate::models::player::{Blessure, ContexteMatch, GraviteBlessure, Position, RECUPERATION_BANC, SUSPENSION_ROUGE_DIRECT};
 use crate::models::power_up::TypePowerUp;
+use crate::models::aleatoire::GenerateurAleatoire;
+use crate::models::capacites::EffetActif;
+use crate::models::regles::RegleMatch;
+use crate::models::tirs_au_but::SeanceTirsAuBut;
+use crate::models::coups_de_pied_arretes::{CoupDePiedArrete, PROBA_CARTON_FAUTE};
+use crate::models::statistiques::StatistiquesMatch;
+use crate::models::entraineur_ia::EntraineurIA;
+
+/// Part du temps réglementaire après laquelle s'activent les traits de fin de match
+const DEBUT_FIN_DE_MATCH: f32 = 0.75;
+/// Part des cartons qui sont des rouges directs
+const PROBA_ROUGE_DIRECT: f32 = 0.06;
+/// Fautes par seconde de jeu en mode probabiliste
+const PROBA_FAUTE: f32 = 0.0075;
+/// Part des tirs manqués contrés par la défense en mode probabiliste
+const PROBA_TIR_CONTRE: f32 = 0.2;
+/// Pas fixe de la simulation (s de jeu) : le résultat ne dépend ni des images par seconde
+/// ni de la vitesse d'affichage
+pub const PAS_SIMULATION: f32 = 0.1;
 
 /// Période du match
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PeriodeMatch {
     PremiereMitemps,
-    MiTemps,
-    DeuxiemeMitemps,
+    MiTemps,            // Pause entre deux périodes (ou avant la prolongation)
+    DeuxiemeMitemps,    // Périodes suivantes du temps réglementaire
+    Prolongation,
+    TirsAuBut,
     Termine,
 }
 
@@ -19,13 +40,62 @@ impl PeriodeMatch {
             PeriodeMatch::PremiereMitemps => "1ère mi-temps",
             PeriodeMatch::MiTemps => "Mi-temps",
             PeriodeMatch::DeuxiemeMitemps => "2ème mi-temps",
+            PeriodeMatch::Prolongation => "Prolongation",
+            PeriodeMatch::TirsAuBut => "Tirs au but",
             PeriodeMatch::Termine => "Terminé",
         }
     }
 }
 
+/// Compétition dans laquelle se joue le match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Competition {
+    #[default]
+    Championnat,  // Compte dans le bilan des équipes (victoires, nuls, défaites)
+    Coupe,        // Élimination directe : n'entre pas dans le bilan de saison
+}
+
+/// Mode de simulation du match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModeSimulation {
+    /// Simulation positionnelle : chaque joueur se déplace, passes, duels et tirs
+    /// découlent des positions, de la vitesse et de l'intelligence
+    Spatiale,
+    /// Ancien mode : ballon en marche aléatoire et buts tirés au sort
+    Probabiliste,
+}
+
+impl ModeSimulation {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            ModeSimulation::Spatiale => "Simulation spatiale",
+            ModeSimulation::Probabiliste => "Simulation probabiliste",
+        }
+    }
+}
+
+/// Issue d'un tir
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResultatTir {
+    But,
+    Arrete,
+    NonCadre,
+    Contre,
+}
+
+impl ResultatTir {
+    pub fn get_nom(&self) -> &'static str {
+        match self {
+            ResultatTir::But => "but",
+            ResultatTir::Arrete => "arrêté",
+            ResultatTir::NonCadre => "non cadré",
+            ResultatTir::Contre => "contré",
+        }
+    }
+}
+
 /// Événement survenu pendant le match
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EvenementMatch {
     But {
         minute: u32,
@@ -33,6 +103,7 @@ pub enum EvenementMatch {
         equipe_id: u32,
         passeur_id: Option<u32>,
         description: String,
+        coup_de_pied_arrete: Option<CoupDePiedArrete>, // But sur phase arrêtée
     },
     Substitution {
         minute: u32,
@@ -50,11 +121,27 @@ pub enum EvenementMatch {
         joueur_id: u32,
         raison: String,
     },
+    CartonRouge {
+        minute: u32,
+        equipe_id: u32,
+        joueur_id: u32,
+        raison: String,
+        second_jaune: bool,
+    },
+    Blessure {
+        minute: u32,
+        equipe_id: u32,
+        joueur_id: u32,
+        nature: String,
+        gravite: GraviteBlessure,
+        matchs_absence: u32,
+    },
     DecouverteScientifique {
         minute: u32,
         equipe_id: u32,
+        joueurs_ids: [u32; 2],  // Paire à l'origine de la découverte
         description: String,
-        bonus: f32,
+        duree: f32,             // Durée du bonus accordé à l'équipe (s)
     },
     SauvetageGardien {
         minute: u32,
@@ -65,6 +152,45 @@ pub enum EvenementMatch {
         joueur_id: u32,
         description: String,
     },
+    CapaciteUtilisee {
+        minute: u32,
+        equipe_id: u32,
+        joueur_id: u32,
+        nom: String,
+    },
+    TirAuBut {
+        minute: u32,
+        equipe_id: u32,
+        tireur_id: u32,
+        reussi: bool,
+    },
+    Tir {
+        minute: u32,
+        equipe_id: u32,
+        tireur_id: u32,
+        x: f32,                 // Position du ballon au moment du tir
+        z: f32,
+        resultat: ResultatTir,
+        xg: f32,                // Probabilité de but de l'occasion (buts attendus)
+    },
+    Faute {
+        minute: u32,
+        equipe_id: u32,         // Équipe fautive
+        fautif_id: u32,
+        victime_id: u32,
+        coup: CoupDePiedArrete, // Coup franc ou penalty
+    },
+    CoupDePiedArrete {
+        minute: u32,
+        equipe_id: u32,
+        tireur_id: u32,
+        coup: CoupDePiedArrete,
+    },
+    ChangementTactique {
+        minute: u32,
+        equipe_id: u32,
+        description: String,
+    },
 }
 
 impl EvenementMatch {
@@ -74,9 +200,17 @@ impl EvenementMatch {
             EvenementMatch::Substitution { minute, .. } => *minute,
             EvenementMatch::PowerUpUtilise { minute, .. } => *minute,
             EvenementMatch::CartonJaune { minute, .. } => *minute,
+            EvenementMatch::CartonRouge { minute, .. } => *minute,
+            EvenementMatch::Blessure { minute, .. } => *minute,
             EvenementMatch::DecouverteScientifique { minute, .. } => *minute,
             EvenementMatch::SauvetageGardien { minute, .. } => *minute,
             EvenementMatch::BelleAction { minute, .. } => *minute,
+            EvenementMatch::CapaciteUtilisee { minute, .. } => *minute,
+            EvenementMatch::TirAuBut { minute, .. } => *minute,
+            EvenementMatch::Tir { minute, .. } => *minute,
+            EvenementMatch::Faute { minute, .. } => *minute,
+            EvenementMatch::CoupDePiedArrete { minute, .. } => *minute,
+            EvenementMatch::ChangementTactique { minute, .. } => *minute,
         }
     }
 
@@ -88,15 +222,35 @@ impl EvenementMatch {
                 format!("{}' ⚡ {}", minute, type_power_up.get_nom())
             },
             EvenementMatch::CartonJaune { minute, raison, .. } => format!("{}' 🟡 {}", minute, raison),
+            EvenementMatch::CartonRouge { minute, raison, second_jaune, .. } => {
+                if *second_jaune {
+                    format!("{}' 🟡🔴 Second avertissement : {}", minute, raison)
+                } else {
+                    format!("{}' 🔴 Expulsion : {}", minute, raison)
+                }
+            },
+            EvenementMatch::Blessure { minute, nature, matchs_absence, .. } => {
+                format!("{}' 🤕 {} ({} match(s) d'absence)", minute, nature, matchs_absence)
+            },
             EvenementMatch::DecouverteScientifique { minute, description, .. } => format!("{}' 🔬 {}", minute, description),
             EvenementMatch::SauvetageGardien { minute, .. } => format!("{}' 🧤 Arrêt du gardien", minute),
             EvenementMatch::BelleAction { minute, description, .. } => format!("{}' ✨ {}", minute, description),
+            EvenementMatch::CapaciteUtilisee { minute, nom, .. } => format!("{}' 🔬 {}", minute, nom),
+            EvenementMatch::TirAuBut { minute, reussi, .. } => {
+                format!("{}' 🎯 Tir au but {}", minute, if *reussi { "réussi" } else { "manqué" })
+            },
+            EvenementMatch::Tir { minute, resultat, xg, .. } => {
+                format!("{}' 👟 Tir {} (xG {:.2})", minute, resultat.get_nom(), xg)
+            },
+            EvenementMatch::Faute { minute, coup, .. } => format!("{}' 🚫 Faute : {}", minute, coup.get_nom().to_lowercase()),
+            EvenementMatch::CoupDePiedArrete { minute, coup, .. } => format!("{}' {} {}", minute, coup.get_emoji(), coup.get_nom()),
+            EvenementMatch::ChangementTactique { minute, description, .. } => format!("{}' 📋 {}", minute, description),
         }
     }
 }
 
 /// État de position d'un joueur pendant le match
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EtatJoueurMatch {
     pub joueur_id: u32,
     pub equipe_id: u32,
@@ -107,6 +261,8 @@ pub struct EtatJoueurMatch {
     pub a_le_ballon: bool,
     pub stamina: f32,
     pub actif: bool,
+    pub base_x: f32,       // Position de référence selon le rôle
+    pub base_z: f32,
 }
 
 /// Moteur de simulation de match
@@ -117,20 +273,52 @@ pub struct MoteurMatch {
     pub equipe_exterieur: Equipe,
     pub score_domicile: u32,
     pub score_exterieur: u32,
-    pub temps_ecoule: f32,          // En secondes (0 à 1200 = 20 minutes)
-    pub duree_match: f32,
+    pub temps_ecoule: f32,          // En secondes depuis le coup d'envoi
+    pub duree_match: f32,           // Temps réglementaire (s)
+    pub regles: RegleMatch,
     pub periode: PeriodeMatch,
+    pub numero_periode: u32,        // Période réglementaire en cours (à partir de 1)
     pub evenements: Vec<EvenementMatch>,
+    pub statistiques: StatistiquesMatch,
     pub ballon_x: f32,
     pub ballon_z: f32,
     pub en_jeu: bool,
+    pub coup_d_envoi_donne: bool,   // demarrer() n'est appelé qu'une fois par match
     pub vitesse_simulation: f32,    // Multiplicateur de vitesse
+    pub(crate) temps_a_simuler: f32, // Temps reçu pas encore simulé (moins d'un pas)
     pub bonus_domicile: f32,        // Bonus scientifique de l'équipe domicile
     pub bonus_exterieur: f32,
+    pub graine: u64,                // Graine du match (à joindre aux rapports de bug)
+    pub(crate) rng: GenerateurAleatoire, // Seule source d'aléa de la simulation
+    pub mode_simulation: ModeSimulation,
+    pub etats_joueurs: Vec<EtatJoueurMatch>,
+    pub(crate) temps_avant_action: f32, // Délai avant la prochaine décision du porteur
+    pub(crate) delai_duel: f32,         // Répit après une récupération de balle
+    pub(crate) dernier_passeur_id: Option<u32>, // Pour attribuer les passes décisives
+    pub(crate) temps_possession: f32,   // Depuis la dernière récupération (contres)
+    pub effets_actifs: Vec<EffetActif>, // Capacités spéciales en cours
+    pub capacites_auto_domicile: bool,  // L'IA déclenche les capacités de l'équipe
+    pub capacites_auto_exterieur: bool,
+    pub entraineur_domicile: Option<EntraineurIA>,  // Équipe dirigée par l'IA
+    pub entraineur_exterieur: Option<EntraineurIA>,
+    pub remplacements_domicile: u32,    // Changements effectués
+    pub remplacements_exterieur: u32,
+    pub joueurs_remplaces: Vec<u32>,    // Sortis sur changement (retour selon les règles)
+    pub seance_tirs_au_but: Option<SeanceTirsAuBut>,
+    pub tireurs_choisis_domicile: Vec<u32>,  // Ordre imposé des tireurs (complété automatiquement)
+    pub tireurs_choisis_exterieur: Vec<u32>,
+    pub competition: Competition,
+    pub buts_aller: Option<(u32, u32)>,  // Match retour : buts (domicile, extérieur) du match aller
 }
 
 impl MoteurMatch {
-    pub fn nouveau(id: u32, domicile: Equipe, exterieur: Equipe) -> Self {
+    pub fn nouveau(id: u32, domicile: Equipe, exterieur: Equipe, regles: RegleMatch) -> Self {
+        Self::avec_graine(id, domicile, exterieur, regles, GenerateurAleatoire::graine_aleatoire())
+    }
+
+    /// Créer un match reproductible : même graine + mêmes compositions + mêmes
+    /// commandes = mêmes événements et même score
+    pub fn avec_graine(id: u32, domicile: Equipe, exterieur: Equipe, regles: RegleMatch, graine: u64) -> Self {
         let mut m = Self {
             id,
             equipe_domicile: domicile,
@@ -138,15 +326,41 @@ impl MoteurMatch {
             score_domicile: 0,
             score_exterieur: 0,
             temps_ecoule: 0.0,
-            duree_match: 1200.0, // 20 minutes
+            duree_match: regles.duree_reglementaire(),
+            regles,
             periode: PeriodeMatch::PremiereMitemps,
+            numero_periode: 1,
             evenements: Vec::new(),
+            statistiques: StatistiquesMatch::default(),
             ballon_x: 0.0,
             ballon_z: 0.0,
             en_jeu: false,
+            coup_d_envoi_donne: false,
             vitesse_simulation: 1.0,
+            temps_a_simuler: 0.0,
             bonus_domicile: 0.0,
             bonus_exterieur: 0.0,
+            graine,
+            rng: GenerateurAleatoire::new(graine),
+            mode_simulation: ModeSimulation::Spatiale,
+            etats_joueurs: Vec::new(),
+            temps_avant_action: 0.0,
+            delai_duel: 0.0,
+            dernier_passeur_id: None,
+            temps_possession: 0.0,
+            effets_actifs: Vec::new(),
+            capacites_auto_domicile: true,
+            capacites_auto_exterieur: true,
+            entraineur_domicile: None,
+            entraineur_exterieur: None,
+            remplacements_domicile: 0,
+            remplacements_exterieur: 0,
+            joueurs_remplaces: Vec::new(),
+            seance_tirs_au_but: None,
+            tireurs_choisis_domicile: Vec::new(),
+            tireurs_choisis_exterieur: Vec::new(),
+            competition: Competition::default(),
+            buts_aller: None,
         };
         m.calculer_bonus_scientifiques();
         m
@@ -164,6 +378,7 @@ impl MoteurMatch {
 
     pub fn demarrer(&mut self) {
         self.en_jeu = true;
+        self.coup_d_envoi_donne = true;
         self.periode = PeriodeMatch::PremiereMitemps;
 
         // Mettre les titulaires sur le terrain
@@ -177,6 +392,9 @@ impl MoteurMatch {
                 j.matchs_joues += 1;
             }
         }
+
+        // Coup d'envoi pour l'équipe à domicile
+        self.engagement(true);
     }
 
     pub fn pause(&mut self) {
@@ -185,139 +403,352 @@ impl MoteurMatch {
 
     pub fn reprendre(&mut self) {
         if self.periode == PeriodeMatch::MiTemps {
-            self.periode = PeriodeMatch::DeuxiemeMitemps;
+            if self.numero_periode < self.regles.nb_periodes {
+                self.numero_periode += 1;
+                self.periode = PeriodeMatch::DeuxiemeMitemps;
+            } else {
+                self.periode = PeriodeMatch::Prolongation;
+            }
+            // Les équipes alternent l'engagement à chaque période
+            self.engagement(self.numero_periode % 2 == 1 && self.periode != PeriodeMatch::Prolongation);
         }
         self.en_jeu = true;
     }
 
+    /// Faire avancer le match de `delta` secondes réelles, découpées en pas fixes
     pub fn mise_a_jour(&mut self, delta: f32) {
         if !self.en_jeu { return; }
 
-        let delta_ajuste = delta * self.vitesse_simulation;
-        self.temps_ecoule += delta_ajuste;
+        self.temps_a_simuler += delta * self.vitesse_simulation;
+        // Petite tolérance : une somme de deltas n'atteint pas toujours exactement le pas
+        while self.en_jeu && self.temps_a_simuler + 1e-4 >= PAS_SIMULATION {
+            self.temps_a_simuler -= PAS_SIMULATION;
+            self.avancer_d_un_pas();
+        }
+        // Pause, mi-temps ou fin : le reliquat ne déborde pas sur la reprise
+        if !self.en_jeu {
+            self.temps_a_simuler = 0.0;
+        }
+    }
 
-        // Gestion des périodes
-        if self.temps_ecoule >= 600.0 && self.periode == PeriodeMatch::PremiereMitemps {
-            self.periode = PeriodeMatch::MiTemps;
-            self.en_jeu = false;
-            return;
+    /// Résoudre instantanément la suite du match, pauses comprises, avec les mêmes pas
+    /// qu'un match affiché : même graine et mêmes commandes donnent le même résultat
+    pub fn simuler_jusqu_a_la_fin(&mut self) {
+        if !self.coup_d_envoi_donne {
+            self.demarrer();
         }
-        if self.temps_ecoule >= 1200.0 && self.periode == PeriodeMatch::DeuxiemeMitemps {
-            self.terminer_match();
+        while self.periode != PeriodeMatch::Termine {
+            if !self.en_jeu {
+                self.reprendre();
+            }
+            self.avancer_d_un_pas();
+        }
+        self.temps_a_simuler = 0.0;
+    }
+
+    fn avancer_d_un_pas(&mut self) {
+        let delta_ajuste = PAS_SIMULATION;
+        if self.periode == PeriodeMatch::TirsAuBut {
+            self.mise_a_jour_tirs_au_but(delta_ajuste);
             return;
         }
+        self.temps_ecoule += delta_ajuste;
+
+        // Gestion des périodes
+        match self.periode {
+            PeriodeMatch::PremiereMitemps | PeriodeMatch::DeuxiemeMitemps
+                if self.temps_ecoule >= self.regles.duree_periode * self.numero_periode as f32 =>
+            {
+                let egalite = self.est_a_egalite();
+                if self.numero_periode < self.regles.nb_periodes || (egalite && self.regles.prolongation.is_some()) {
+                    self.periode = PeriodeMatch::MiTemps;
+                    self.en_jeu = false;
+                    for j in self.equipe_domicile.joueurs.iter_mut().chain(self.equipe_exterieur.joueurs.iter_mut()) {
+                        j.recuperer_mi_temps();
+                    }
+                } else {
+                    self.terminer_temps_de_jeu();
+                }
+                return;
+            }
+            PeriodeMatch::Prolongation
+                if self.temps_ecoule >= self.duree_match + self.regles.prolongation.unwrap_or(0.0) =>
+            {
+                self.terminer_temps_de_jeu();
+                return;
+            }
+            _ => {}
+        }
 
         // Simulation du gameplay
         self.simuler_jeu(delta_ajuste);
+        if !self.en_jeu { return; } // But en or
 
-        // Mise à jour stamina
+        // Mise à jour stamina, familiarité des postes et bonus temporaires
         self.mise_a_jour_stamina(delta_ajuste);
+        self.mise_a_jour_familiarite(delta_ajuste);
+        self.mise_a_jour_bonus(delta_ajuste);
+        self.mise_a_jour_capacites(delta_ajuste);
+        self.mise_a_jour_entraineurs(delta_ajuste);
+        self.mise_a_jour_contexte();
 
         // Événements aléatoires
         self.generer_evenements(delta_ajuste);
     }
 
     fn simuler_jeu(&mut self, delta: f32) {
-        let mut rng = rand::thread_rng();
+        match self.mode_simulation {
+            ModeSimulation::Spatiale => self.simuler_jeu_spatial(delta),
+            ModeSimulation::Probabiliste => self.simuler_jeu_probabiliste(delta),
+        }
+    }
 
+    fn simuler_jeu_probabiliste(&mut self, delta: f32) {
         // Déplacement du ballon (simulation simplifiée)
-        let bruit_x: f32 = rng.gen_range(-1.0..1.0) * delta * 8.0;
-        let bruit_z: f32 = rng.gen_range(-1.0..1.0) * delta * 8.0;
+        let bruit_x: f32 = self.rng.gen_range(-1.0..1.0) * delta * 8.0;
+        let bruit_z: f32 = self.rng.gen_range(-1.0..1.0) * delta * 8.0;
 
         self.ballon_x = (self.ballon_x + bruit_x).clamp(-48.0, 48.0);
         self.ballon_z = (self.ballon_z + bruit_z).clamp(-23.0, 23.0);
 
+        // Ballon sorti en touche : rentrée pour l'une ou l'autre équipe
+        if self.ballon_z.abs() >= 23.0 {
+            let pour_domicile = self.rng.gen_bool(0.5);
+            let (x, z) = (self.ballon_x, self.ballon_z);
+            self.accorder_coup_de_pied_arrete(CoupDePiedArrete::RentreeDeTouche, pour_domicile, x, z);
+            self.ballon_z = 20.0 * z.signum();
+        }
+
         // Influence des équipes sur le contrôle du ballon
-        let note_d = self.equipe_domicile.note_equipe() * (1.0 + self.bonus_domicile);
-        let note_e = self.equipe_exterieur.note_equipe() * (1.0 + self.bonus_exterieur);
+        let note_d = self.note_en_match(true);
+        let note_e = self.note_en_match(false);
         let total = note_d + note_e;
 
         if total > 0.0 {
-            let possession_d = note_d / total;
+            // Le pressing fait remonter le ballon dans le camp adverse
+            let recup_d = self.get_modificateurs(true).recuperation;
+            let recup_e = self.get_modificateurs(false).recuperation;
+            let possession_d = note_d * recup_d / (note_d * recup_d + note_e * recup_e);
             // Pousser le ballon vers l'un ou l'autre camp selon la domination
             let tendance = (possession_d - 0.5) * delta * 3.0;
             self.ballon_x = (self.ballon_x + tendance).clamp(-48.0, 48.0);
+            self.statistiques.ajouter_possession(possession_d, delta);
         }
     }
 
+    /// Note d'une équipe avec ses bonus, réduite si elle joue en infériorité numérique
+    fn note_en_match(&self, est_domicile: bool) -> f32 {
+        let (equipe, bonus) = if est_domicile {
+            (&self.equipe_domicile, self.bonus_domicile)
+        } else {
+            (&self.equipe_exterieur, self.bonus_exterieur)
+        };
+        let effectif_complet = equipe.formation.get_positions_requises().len() as f32;
+        let presents = equipe.get_titulaires().len() as f32;
+        equipe.note_equipe() * (1.0 + bonus) * (presents / effectif_complet).min(1.0)
+    }
+
+    /// Modificateurs tactiques d'une équipe face aux consignes et à la formation de l'autre
+    pub fn get_modificateurs(&self, est_domicile: bool) -> ModificateursTactiques {
+        let (equipe, adversaire) = if est_domicile {
+            (&self.equipe_domicile, &self.equipe_exterieur)
+        } else {
+            (&self.equipe_exterieur, &self.equipe_domicile)
+        };
+        let mut m = equipe.instructions.modificateurs(&adversaire.instructions);
+        m.exposition *= equipe.formation.couverture_defensive();
+        m.qualite_tir *= equipe.formation.avantage_contre(&adversaire.formation);
+        self.appliquer_effets_capacites(equipe.id, &mut m);
+        m
+    }
+
     fn mise_a_jour_stamina(&mut self, delta: f32) {
-        let intensite_d = self.equipe_domicile.instructions.intensite;
-        let intensite_e = self.equipe_exterieur.instructions.intensite;
+        // ~60 points sur un match complet à intensité normale
+        let conso_d = self.get_modificateurs(true).consommation_stamina;
+        let conso_e = self.get_modificateurs(false).consommation_stamina;
+
+        for (joueurs, conso) in [(&mut self.equipe_domicile.joueurs, conso_d), (&mut self.equipe_exterieur.joueurs, conso_e)] {
+            for j in joueurs.iter_mut() {
+                if j.sur_le_terrain {
+                    let avant = j.stamina;
+                    j.consommer_stamina(0.05 * conso * delta);
+                    self.statistiques.joueur_mut(j.id).stamina_depensee += avant - j.stamina;
+                } else {
+                    // Les remplaçants récupèrent sur le banc
+                    let recuperation = RECUPERATION_BANC * j.capacite_recuperation() * delta;
+                    j.recuperer_stamina(recuperation);
+                }
+            }
+        }
+    }
 
-        for j in &mut self.equipe_domicile.joueurs {
+    fn mise_a_jour_familiarite(&mut self, delta: f32) {
+        let minutes = delta / 60.0;
+        for j in self.equipe_domicile.joueurs.iter_mut().chain(self.equipe_exterieur.joueurs.iter_mut()) {
             if j.sur_le_terrain {
-                j.consommer_stamina(1.5 * intensite_d * delta);
+                j.jouer_minutes(minutes);
+                self.statistiques.joueur_mut(j.id).minutes += minutes;
             }
         }
+    }
+
+    fn mise_a_jour_bonus(&mut self, delta: f32) {
+        for j in self.equipe_domicile.joueurs.iter_mut().chain(self.equipe_exterieur.joueurs.iter_mut()) {
+            j.mise_a_jour_bonus(delta);
+        }
+    }
+
+    /// Informer les joueurs de la situation du match (traits conditionnels)
+    fn mise_a_jour_contexte(&mut self) {
+        let fin_de_match = self.temps_ecoule >= self.duree_match * DEBUT_FIN_DE_MATCH;
+        let provocation = |equipe: &Equipe| equipe.joueurs.iter()
+            .filter(|j| j.sur_le_terrain)
+            .map(|j| j.modificateur_intelligence_adversaires())
+            .product::<f32>();
+        let contexte_d = ContexteMatch {
+            fin_de_match,
+            equipe_menee: self.score_domicile < self.score_exterieur,
+            intelligence_subie: provocation(&self.equipe_exterieur),
+        };
+        let contexte_e = ContexteMatch {
+            fin_de_match,
+            equipe_menee: self.score_exterieur < self.score_domicile,
+            intelligence_subie: provocation(&self.equipe_domicile),
+        };
+        for j in &mut self.equipe_domicile.joueurs {
+            j.changer_contexte(contexte_d);
+        }
         for j in &mut self.equipe_exterieur.joueurs {
-            if j.sur_le_terrain {
-                j.consommer_stamina(1.5 * intensite_e * delta);
-            }
+            j.changer_contexte(contexte_e);
         }
     }
 
     fn generer_evenements(&mut self, delta: f32) {
-        let mut rng = rand::thread_rng();
         let minute = self.get_minute_actuelle();
 
         // Probabilité de but (ajustée par les notes d'équipe)
-        let note_d = self.equipe_domicile.note_equipe() * (1.0 + self.bonus_domicile);
-        let note_e = self.equipe_exterieur.note_equipe() * (1.0 + self.bonus_exterieur);
-        let total = note_d + note_e;
+        let note_d = self.note_en_match(true);
+        let note_e = self.note_en_match(false);
 
+        // En mode spatial, les tirs découlent des positions (voir simulation_spatiale)
         let prob_but_base = 0.0018 * delta;
 
-        if rng.gen::<f32>() < prob_but_base {
-            let c_domicile = note_d / total;
-            if rng.gen::<f32>() < c_domicile {
+        if self.mode_simulation == ModeSimulation::Probabiliste && self.rng.gen::<f32>() < prob_but_base {
+            let (mod_d, mod_e) = (self.get_modificateurs(true), self.get_modificateurs(false));
+            let occasions_d = note_d * mod_d.recuperation * mod_d.envie_tir;
+            let occasions_e = note_e * mod_e.recuperation * mod_e.envie_tir;
+            let c_domicile = occasions_d / (occasions_d + occasions_e);
+            if self.rng.gen::<f32>() < c_domicile {
                 self.tenter_but(true, minute);
             } else {
                 self.tenter_but(false, minute);
             }
         }
 
-        // Probabilité de carton jaune
-        let prob_carton = 0.0003 * delta;
-        if rng.gen::<f32>() < prob_carton {
-            self.generer_carton(minute);
+        // En mode spatial, les fautes naissent des duels (voir simulation_spatiale)
+        let prob_faute = PROBA_FAUTE * delta;
+        if self.mode_simulation == ModeSimulation::Probabiliste && self.rng.gen::<f32>() < prob_faute {
+            self.generer_faute();
+        }
+
+        // En mode probabiliste, les contacts ne sont pas simulés : tirer un joueur au hasard
+        let prob_contact = 0.2 * delta;
+        if self.mode_simulation == ModeSimulation::Probabiliste && self.rng.gen::<f32>() < prob_contact {
+            let est_domicile = self.rng.gen_bool(0.5);
+            let (equipe, adversaire) = if est_domicile {
+                (&self.equipe_domicile, &self.equipe_exterieur)
+            } else {
+                (&self.equipe_exterieur, &self.equipe_domicile)
+            };
+            let titulaires: Vec<u32> = equipe.get_titulaires().iter().map(|j| j.id).collect();
+            let intensite = adversaire.instructions.intensite;
+            if !titulaires.is_empty() {
+                let joueur_id = titulaires[self.rng.gen_range(0..titulaires.len())];
+                self.tester_blessure(est_domicile, joueur_id, intensite);
+            }
         }
 
         // Découvertes scientifiques (boost temporaire)
         let prob_decouverte = 0.0002 * delta;
-        if rng.gen::<f32>() < prob_decouverte {
+        if self.rng.gen::<f32>() < prob_decouverte {
             self.generer_decouverte_scientifique(minute);
         }
 
         // Belle action
         let prob_belle_action = 0.0005 * delta;
-        if rng.gen::<f32>() < prob_belle_action {
+        if self.rng.gen::<f32>() < prob_belle_action {
             self.generer_belle_action(minute);
         }
     }
 
     fn tenter_but(&mut self, est_domicile: bool, minute: u32) {
-        let mut rng = rand::thread_rng();
+        let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
+
+        // Le tireur est tiré au sort selon les postes privilégiés par la formation
+        let tireurs: Vec<(u32, f32)> = equipe.joueurs.iter()
+            .filter(|j| j.sur_le_terrain)
+            .map(|j| (j.id, equipe.formation.poids_tireur(j.position_actuelle)))
+            .filter(|(_, poids)| *poids > 0.0)
+            .collect();
+
+        let total: f32 = tireurs.iter().map(|(_, poids)| poids).sum();
+        if total <= 0.0 { return; }
+
+        let mut tirage = self.rng.gen::<f32>() * total;
+        let mut tireur_id = tireurs[tireurs.len() - 1].0;
+        for (id, poids) in &tireurs {
+            if tirage < *poids {
+                tireur_id = *id;
+                break;
+            }
+            tirage -= poids;
+        }
 
+        // Chercher un passeur potentiel
+        let passeurs: Vec<u32> = equipe.joueurs.iter()
+            .filter(|j| j.sur_le_terrain && j.id != tireur_id)
+            .map(|j| j.id)
+            .collect();
+        let passeur_id = if !passeurs.is_empty() && self.rng.gen_bool(0.6) {
+            Some(passeurs[self.rng.gen_range(0..passeurs.len())])
+        } else { None };
+
+        let qualite = self.get_modificateurs(est_domicile).qualite_tir
+            * self.get_modificateurs(!est_domicile).exposition;
+        let resultat = self.resoudre_tir(est_domicile, tireur_id, passeur_id, qualite, minute, None);
+        if matches!(resultat, ResultatTir::Arrete | ResultatTir::Contre) {
+            let z = self.ballon_z;
+            self.corner_apres_tir(est_domicile, z);
+        }
+    }
+
+    /// Résoudre un tir depuis la position du ballon : `qualite` module les chances
+    /// (distance, angle...), qui donnent aussi les buts attendus (xG) de l'occasion.
+    pub(crate) fn resoudre_tir(
+        &mut self,
+        est_domicile: bool,
+        tireur_id: u32,
+        passeur_id: Option<u32>,
+        qualite: f32,
+        minute: u32,
+        coup_de_pied_arrete: Option<CoupDePiedArrete>,
+    ) -> ResultatTir {
         let (equipe, equipe_adverse, equipe_id) = if est_domicile {
             (&self.equipe_domicile, &self.equipe_exterieur, self.equipe_domicile.id)
         } else {
             (&self.equipe_exterieur, &self.equipe_domicile, self.equipe_exterieur.id)
         };
 
-        // Chercher un attaquant ou milieu disponible
-        let tireurs: Vec<u32> = equipe.joueurs.iter()
-            .filter(|j| j.sur_le_terrain && matches!(j.position_actuelle, Position::Attaquant | Position::Milieu))
-            .map(|j| j.id)
-            .collect();
-
-        if tireurs.is_empty() { return; }
-
-        let tireur_id = tireurs[rng.gen_range(0..tireurs.len())];
-        let tireur = equipe.joueurs.iter().find(|j| j.id == tireur_id).unwrap();
+        let Some(tireur) = equipe.joueurs.iter().find(|j| j.id == tireur_id) else { return ResultatTir::NonCadre; };
 
         // Calculer la probabilité de réussite
         let precision_tir = tireur.stats_effectives.precision / 100.0;
-        let attaque = tireur.stats_effectives.attaque / 100.0;
+        // Sur corner, le ballon se reprend de la tête
+        let attaque = if coup_de_pied_arrete == Some(CoupDePiedArrete::Corner) {
+            tireur.stats_effectives.jeu_de_tete / 100.0
+        } else {
+            tireur.stats_effectives.attaque / 100.0
+        };
 
         // Résistance du gardien adverse
         let gardien = equipe_adverse.joueurs.iter()
@@ -329,28 +760,64 @@ impl MoteurMatch {
             0.4 // Gardien par défaut si absent
         };
 
-        let chance_reussite = (precision_tir * 0.5 + attaque * 0.5) * (1.0 - resistance_gardien * 0.6);
+        let gardien_id = gardien.map(|g| g.id);
+        let prenom_tireur = tireur.prenom.clone();
+        let finition = tireur.modificateur_finition();
+        let equipe_defense_id = equipe_adverse.id;
 
-        if rng.gen::<f32>() < chance_reussite {
-            // BUT !
-            // Chercher un passeur potentiel
-            let passeurs: Vec<u32> = equipe.joueurs.iter()
-                .filter(|j| j.sur_le_terrain && j.id != tireur_id)
-                .map(|j| j.id)
-                .collect();
-            let passeur_id = if !passeurs.is_empty() && rng.gen_bool(0.6) {
-                Some(passeurs[rng.gen_range(0..passeurs.len())])
-            } else { None };
+        // Capacités : un tir puissant trompe plus facilement le gardien, un pare-feu bloque tout
+        let puissance = self.consommer_tir_puissant(tireur_id);
+        let bloque = self.tirs_bloques(equipe_defense_id);
+        let chance_reussite = if bloque {
+            0.0
+        } else {
+            (precision_tir * 0.5 + attaque * 0.5) * (1.0 - resistance_gardien * 0.6 / puissance) * qualite * finition
+        };
+
+        // Un tir manqué peut être contré par un défenseur dans l'axe, sinon cadré selon la précision
+        let (x, z) = (self.ballon_x, self.ballon_z);
+        let proba_contre = match (coup_de_pied_arrete, self.mode_simulation) {
+            (Some(CoupDePiedArrete::Penalty), _) => 0.0,
+            (_, ModeSimulation::Spatiale) => 1.0 - 0.8_f32.powi(self.defenseurs_sur_trajectoire(equipe_id, x, z) as i32),
+            (_, ModeSimulation::Probabiliste) => PROBA_TIR_CONTRE,
+        };
+        let resultat = if self.rng.gen::<f32>() < chance_reussite {
+            ResultatTir::But
+        } else if bloque || self.rng.gen::<f32>() < proba_contre {
+            ResultatTir::Contre
+        } else if gardien_id.is_some() && self.rng.gen::<f32>() < 0.4 + 0.4 * precision_tir {
+            ResultatTir::Arrete
+        } else {
+            ResultatTir::NonCadre
+        };
+        self.consigner(EvenementMatch::Tir {
+            minute,
+            equipe_id,
+            tireur_id,
+            x,
+            z,
+            resultat,
+            xg: chance_reussite.clamp(0.0, 1.0),
+        });
 
+        if resultat == ResultatTir::But {
+            // BUT !
             // Générer une description de but créative
-            let descriptions = [
-                format!("Magnifique frappe de {} !", tireur.prenom),
-                format!("{} conclut brillamment !", tireur.prenom),
-                format!("Quel geste technique de {} !", tireur.prenom),
-                format!("{} ne rate pas !", tireur.prenom),
-                format!("But d'anthologie de {} !", tireur.prenom),
-            ];
-            let desc = descriptions[rng.gen_range(0..descriptions.len())].clone();
+            let desc = match coup_de_pied_arrete {
+                Some(CoupDePiedArrete::Penalty) => format!("{} transforme le penalty !", prenom_tireur),
+                Some(CoupDePiedArrete::CoupFranc) => format!("Coup franc direct de {} !", prenom_tireur),
+                Some(CoupDePiedArrete::Corner) => format!("{} reprend le corner de la tête !", prenom_tireur),
+                _ => {
+                    let descriptions = [
+                        format!("Magnifique frappe de {} !", prenom_tireur),
+                        format!("{} conclut brillamment !", prenom_tireur),
+                        format!("Quel geste technique de {} !", prenom_tireur),
+                        format!("{} ne rate pas !", prenom_tireur),
+                        format!("But d'anthologie de {} !", prenom_tireur),
+                    ];
+                    descriptions[self.rng.gen_range(0..descriptions.len())].clone()
+                }
+            };
 
             if est_domicile {
                 self.score_domicile += 1;
@@ -368,7 +835,7 @@ impl MoteurMatch {
                     j.moral = (j.moral + 0.05).min(1.5);
                 }
                 for j in &mut self.equipe_exterieur.joueurs {
-                    j.moral = (j.moral - 0.05).max(0.5);
+                    j.moral = (j.moral - 0.05 * j.modificateur_moral()).max(0.5);
                 }
             } else {
                 self.score_exterieur += 1;
@@ -384,103 +851,175 @@ impl MoteurMatch {
                     j.moral = (j.moral + 0.05).min(1.5);
                 }
                 for j in &mut self.equipe_domicile.joueurs {
-                    j.moral = (j.moral - 0.05).max(0.5);
+                    j.moral = (j.moral - 0.05 * j.modificateur_moral()).max(0.5);
                 }
             }
 
-            self.evenements.push(EvenementMatch::But {
+            self.consigner(EvenementMatch::But {
                 minute,
                 buteur_id: tireur_id,
                 equipe_id,
                 passeur_id,
                 description: desc,
+                coup_de_pied_arrete,
             });
-        } else if let Some(g) = gardien {
+            if self.periode == PeriodeMatch::Prolongation && self.regles.but_en_or {
+                self.terminer_match();
+            }
+        } else if let (ResultatTir::Arrete, Some(gardien_id)) = (resultat, gardien_id) {
             // Arrêt du gardien
-            let gardien_id = g.id;
-            self.evenements.push(EvenementMatch::SauvetageGardien {
+            self.consigner(EvenementMatch::SauvetageGardien {
                 minute,
                 gardien_id,
             });
         }
+        resultat
     }
 
-    fn generer_carton(&mut self, minute: u32) {
-        let mut rng = rand::thread_rng();
-
-        // Domaine à risque = Mathématiques (Théo fait des fautes)
-        let equipes = [
-            (self.equipe_domicile.id, true),
-            (self.equipe_exterieur.id, false),
-        ];
+    /// Buts attendus (xG) cumulés par une équipe sur ses tirs (hors tirs au but)
+    pub fn xg_equipe(&self, equipe_id: u32) -> f32 {
+        self.evenements.iter()
+            .filter_map(|e| match e {
+                EvenementMatch::Tir { equipe_id: id, xg, .. } if *id == equipe_id => Some(*xg),
+                _ => None,
+            })
+            .sum()
+    }
 
-        let (_equipe_id, est_domicile) = equipes[rng.gen_range(0..equipes.len())];
+    /// Buts attendus (xG) cumulés par un joueur
+    pub fn xg_joueur(&self, joueur_id: u32) -> f32 {
+        self.evenements.iter()
+            .filter_map(|e| match e {
+                EvenementMatch::Tir { tireur_id, xg, .. } if *tireur_id == joueur_id => Some(*xg),
+                _ => None,
+            })
+            .sum()
+    }
 
-        let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
-        let joueurs_terrain: Vec<u32> = equipe.joueurs.iter()
-            .filter(|j| j.sur_le_terrain)
-            .map(|j| j.id)
-            .collect();
+    /// Faute entre deux joueurs tirés au sort (mode probabiliste)
+    fn generer_faute(&mut self) {
+        let fautif_domicile = self.rng.gen_bool(0.5);
+        let (equipe, adversaire) = if fautif_domicile {
+            (&self.equipe_domicile, &self.equipe_exterieur)
+        } else {
+            (&self.equipe_exterieur, &self.equipe_domicile)
+        };
+        let fautifs: Vec<u32> = equipe.get_titulaires().iter().map(|j| j.id).collect();
+        let victimes: Vec<u32> = adversaire.get_titulaires().iter().map(|j| j.id).collect();
+        if fautifs.is_empty() || victimes.is_empty() { return; }
 
-        if joueurs_terrain.is_empty() { return; }
+        let fautif_id = fautifs[self.rng.gen_range(0..fautifs.len())];
+        let victime_id = victimes[self.rng.gen_range(0..victimes.len())];
+        self.commettre_faute(fautif_domicile, fautif_id, victime_id);
+    }
 
-        let joueur_id = joueurs_terrain[rng.gen_range(0..joueurs_terrain.len())];
-        let equipe_mut = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+    /// Carton éventuel pour l'auteur d'une faute
+    pub(crate) fn sanctionner_faute(&mut self, fautif_domicile: bool, joueur_id: u32, minute: u32) {
+        let equipe_mut = if fautif_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+        let equipe_id = equipe_mut.id;
 
         if let Some(j) = equipe_mut.joueurs.iter_mut().find(|j| j.id == joueur_id) {
-            // Théo a moins de chances de carton (charmeur d'arbitre)
-            let reduction = if j.traits.iter().any(|t| t.nom == "Charmeur d'Arbitre") { 0.3 } else { 1.0 };
-            if rng.gen::<f32>() > reduction * 0.7 { return; }
-
-            j.cartons_jaunes += 1;
-            let raisons = [
-                "Tacle trop appuyé",
-                "Contestation de décision",
-                "Retard de jeu",
-                "Faute tactique",
-            ];
-            let raison = raisons[rng.gen_range(0..raisons.len())].to_string();
-
-            self.evenements.push(EvenementMatch::CartonJaune {
-                minute,
-                joueur_id,
-                raison,
-            });
+            // Certains traits attirent ou évitent les cartons (Théo, charmeur d'arbitre) ;
+            // un joueur déjà averti se montre plus prudent
+            let prudence = if j.cartons_jaunes_match > 0 { 0.4 } else { 1.0 };
+            if self.rng.gen::<f32>() > (PROBA_CARTON_FAUTE * j.modificateur_cartons() * prudence).min(1.0) { return; }
+
+            let rouge_direct = self.rng.gen::<f32>() < PROBA_ROUGE_DIRECT;
+            let expulse = if rouge_direct {
+                j.expulser(SUSPENSION_ROUGE_DIRECT);
+                true
+            } else {
+                j.recevoir_carton_jaune()
+            };
+
+            let raisons = if rouge_direct {
+                &["Tacle dangereux", "Faute de dernier recours", "Geste d'humeur"][..]
+            } else {
+                &["Tacle trop appuyé", "Faute tactique", "Tirage de maillot", "Charge imprudente"][..]
+            };
+            let raison = raisons[self.rng.gen_range(0..raisons.len())].to_string();
+
+            if expulse {
+                // L'équipe termine le match à un de moins
+                self.retirer_du_terrain(equipe_id, joueur_id);
+                self.consigner(EvenementMatch::CartonRouge {
+                    minute,
+                    equipe_id,
+                    joueur_id,
+                    raison,
+                    second_jaune: !rouge_direct,
+                });
+            } else {
+                self.consigner(EvenementMatch::CartonJaune {
+                    minute,
+                    joueur_id,
+                    raison,
+                });
+            }
         }
     }
 
-    fn generer_decouverte_scientifique(&mut self, minute: u32) {
-        let mut rng = rand::thread_rng();
-        let est_domicile = rng.gen_bool(0.5);
-        let equipe_id = if est_domicile { self.equipe_domicile.id } else { self.equipe_exterieur.id };
-
-        let decouvertes = [
-            ("Synergie moléculaire découverte !", 0.1),
-            ("Calcul tactique optimisé par algorithme !", 0.08),
-            ("Réaction catalytique explosive !", 0.12),
-            ("Équation du mouvement parfaitement calculée !", 0.09),
-            ("Circuit neuronal activé !", 0.07),
-        ];
-
-        let (desc, bonus) = decouvertes[rng.gen_range(0..decouvertes.len())];
-
-        // Appliquer le bonus temporaire à l'équipe
+    /// Contact subi par un joueur : il peut se blesser selon sa fatigue, sa force
+    /// et l'intensité du contact (engagement de l'équipe adverse compris)
+    pub(crate) fn tester_blessure(&mut self, est_domicile: bool, joueur_id: u32, intensite: f32) {
+        let minute = self.get_minute_actuelle();
         let equipe = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
-        for j in equipe.joueurs.iter_mut().filter(|j| j.sur_le_terrain) {
-            j.forme = (j.forme + bonus).min(1.5);
+        let equipe_id = equipe.id;
+        let Some(j) = equipe.get_joueur_mut(joueur_id) else { return; };
+        if !j.sur_le_terrain || self.rng.gen::<f32>() >= j.risque_blessure(intensite) {
+            return;
         }
 
-        self.evenements.push(EvenementMatch::DecouverteScientifique {
+        // Les contacts les plus engagés provoquent les blessures les plus graves
+        let tirage = self.rng.gen::<f32>() / intensite.max(0.5);
+        let gravite = if tirage < 0.08 {
+            GraviteBlessure::Grave
+        } else if tirage < 0.35 {
+            GraviteBlessure::Moyenne
+        } else {
+            GraviteBlessure::Legere
+        };
+        let natures = match gravite {
+            GraviteBlessure::Legere => &["Contusion", "Crampe sévère", "Cheville tordue"][..],
+            GraviteBlessure::Moyenne => &["Élongation", "Entorse", "Déchirure légère"][..],
+            GraviteBlessure::Grave => &["Déchirure musculaire", "Entorse grave du genou", "Fracture de fatigue"][..],
+        };
+        let nature = natures[self.rng.gen_range(0..natures.len())].to_string();
+        let (min, max) = gravite.duree_matchs();
+        let matchs_absence = self.rng.gen_range(min..=max);
+
+        j.blesser(Blessure {
+            nature: nature.clone(),
+            gravite,
+            matchs_restants: matchs_absence,
+            survenue_en_match: true,
+        });
+        self.consigner(EvenementMatch::Blessure {
             minute,
             equipe_id,
-            description: desc.to_string(),
-            bonus,
+            joueur_id,
+            nature,
+            gravite,
+            matchs_absence,
         });
+
+        // Le blessé est remplacé, ou son équipe finit à un de moins
+        let equipe = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+        let poste = equipe.get_joueur(joueur_id).map(|j| j.position_actuelle).unwrap_or(Position::Milieu);
+        let exclus = if self.regles.retour_autorise { &[][..] } else { &self.joueurs_remplaces[..] };
+        let remplace = equipe.meilleur_remplacant(poste, exclus)
+            .is_some_and(|entrant_id| self.faire_substitution(equipe_id, joueur_id, entrant_id).is_ok());
+        if !remplace {
+            let equipe = if est_domicile { &mut self.equipe_domicile } else { &mut self.equipe_exterieur };
+            if let Some(j) = equipe.get_joueur_mut(joueur_id) {
+                j.sur_le_terrain = false;
+            }
+            self.retirer_du_terrain(equipe_id, joueur_id);
+        }
     }
 
     fn generer_belle_action(&mut self, minute: u32) {
-        let mut rng = rand::thread_rng();
-        let est_domicile = rng.gen_bool(0.5);
+        let est_domicile = self.rng.gen_bool(0.5);
         let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
 
         let joueurs_terrain: Vec<u32> = equipe.joueurs.iter()
@@ -489,7 +1028,7 @@ impl MoteurMatch {
             .collect();
 
         if joueurs_terrain.is_empty() { return; }
-        let joueur_id = joueurs_terrain[rng.gen_range(0..joueurs_terrain.len())];
+        let joueur_id = joueurs_terrain[self.rng.gen_range(0..joueurs_terrain.len())];
 
         let actions = [
             "Dribble dévastateur !",
@@ -499,20 +1038,43 @@ impl MoteurMatch {
             "Reprise de volée technique !",
             "Passe en profondeur parfaite !",
         ];
-        let desc = actions[rng.gen_range(0..actions.len())].to_string();
+        let desc = actions[self.rng.gen_range(0..actions.len())].to_string();
 
-        self.evenements.push(EvenementMatch::BelleAction {
+        self.consigner(EvenementMatch::BelleAction {
             minute,
             joueur_id,
             description: desc,
         });
     }
 
-    fn terminer_match(&mut self) {
+    /// Fin du temps de jeu : tirs au but en cas d'égalité si les règles le prévoient
+    fn terminer_temps_de_jeu(&mut self) {
+        if self.est_a_egalite() && self.regles.tirs_au_but {
+            self.demarrer_tirs_au_but();
+        } else {
+            self.terminer_match();
+        }
+    }
+
+    pub(crate) fn terminer_match(&mut self) {
         self.en_jeu = false;
         self.periode = PeriodeMatch::Termine;
 
-        // Mettre à jour les statistiques des équipes
+        // Les matchs de coupe n'entrent pas dans le bilan de saison
+        if self.competition == Competition::Championnat {
+            self.comptabiliser_bilan();
+        }
+
+        self.attribuer_notes();
+
+        // Suspensions : purgées par ceux qui ont manqué ce match, effectives pour les sanctionnés
+        for j in self.equipe_domicile.joueurs.iter_mut().chain(self.equipe_exterieur.joueurs.iter_mut()) {
+            j.cloturer_match();
+        }
+    }
+
+    /// Reporter le résultat dans les statistiques de saison des équipes
+    fn comptabiliser_bilan(&mut self) {
         match self.score_domicile.cmp(&self.score_exterieur) {
             std::cmp::Ordering::Greater => {
                 self.equipe_domicile.victoires += 1;
@@ -534,6 +1096,17 @@ impl MoteurMatch {
         self.equipe_exterieur.buts_encaisses += self.score_domicile;
     }
 
+    /// Score cumulé (domicile, extérieur), match aller compris
+    pub fn score_cumule(&self) -> (u32, u32) {
+        let (aller_d, aller_e) = self.buts_aller.unwrap_or((0, 0));
+        (self.score_domicile + aller_d, self.score_exterieur + aller_e)
+    }
+
+    fn est_a_egalite(&self) -> bool {
+        let (d, e) = self.score_cumule();
+        d == e
+    }
+
     pub fn get_minute_actuelle(&self) -> u32 {
         (self.temps_ecoule / 60.0) as u32
     }
@@ -544,9 +1117,25 @@ impl MoteurMatch {
         format!("{:02}:{:02}", minutes, secondes)
     }
 
+    /// Nom de la période en cours, numérotée si le match n'est pas en deux mi-temps
+    pub fn get_nom_periode(&self) -> String {
+        match self.periode {
+            PeriodeMatch::PremiereMitemps | PeriodeMatch::DeuxiemeMitemps if self.regles.nb_periodes != 2 => {
+                format!("Période {}/{}", self.numero_periode, self.regles.nb_periodes)
+            }
+            PeriodeMatch::MiTemps if self.regles.nb_periodes != 2 => "Pause".to_string(),
+            periode => periode.get_nom().to_string(),
+        }
+    }
+
     pub fn get_vainqueur(&self) -> Option<u32> {
         if self.periode != PeriodeMatch::Termine { return None; }
-        match self.score_domicile.cmp(&self.score_exterieur) {
+        // À égalité (sur l'ensemble des deux matchs), la séance de tirs au but départage les équipes
+        let (score_d, score_e) = match &self.seance_tirs_au_but {
+            Some(seance) if self.est_a_egalite() => seance.score(),
+            _ => self.score_cumule(),
+        };
+        match score_d.cmp(&score_e) {
             std::cmp::Ordering::Greater => Some(self.equipe_domicile.id),
             std::cmp::Ordering::Less => Some(self.equipe_exterieur.id),
             std::cmp::Ordering::Equal => None,
@@ -554,14 +1143,29 @@ impl MoteurMatch {
     }
 
     pub fn get_score_affichage(&self) -> String {
-        format!("{} - {}", self.score_domicile, self.score_exterieur)
+        let score = match &self.seance_tirs_au_but {
+            Some(seance) => {
+                let (tab_d, tab_e) = seance.score();
+                format!("{} - {} ({} - {} t.a.b.)", self.score_domicile, self.score_exterieur, tab_d, tab_e)
+            }
+            None => format!("{} - {}", self.score_domicile, self.score_exterieur),
+        };
+        match self.buts_aller {
+            Some(_) => {
+                let (cumul_d, cumul_e) = self.score_cumule();
+                format!("{} [cumul {} - {}]", score, cumul_d, cumul_e)
+            }
+            None => score,
+        }
     }
 
     pub fn get_evenements_recents(&self, n: usize) -> Vec<&EvenementMatch> {
         self.evenements.iter().rev().take(n).collect()
     }
 
-    pub fn faire_substitution(&mut self, equipe_id: u32, sortant_id: u32, entrant_id: u32) -> Result<(), String> {
+    /// Activer le power-up n° `index` de l'inventaire de l'équipe sur un joueur du terrain
+    pub fn activer_power_up(&mut self, equipe_id: u32, joueur_id: u32, index: usize) -> Result<TypePowerUp, String> {
+        let minute = self.get_minute_actuelle();
         let equipe = if equipe_id == self.equipe_domicile.id {
             &mut self.equipe_domicile
         } else if equipe_id == self.equipe_exterieur.id {
@@ -570,9 +1174,52 @@ impl MoteurMatch {
             return Err("Équipe non trouvée".to_string());
         };
 
+        let joueur = equipe.get_joueur(joueur_id).ok_or("Joueur non trouvé")?;
+        if !joueur.sur_le_terrain {
+            return Err("Le joueur n'est pas sur le terrain".to_string());
+        }
+        let type_power_up = equipe.inventaire_power_ups.utiliser(index)
+            .ok_or("Power-up non disponible")?;
+
+        if let Some(joueur) = equipe.get_joueur_mut(joueur_id) {
+            joueur.ajouter_bonus_temporaire(type_power_up.get_nom(), type_power_up.get_modificateurs(), type_power_up.get_duree());
+        }
+
+        self.consigner(EvenementMatch::PowerUpUtilise {
+            minute,
+            joueur_id,
+            type_power_up,
+        });
+
+        Ok(type_power_up)
+    }
+
+    pub fn faire_substitution(&mut self, equipe_id: u32, sortant_id: u32, entrant_id: u32) -> Result<(), String> {
+        let (equipe, effectues) = if equipe_id == self.equipe_domicile.id {
+            (&mut self.equipe_domicile, &mut self.remplacements_domicile)
+        } else if equipe_id == self.equipe_exterieur.id {
+            (&mut self.equipe_exterieur, &mut self.remplacements_exterieur)
+        } else {
+            return Err("Équipe non trouvée".to_string());
+        };
+
+        if !self.regles.remplacement_possible(*effectues) {
+            return Err("Plus de remplacement autorisé".to_string());
+        }
+        if !self.regles.retour_autorise && self.joueurs_remplaces.contains(&entrant_id) {
+            return Err("Un joueur remplacé ne peut pas revenir en jeu".to_string());
+        }
+
         equipe.faire_substitution(sortant_id, entrant_id)?;
+        *effectues += 1;
+        self.joueurs_remplaces.push(sortant_id);
+
+        // Le remplaçant reprend la place du sortant sur le terrain
+        if let Some(etat) = self.etats_joueurs.iter_mut().find(|e| e.joueur_id == sortant_id) {
+            etat.joueur_id = entrant_id;
+        }
 
-        self.evenements.push(EvenementMatch::Substitution {
+        self.consigner(EvenementMatch::Substitution {
             minute: self.get_minute_actuelle(),
             equipe_id,
             sortant_id,
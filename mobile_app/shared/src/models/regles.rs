@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+/// Politique de remplacements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Remplacements {
+    Illimites,    // Changements volants
+    Limites(u32), // Nombre maximum de changements par équipe
+}
+
+/// Règles d'un match (durée, remplacements, prolongation)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RegleMatch {
+    pub duree_periode: f32,         // En secondes
+    pub nb_periodes: u32,
+    pub remplacements: Remplacements,
+    pub retour_autorise: bool,      // Un joueur remplacé peut-il revenir en jeu ?
+    pub prolongation: Option<f32>,  // Durée (s) jouée en cas d'égalité, sinon match nul
+    pub but_en_or: bool,            // Le premier but de la prolongation termine le match
+    pub tirs_au_but: bool,          // Séance de tirs au but si l'égalité persiste
+}
+
+impl Default for RegleMatch {
+    /// Match amical : 2×10 minutes, changements volants
+    fn default() -> Self {
+        Self {
+            duree_periode: 600.0,
+            nb_periodes: 2,
+            remplacements: Remplacements::Illimites,
+            retour_autorise: true,
+            prolongation: None,
+            but_en_or: false,
+            tirs_au_but: false,
+        }
+    }
+}
+
+impl RegleMatch {
+    /// Nos vraies sessions de 5v5 : 2×25 minutes, changements volants
+    pub fn session_5v5() -> Self {
+        Self {
+            duree_periode: 1500.0,
+            ..Self::default()
+        }
+    }
+
+    /// Soirée de coupe : 2×10 minutes, il faut un vainqueur (prolongation, but en or, tirs au but)
+    pub fn soiree_coupe() -> Self {
+        Self {
+            prolongation: Some(300.0),
+            but_en_or: true,
+            tirs_au_but: true,
+            ..Self::default()
+        }
+    }
+
+    /// Match de coupe : l'aller peut finir à égalité, le match décisif doit désigner un vainqueur
+    pub fn pour_match_de_coupe(&self, decisif: bool) -> Self {
+        if decisif {
+            let coupe = Self::soiree_coupe();
+            Self {
+                prolongation: self.prolongation.or(coupe.prolongation),
+                but_en_or: self.but_en_or || coupe.but_en_or,
+                tirs_au_but: true,
+                ..*self
+            }
+        } else {
+            Self { prolongation: None, but_en_or: false, tirs_au_but: false, ..*self }
+        }
+    }
+
+    /// Durée du temps réglementaire (s)
+    pub fn duree_reglementaire(&self) -> f32 {
+        self.duree_periode * self.nb_periodes as f32
+    }
+
+    pub fn valider(&self) -> Result<(), String> {
+        if self.nb_periodes == 0 || self.duree_periode <= 0.0 {
+            return Err("Le match doit comporter au moins une période".to_string());
+        }
+        if self.prolongation.is_some_and(|d| d <= 0.0) {
+            return Err("La prolongation doit avoir une durée".to_string());
+        }
+        Ok(())
+    }
+
+    /// Une équipe ayant déjà effectué `effectues` changements peut-elle en faire un autre ?
+    pub fn remplacement_possible(&self, effectues: u32) -> bool {
+        match self.remplacements {
+            Remplacements::Illimites => true,
+            Remplacements::Limites(max) => effectues < max,
+        }
+    }
+}
@@ -55,6 +55,14 @@ impl ScientificDomain {
         }
     }
 
+    /// Multiplicateur de la récupération physique (mi-temps, banc, jours de repos)
+    pub fn get_recovery_multiplier(&self) -> f32 {
+        match self {
+            ScientificDomain::BiologieMedecine => 1.3, // Récupération rapide
+            _ => 1.0,
+        }
+    }
+
     /// Bonus spécifique au domaine scientifique (multiplicateurs sur les attributs)
     pub fn get_domain_bonus(&self) -> DomainBonus {
         match self {
@@ -0,0 +1,567 @@
+use rand::Rng;
+use crate::models::coups_de_pied_arretes::{dans_la_surface, CoupDePiedArrete, IssueCoupArrete};
+use crate::models::match_engine::{EtatJoueurMatch, MoteurMatch, ResultatTir};
+use crate::models::player::{Joueur, Position};
+use crate::models::team::ModificateursTactiques;
+
+/// Demi-longueur du terrain : les buts sont en x = ±50
+pub const DEMI_LONGUEUR_TERRAIN: f32 = 50.0;
+/// Demi-largeur du terrain : les lignes de touche sont en z = ±25
+pub const DEMI_LARGEUR_TERRAIN: f32 = 25.0;
+
+const DISTANCE_DUEL: f32 = 2.0;        // Distance à laquelle un défenseur peut tacler
+const DELAI_APRES_RECUPERATION: f32 = 1.5;
+const LIMITE_APPEL: f32 = 38.0;        // Profondeur maximale d'un appel sans ballon
+const DUREE_CONTRE: f32 = 8.0;         // Fenêtre (s) après une récupération où l'on « contre »
+const PROBA_FAUTE_DUEL: f32 = 0.015;   // Fautes par seconde de duel, à intensité normale
+const PROBA_SORTIE_EN_TOUCHE: f32 = 0.35; // Passe ratée non coupée qui sort du terrain
+
+impl MoteurMatch {
+    /// Positions de référence des joueurs d'une équipe selon sa formation
+    fn positions_de_base(&self, est_domicile: bool) -> Vec<(u32, f32, f32)> {
+        let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
+        let titulaires: Vec<&Joueur> = equipe.joueurs.iter().filter(|j| j.sur_le_terrain).collect();
+
+        titulaires.iter().map(|joueur| {
+            // Répartir en largeur les joueurs qui partagent le même rôle
+            let meme_role: Vec<u32> = titulaires.iter()
+                .filter(|j| j.position_actuelle == joueur.position_actuelle)
+                .map(|j| j.id)
+                .collect();
+            let rang = meme_role.iter().position(|id| *id == joueur.id).unwrap_or(0);
+            let (x, z) = equipe.formation.position_de_base(joueur.position_actuelle, rang, meme_role.len());
+            let (x, z) = if est_domicile { (x, z) } else { (-x, -z) };
+            (joueur.id, x, z)
+        }).collect()
+    }
+
+    /// Replacer tous les joueurs sur leur position de référence
+    pub(crate) fn initialiser_positions(&mut self) {
+        self.etats_joueurs.clear();
+
+        for est_domicile in [true, false] {
+            let equipe = if est_domicile { &self.equipe_domicile } else { &self.equipe_exterieur };
+            let equipe_id = equipe.id;
+            let staminas: Vec<f32> = equipe.joueurs.iter().filter(|j| j.sur_le_terrain).map(|j| j.stamina).collect();
+
+            for ((joueur_id, x, z), stamina) in self.positions_de_base(est_domicile).into_iter().zip(staminas) {
+                self.etats_joueurs.push(EtatJoueurMatch {
+                    joueur_id,
+                    equipe_id,
+                    position_x: x,
+                    position_z: z,
+                    velocite_x: 0.0,
+                    velocite_z: 0.0,
+                    a_le_ballon: false,
+                    stamina,
+                    actif: true,
+                    base_x: x,
+                    base_z: z,
+                });
+            }
+        }
+    }
+
+    /// Changement de système en cours de jeu : nouvelles positions de référence,
+    /// les joueurs les rejoignent d'eux-mêmes
+    pub(crate) fn replacer_bloc(&mut self, est_domicile: bool) {
+        for (joueur_id, x, z) in self.positions_de_base(est_domicile) {
+            if let Some(e) = self.etats_joueurs.iter_mut().find(|e| e.joueur_id == joueur_id) {
+                e.base_x = x;
+                e.base_z = z;
+            }
+        }
+    }
+
+    /// Coup d'envoi : positions de départ et ballon au joueur le plus proche du rond central
+    pub(crate) fn engagement(&mut self, pour_domicile: bool) {
+        self.initialiser_positions();
+        self.ballon_x = 0.0;
+        self.ballon_z = 0.0;
+        self.dernier_passeur_id = None;
+        self.temps_avant_action = 1.0;
+        self.delai_duel = DELAI_APRES_RECUPERATION;
+
+        let equipe_id = if pour_domicile { self.equipe_domicile.id } else { self.equipe_exterieur.id };
+        let engageur = self.etats_joueurs.iter()
+            .enumerate()
+            .filter(|(_, e)| e.equipe_id == equipe_id)
+            .min_by(|(_, a), (_, b)| a.position_x.abs().partial_cmp(&b.position_x.abs()).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(idx, _)| idx);
+
+        if let Some(idx) = engageur {
+            let etat = &mut self.etats_joueurs[idx];
+            etat.position_x = 0.0;
+            etat.position_z = 0.0;
+            etat.a_le_ballon = true;
+        }
+    }
+
+    /// Retirer un joueur expulsé : son équipe continue à un de moins
+    pub(crate) fn retirer_du_terrain(&mut self, equipe_id: u32, joueur_id: u32) {
+        let Some(idx) = self.etats_joueurs.iter().position(|e| e.equipe_id == equipe_id && e.joueur_id == joueur_id) else { return; };
+        let etat = self.etats_joueurs.remove(idx);
+        if etat.a_le_ballon {
+            // Faute du porteur : ballon à l'adversaire le plus proche
+            if let Some(adv) = self.adversaire_le_plus_proche(etat.equipe_id, etat.position_x, etat.position_z) {
+                self.donner_ballon(adv);
+            }
+        }
+    }
+
+    pub(crate) fn simuler_jeu_spatial(&mut self, delta: f32) {
+        if self.etats_joueurs.is_empty() {
+            self.engagement(true);
+        }
+        if self.etats_joueurs.iter().all(|e| !e.a_le_ballon) {
+            let pour_domicile = self.ballon_x <= 0.0;
+            self.engagement(pour_domicile);
+        }
+
+        self.temps_possession += delta;
+        self.deplacer_joueurs(delta);
+
+        // Le ballon suit son porteur
+        if let Some(porteur) = self.etats_joueurs.iter().find(|e| e.a_le_ballon) {
+            self.ballon_x = porteur.position_x;
+            self.ballon_z = porteur.position_z;
+            let part_domicile = if porteur.equipe_id == self.equipe_domicile.id { 1.0 } else { 0.0 };
+            self.statistiques.ajouter_possession(part_domicile, delta);
+        }
+
+        self.delai_duel = (self.delai_duel - delta).max(0.0);
+        if self.delai_duel <= 0.0 {
+            self.resoudre_duel(delta);
+        }
+
+        self.temps_avant_action -= delta;
+        if self.temps_avant_action <= 0.0 {
+            self.decider_action_porteur();
+        }
+    }
+
+    fn deplacer_joueurs(&mut self, delta: f32) {
+        let Some(porteur_idx) = self.etats_joueurs.iter().position(|e| e.a_le_ballon) else { return; };
+        let equipe_porteuse = self.etats_joueurs[porteur_idx].equipe_id;
+        let (bx, bz) = (self.ballon_x, self.ballon_z);
+
+        // Le défenseur (hors gardien) le plus proche du ballon part au pressing,
+        // dès la perte du ballon si l'équipe presse haut, sinon une fois le ballon dans son camp
+        let equipe_defense_domicile = equipe_porteuse != self.equipe_domicile.id;
+        let presse_haut = self.get_modificateurs(equipe_defense_domicile).pressing_haut;
+        let ballon_dans_notre_camp = if equipe_defense_domicile { bx < 5.0 } else { bx > -5.0 };
+        let presseur_idx = self.etats_joueurs.iter()
+            .enumerate()
+            .filter(|_| presse_haut || ballon_dans_notre_camp)
+            .filter(|(_, e)| e.actif && e.equipe_id != equipe_porteuse && !self.est_gardien(e.joueur_id))
+            .min_by(|(_, a), (_, b)| {
+                distance(a.position_x, a.position_z, bx, bz)
+                    .partial_cmp(&distance(b.position_x, b.position_z, bx, bz))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(idx, _)| idx);
+
+        let mut cibles: Vec<(f32, f32, f32)> = self.etats_joueurs.iter()
+            .enumerate()
+            .map(|(idx, e)| {
+                let sens = self.sens_attaque(e.equipe_id);
+                let hauteur_bloc = self.modificateurs_equipe(e.equipe_id).hauteur_bloc;
+                let (cx, cz) = if self.est_gardien(e.joueur_id) {
+                    // Le gardien reste sur sa ligne et suit le ballon en largeur
+                    (e.base_x, (bz * 0.3).clamp(-4.0, 4.0))
+                } else if e.a_le_ballon {
+                    // Le porteur avance vers le but adverse en restant dans son couloir
+                    (sens * (DEMI_LONGUEUR_TERRAIN - 10.0), e.position_z * 0.6)
+                } else if Some(idx) == presseur_idx {
+                    (bx, bz)
+                } else {
+                    // Le bloc coulisse avec le ballon, monte quand l'équipe a la possession
+                    // et, quand elle défend, resserre l'axe à la hauteur demandée par la tactique
+                    let (decalage, resserrement) = if e.equipe_id == equipe_porteuse {
+                        (8.0, 0.25)
+                    } else {
+                        (hauteur_bloc - 3.0, 0.6)
+                    };
+                    // Personne n'attend le ballon collé au gardien adverse
+                    let x = (e.base_x + bx * 0.4 + sens * decalage).clamp(-LIMITE_APPEL, LIMITE_APPEL);
+                    (x, e.base_z + (bz - e.base_z) * resserrement)
+                };
+                // Conduire le ballon ralentit
+                let vitesse_max = self.get_joueur(e.joueur_id)
+                    .map(vitesse_de_course)
+                    .unwrap_or(4.0) * if e.a_le_ballon { 0.8 } else { 1.0 };
+                (cx, cz, vitesse_max)
+            })
+            .collect();
+
+        self.marquer_adversaires(&mut cibles, equipe_porteuse, presseur_idx);
+
+        for (etat, (cx, cz, vitesse_max)) in self.etats_joueurs.iter_mut().zip(cibles) {
+            if !etat.actif { continue; }
+            let cx = cx.clamp(-DEMI_LONGUEUR_TERRAIN + 1.0, DEMI_LONGUEUR_TERRAIN - 1.0);
+            let cz = cz.clamp(-DEMI_LARGEUR_TERRAIN + 1.0, DEMI_LARGEUR_TERRAIN - 1.0);
+            let dx = cx - etat.position_x;
+            let dz = cz - etat.position_z;
+            let dist = (dx * dx + dz * dz).sqrt();
+            if dist < 0.05 {
+                etat.velocite_x = 0.0;
+                etat.velocite_z = 0.0;
+                continue;
+            }
+            let pas = (vitesse_max * delta).min(dist);
+            etat.velocite_x = dx / dist * vitesse_max;
+            etat.velocite_z = dz / dist * vitesse_max;
+            etat.position_x += dx / dist * pas;
+            etat.position_z += dz / dist * pas;
+        }
+
+        // Synchroniser la stamina affichée avec celle des joueurs
+        for idx in 0..self.etats_joueurs.len() {
+            if let Some(stamina) = self.get_joueur(self.etats_joueurs[idx].joueur_id).map(|j| j.stamina) {
+                self.etats_joueurs[idx].stamina = stamina;
+            }
+        }
+    }
+
+    /// Sans ballon, chaque joueur de champ se rapproche de l'adversaire de sa zone
+    /// et se place entre lui et son but
+    fn marquer_adversaires(&self, cibles: &mut [(f32, f32, f32)], equipe_porteuse: u32, presseur_idx: Option<usize>) {
+        let mut defenseurs: Vec<usize> = Vec::new();
+        let mut attaquants: Vec<usize> = Vec::new();
+        for (idx, e) in self.etats_joueurs.iter().enumerate() {
+            if !e.actif || e.a_le_ballon || Some(idx) == presseur_idx || self.est_gardien(e.joueur_id) { continue; }
+            if e.equipe_id == equipe_porteuse { attaquants.push(idx); } else { defenseurs.push(idx); }
+        }
+
+        // Les adversaires les plus proches de notre but sont pris en charge en premier,
+        // chacun par le coéquipier libre le plus proche
+        let sens_defense = if equipe_porteuse == self.equipe_domicile.id { -1.0 } else { 1.0 };
+        let but_x = -sens_defense * DEMI_LONGUEUR_TERRAIN;
+        attaquants.sort_by(|&a, &b| {
+            let ea = &self.etats_joueurs[a];
+            let eb = &self.etats_joueurs[b];
+            distance(ea.position_x, ea.position_z, but_x, 0.0)
+                .partial_cmp(&distance(eb.position_x, eb.position_z, but_x, 0.0))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        for a in attaquants {
+            let adv = &self.etats_joueurs[a];
+            let Some(pos) = defenseurs.iter()
+                .enumerate()
+                .min_by(|(_, &d1), (_, &d2)| {
+                    let e1 = &self.etats_joueurs[d1];
+                    let e2 = &self.etats_joueurs[d2];
+                    distance(e1.position_x, e1.position_z, adv.position_x, adv.position_z)
+                        .partial_cmp(&distance(e2.position_x, e2.position_z, adv.position_x, adv.position_z))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(pos, _)| pos)
+            else { break; };
+            let d = defenseurs.remove(pos);
+
+            let marquage = (adv.position_x - sens_defense * 2.5, adv.position_z);
+            let (zx, zz, vitesse) = cibles[d];
+            cibles[d] = (zx * 0.65 + marquage.0 * 0.35, zz * 0.65 + marquage.1 * 0.35, vitesse);
+        }
+    }
+
+    /// Duel entre le porteur et l'adversaire le plus proche
+    fn resoudre_duel(&mut self, delta: f32) {
+        let Some(porteur_idx) = self.etats_joueurs.iter().position(|e| e.a_le_ballon) else { return; };
+        let porteur = &self.etats_joueurs[porteur_idx];
+        let Some(defenseur_idx) = self.adversaire_le_plus_proche(porteur.equipe_id, porteur.position_x, porteur.position_z) else { return; };
+        let defenseur = &self.etats_joueurs[defenseur_idx];
+
+        if distance(porteur.position_x, porteur.position_z, defenseur.position_x, defenseur.position_z) > DISTANCE_DUEL {
+            return;
+        }
+
+        let (Some(attaquant), Some(tacleur)) = (self.get_joueur(porteur.joueur_id), self.get_joueur(defenseur.joueur_id)) else { return; };
+        let a = &attaquant.stats_effectives;
+        let d = &tacleur.stats_effectives;
+        let force_defense = d.defense * 0.6 + d.vitesse * 0.2 + d.force * 0.2;
+        let force_dribble = a.creativite * 0.5 + a.vitesse * 0.3 + a.force * 0.2;
+        let recuperation = self.modificateurs_equipe(defenseur.equipe_id).recuperation * tacleur.modificateur_recuperation();
+        let prob_recuperation = (0.25 * recuperation * force_defense / force_dribble.max(1.0) * delta).min(0.5);
+        // Un tacle appuyé d'un défenseur costaud peut blesser le porteur
+        let intensite = self.intensite_equipe(defenseur.equipe_id);
+        let intensite_tacle = intensite * (0.5 + d.force / 100.0);
+        // Dans sa propre surface, le défenseur retient ses tacles
+        let prudence = if dans_la_surface(porteur.position_x, porteur.position_z, defenseur.equipe_id == self.equipe_domicile.id) { 0.1 } else { 1.0 };
+        let prob_faute = PROBA_FAUTE_DUEL * intensite * prudence * delta;
+        let (porteur_id, tacleur_id) = (porteur.joueur_id, defenseur.joueur_id);
+        let porteur_domicile = porteur.equipe_id == self.equipe_domicile.id;
+
+        if self.rng.gen::<f32>() < prob_faute {
+            self.tester_blessure(porteur_domicile, porteur_id, intensite_tacle);
+            self.commettre_faute(!porteur_domicile, tacleur_id, porteur_id);
+        } else if self.rng.gen::<f32>() < prob_recuperation {
+            self.donner_ballon(defenseur_idx);
+            self.tester_blessure(porteur_domicile, porteur_id, intensite_tacle);
+        }
+    }
+
+    fn decider_action_porteur(&mut self) {
+        let Some(porteur_idx) = self.etats_joueurs.iter().position(|e| e.a_le_ballon) else { return; };
+        let porteur = self.etats_joueurs[porteur_idx].clone();
+        let Some(joueur) = self.get_joueur(porteur.joueur_id) else { return; };
+        let stats = joueur.stats_effectives;
+        let poste = joueur.position_actuelle;
+
+        // Les joueurs intelligents décident plus vite
+        self.temps_avant_action = 1.2 + self.rng.gen::<f32>() * 1.5 * (1.5 - stats.intelligence / 100.0);
+
+        let sens = self.sens_attaque(porteur.equipe_id);
+        let tactique = self.modificateurs_equipe(porteur.equipe_id);
+        let distance_but = distance(porteur.position_x, porteur.position_z, sens * DEMI_LONGUEUR_TERRAIN, 0.0);
+
+        let ecart_adversaire = self.adversaire_le_plus_proche(porteur.equipe_id, porteur.position_x, porteur.position_z)
+            .map(|idx| {
+                let a = &self.etats_joueurs[idx];
+                distance(a.position_x, a.position_z, porteur.position_x, porteur.position_z)
+            })
+            .unwrap_or(10.0);
+
+        if distance_but < tactique.portee_tir && !self.est_gardien(porteur.joueur_id) {
+            // On tire plus volontiers de près et quand l'angle est dégagé
+            let proximite = (1.0 - (distance_but - 8.0) / 14.0).clamp(0.0, 1.0);
+            let degagement = (ecart_adversaire / 4.0).clamp(0.5, 1.0)
+                * 0.8_f32.powi(self.defenseurs_sur_trajectoire(porteur.equipe_id, porteur.position_x, porteur.position_z) as i32);
+            let poids_poste = self.poids_tireur(porteur.equipe_id, poste);
+            // Un bloc bien couvert laisse moins de fenêtres de tir
+            let adversaire = if porteur.equipe_id == self.equipe_domicile.id { &self.equipe_exterieur } else { &self.equipe_domicile };
+            let couverture = adversaire.formation.couverture_defensive();
+            let envie_tir = (0.05 + 0.3 * proximite) * degagement * tactique.envie_tir * poids_poste * couverture;
+            if self.rng.gen::<f32>() < envie_tir {
+                self.tirer(porteur_idx, distance_but);
+                return;
+            }
+        }
+
+        // Chercher le partenaire le mieux placé : progression + liberté de marquage
+        let sous_pression = ecart_adversaire < 3.0;
+
+        let mut meilleur: Option<(usize, f32)> = None;
+        for (idx, partenaire) in self.etats_joueurs.iter().enumerate() {
+            if idx == porteur_idx || !partenaire.actif || partenaire.equipe_id != porteur.equipe_id { continue; }
+            let progression = sens * (partenaire.position_x - porteur.position_x) / 20.0;
+            let liberte = self.adversaire_le_plus_proche(porteur.equipe_id, partenaire.position_x, partenaire.position_z)
+                .map(|a| {
+                    let adv = &self.etats_joueurs[a];
+                    distance(adv.position_x, adv.position_z, partenaire.position_x, partenaire.position_z)
+                })
+                .unwrap_or(10.0)
+                .min(10.0) / 10.0;
+            let score = progression + liberte;
+            if meilleur.map(|(_, s)| score > s).unwrap_or(true) {
+                meilleur = Some((idx, score));
+            }
+        }
+
+        // Bruit de décision : un joueur peu intelligent choisit moins bien
+        let bruit = (self.rng.gen::<f32>() - 0.5) * (1.0 - stats.intelligence / 100.0);
+        match meilleur {
+            Some((idx, score)) if (sous_pression && score > 0.3) || score + bruit > 0.8 => self.passer(porteur_idx, idx),
+            _ => {} // Continuer à conduire le ballon
+        }
+    }
+
+    fn passer(&mut self, porteur_idx: usize, receveur_idx: usize) {
+        let porteur = self.etats_joueurs[porteur_idx].clone();
+        let receveur = self.etats_joueurs[receveur_idx].clone();
+        let Some(passeur) = self.get_joueur(porteur.joueur_id) else { return; };
+        let stats = passeur.stats_effectives;
+        let qualite_passes = passeur.modificateur_passes();
+
+        let longueur = distance(porteur.position_x, porteur.position_z, receveur.position_x, receveur.position_z);
+
+        // Pression sur le receveur : un adversaire proche et vif peut intercepter
+        let intercepteur_idx = self.adversaire_le_plus_proche(porteur.equipe_id, receveur.position_x, receveur.position_z);
+        let pression = intercepteur_idx
+            .map(|idx| {
+                let adv = &self.etats_joueurs[idx];
+                let d = distance(adv.position_x, adv.position_z, receveur.position_x, receveur.position_z);
+                let lecture = self.get_joueur(adv.joueur_id)
+                    .map(|j| (j.stats_effectives.intelligence + j.stats_effectives.vitesse) / 200.0)
+                    .unwrap_or(0.5);
+                (1.0 - d / 6.0).max(0.0) * 0.4 * lecture
+            })
+            .unwrap_or(0.0);
+
+        let precision_passes = self.modificateurs_equipe(porteur.equipe_id).precision_passes;
+        let prob_reussite = ((0.72 + precision_passes + stats.precision / 100.0 * 0.2 + stats.intelligence / 100.0 * 0.08
+            - longueur / 120.0 - pression) * qualite_passes)
+            .clamp(0.2, 0.97);
+
+        if self.rng.gen::<f32>() < prob_reussite {
+            self.donner_ballon(receveur_idx);
+            self.dernier_passeur_id = Some(porteur.joueur_id);
+        } else if self.rng.gen::<f32>() < PROBA_SORTIE_EN_TOUCHE && !intercepteur_idx.is_some_and(|idx| {
+            let adv = &self.etats_joueurs[idx];
+            distance(adv.position_x, adv.position_z, receveur.position_x, receveur.position_z) < 4.0
+        }) {
+            // Passe ratée sans adversaire pour la couper : le ballon file en touche
+            let pour_domicile = porteur.equipe_id != self.equipe_domicile.id;
+            let z = DEMI_LARGEUR_TERRAIN * receveur.position_z.signum();
+            self.accorder_coup_de_pied_arrete(CoupDePiedArrete::RentreeDeTouche, pour_domicile, receveur.position_x, z);
+        } else if let Some(idx) = intercepteur_idx {
+            self.donner_ballon(idx);
+        }
+    }
+
+    fn tirer(&mut self, tireur_idx: usize, distance_but: f32) {
+        let tireur = self.etats_joueurs[tireur_idx].clone();
+        let est_domicile = tireur.equipe_id == self.equipe_domicile.id;
+        let minute = self.get_minute_actuelle();
+
+        // Plus on tire de loin, moins le tir est dangereux
+        let tactique = self.modificateurs_equipe(tireur.equipe_id);
+        let exposition = self.get_modificateurs(!est_domicile).exposition;
+        let mut qualite = (1.0 - distance_but / 25.0).clamp(0.15, 1.0) * tactique.qualite_tir * exposition;
+        if self.temps_possession < DUREE_CONTRE {
+            qualite *= 1.0 + tactique.bonus_contre;
+        }
+        // Un défenseur au contact gêne la frappe
+        if let Some(idx) = self.adversaire_le_plus_proche(tireur.equipe_id, tireur.position_x, tireur.position_z) {
+            let adv = &self.etats_joueurs[idx];
+            let ecart = distance(adv.position_x, adv.position_z, tireur.position_x, tireur.position_z);
+            qualite *= 0.5 + 0.5 * (ecart / 5.0).min(1.0);
+        }
+        // Chaque défenseur dans l'axe peut contrer la frappe
+        qualite *= 0.7_f32.powi(self.defenseurs_sur_trajectoire(tireur.equipe_id, tireur.position_x, tireur.position_z) as i32);
+        let passeur_id = self.dernier_passeur_id.filter(|id| *id != tireur.joueur_id);
+
+        match self.resoudre_tir(est_domicile, tireur.joueur_id, passeur_id, qualite, minute, None) {
+            ResultatTir::But => self.engagement(!est_domicile),
+            ResultatTir::Arrete | ResultatTir::Contre if self.corner_apres_tir(est_domicile, tireur.position_z) => {}
+            _ => self.relance_du_gardien(tireur.equipe_id, tireur.position_x, tireur.position_z),
+        }
+    }
+
+    /// Le gardien adverse récupère le ballon (arrêt, tir non cadré, centre dégagé)
+    fn relance_du_gardien(&mut self, equipe_attaque_id: u32, x: f32, z: f32) {
+        let gardien_idx = self.etats_joueurs.iter()
+            .position(|e| e.equipe_id != equipe_attaque_id && self.est_gardien(e.joueur_id))
+            .or_else(|| self.adversaire_le_plus_proche(equipe_attaque_id, x, z));
+        if let Some(idx) = gardien_idx {
+            self.donner_ballon(idx);
+        }
+    }
+
+    /// Relancer le jeu après un coup de pied arrêté tiré depuis (x, z)
+    pub(crate) fn reprendre_apres_coup_arrete(&mut self, attaque_domicile: bool, x: f32, z: f32, issue: IssueCoupArrete) {
+        let equipe_id = if attaque_domicile { self.equipe_domicile.id } else { self.equipe_exterieur.id };
+        match issue {
+            IssueCoupArrete::But => self.engagement(!attaque_domicile),
+            IssueCoupArrete::Degage => self.relance_du_gardien(equipe_id, x, z),
+            IssueCoupArrete::JeuRepris { tireur_id } => {
+                // Le tireur pose le ballon sur place, l'adversaire doit reculer
+                let Some(idx) = self.etats_joueurs.iter().position(|e| e.joueur_id == tireur_id) else { return; };
+                let etat = &mut self.etats_joueurs[idx];
+                etat.position_x = x.clamp(-DEMI_LONGUEUR_TERRAIN, DEMI_LONGUEUR_TERRAIN);
+                etat.position_z = z.clamp(-DEMI_LARGEUR_TERRAIN, DEMI_LARGEUR_TERRAIN);
+                self.donner_ballon(idx);
+                self.delai_duel = DELAI_APRES_RECUPERATION;
+                self.temps_avant_action = 1.0;
+            }
+        }
+    }
+
+    fn donner_ballon(&mut self, idx: usize) {
+        let ancienne_equipe = self.etats_joueurs.iter().find(|e| e.a_le_ballon).map(|e| e.equipe_id);
+        for etat in &mut self.etats_joueurs {
+            etat.a_le_ballon = false;
+        }
+        let etat = &mut self.etats_joueurs[idx];
+        etat.a_le_ballon = true;
+        self.ballon_x = etat.position_x;
+        self.ballon_z = etat.position_z;
+
+        if ancienne_equipe != Some(etat.equipe_id) {
+            // Changement de possession : laisser au nouveau porteur le temps de jouer
+            self.dernier_passeur_id = None;
+            self.temps_possession = 0.0;
+            self.delai_duel = DELAI_APRES_RECUPERATION;
+            self.temps_avant_action = 0.6;
+        }
+    }
+
+    fn adversaire_le_plus_proche(&self, equipe_id: u32, x: f32, z: f32) -> Option<usize> {
+        self.etats_joueurs.iter()
+            .enumerate()
+            .filter(|(_, e)| e.actif && e.equipe_id != equipe_id)
+            .min_by(|(_, a), (_, b)| {
+                distance(a.position_x, a.position_z, x, z)
+                    .partial_cmp(&distance(b.position_x, b.position_z, x, z))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(idx, _)| idx)
+    }
+
+    /// +1 si l'équipe attaque vers x = +50 (domicile), -1 sinon
+    fn sens_attaque(&self, equipe_id: u32) -> f32 {
+        if equipe_id == self.equipe_domicile.id { 1.0 } else { -1.0 }
+    }
+
+    /// Adversaires de champ placés entre le porteur et le but qu'il attaque
+    pub(crate) fn defenseurs_sur_trajectoire(&self, equipe_id: u32, x: f32, z: f32) -> usize {
+        let but_x = self.sens_attaque(equipe_id) * DEMI_LONGUEUR_TERRAIN;
+        let (dx, dz) = (but_x - x, -z);
+        let longueur2 = dx * dx + dz * dz;
+        if longueur2 <= 0.0 { return 0; }
+
+        self.etats_joueurs.iter()
+            .filter(|e| e.actif && e.equipe_id != equipe_id && !self.est_gardien(e.joueur_id))
+            .filter(|e| {
+                // Projection sur le segment porteur -> but
+                let t = ((e.position_x - x) * dx + (e.position_z - z) * dz) / longueur2;
+                if !(0.05..=1.0).contains(&t) { return false; }
+                distance(e.position_x, e.position_z, x + t * dx, z + t * dz) < 1.5
+            })
+            .count()
+    }
+
+    fn poids_tireur(&self, equipe_id: u32, poste: Position) -> f32 {
+        let equipe = if equipe_id == self.equipe_domicile.id { &self.equipe_domicile } else { &self.equipe_exterieur };
+        equipe.formation.poids_tireur(poste)
+    }
+
+    fn intensite_equipe(&self, equipe_id: u32) -> f32 {
+        if equipe_id == self.equipe_domicile.id {
+            self.equipe_domicile.instructions.intensite
+        } else {
+            self.equipe_exterieur.instructions.intensite
+        }
+    }
+
+    fn modificateurs_equipe(&self, equipe_id: u32) -> ModificateursTactiques {
+        self.get_modificateurs(equipe_id == self.equipe_domicile.id)
+    }
+
+    fn est_gardien(&self, joueur_id: u32) -> bool {
+        self.get_joueur(joueur_id)
+            .map(|j| j.position_actuelle == Position::Gardien)
+            .unwrap_or(false)
+    }
+
+    pub fn get_joueur(&self, joueur_id: u32) -> Option<&Joueur> {
+        self.equipe_domicile.joueurs.iter()
+            .chain(self.equipe_exterieur.joueurs.iter())
+            .find(|j| j.id == joueur_id)
+    }
+
+    pub fn get_porteur_ballon(&self) -> Option<&EtatJoueurMatch> {
+        self.etats_joueurs.iter().find(|e| e.a_le_ballon)
+    }
+}
+
+/// Vitesse de course en m/s selon la vitesse et la fraîcheur du joueur
+fn vitesse_de_course(joueur: &Joueur) -> f32 {
+    let fraicheur = 0.6 + 0.4 * (joueur.stamina / joueur.stamina_max).clamp(0.0, 1.0);
+    (4.0 + joueur.stats_effectives.vitesse / 100.0 * 2.0) * fraicheur
+}
+
+fn distance(x1: f32, z1: f32, x2: f32, z2: f32) -> f32 {
+    ((x1 - x2).powi(2) + (z1 - z2).powi(2)).sqrt()
+}
@@ -32,7 +32,7 @@ MOBILE = os.path.join(BASE, "mobile_app", "shared", "src")
 
 # --- Test 1: Fichiers de modèles présents ---
 print("\n--- Test 1: Fichiers de modèles présents (desktop et mobile) ---")
-MODEL_FILES = ["player.rs", "team.rs", "match_engine.rs", "power_up.rs", "scientific_domain.rs", "mod.rs"]
+MODEL_FILES = ["player.rs", "team.rs", "match_engine.rs", "power_up.rs", "scientific_domain.rs", "aleatoire.rs", "simulation_spatiale.rs", "capacites.rs", "regles.rs", "tirs_au_but.rs", "coups_de_pied_arretes.rs", "statistiques.rs", "entraineur_ia.rs", "decouvertes.rs", "championnat.rs", "coupe.rs", "generateur.rs", "transferts.rs", "finances.rs", "mod.rs"]
 
 for f in MODEL_FILES:
     desktop_path = os.path.join(DESKTOP, "models", f)